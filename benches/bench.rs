@@ -177,6 +177,7 @@ macro_rules! primitive_impl {
 }
 primitive_impl!(f32);
 primitive_impl!(f64);
+primitive_impl!(i32);
 primitive_impl!(bool);
 
 impl<T, const N: usize> BenchIo for [T; N]
@@ -334,6 +335,7 @@ glam_vector_impl!(glam::Vec2);
 glam_vector_impl!(glam::Vec3);
 glam_vector_impl!(glam::Vec3A);
 glam_vector_impl!(glam::Vec4);
+glam_vector_impl!(glam::IVec4);
 glam_vector_impl!(glam::BVec2);
 glam_vector_impl!(glam::BVec3);
 glam_vector_impl!(glam::BVec3A);
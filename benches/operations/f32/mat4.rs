@@ -32,6 +32,15 @@ bench!(
     (x4_unaligned, <Mat4<f32x4> as Mul>::mul),
 );
 
+bench!(
+    transpose,
+    ARRAY_LEN,
+    (unaligned, |m: Mat4<f32>| m.transpose()),
+    (aligned, |m: Mat4A<f32>| m.transpose()),
+    (aligned_glam, |m: glam::Mat4| m.transpose()),
+    (x4_unaligned, |m: Mat4<f32x4>| m.transpose()),
+);
+
 bench!(
     vector_mul,
     ARRAY_LEN,
@@ -175,6 +175,14 @@ bench!(
     (x4_unaligned, Vec3::<f32x4>::normalize),
 );
 
+bench!(
+    normalize_fast,
+    ARRAY_LEN,
+    (unaligned, Vec3::<f32>::normalize_fast),
+    (aligned, Vec3A::<f32>::normalize_fast),
+    // This function is not in `glam`, or for SoA vectors.
+);
+
 bench!(
     project_onto,
     ARRAY_LEN,
@@ -23,6 +23,14 @@ bench!(
     (x4_unaligned, <Vec4::<f32x4> as Add>::add),
 );
 
+bench!(
+    as_i32,
+    ARRAY_LEN,
+    (unaligned, Vec4::<f32>::as_i32),
+    (aligned, Vec4A::<f32>::as_i32),
+    (aligned_glam, glam::Vec4::as_ivec4),
+);
+
 bench!(
     asin,
     ARRAY_LEN,
@@ -149,6 +157,14 @@ bench!(
     (x4_unaligned, Vec4::<f32x4>::normalize),
 );
 
+bench!(
+    normalize_fast,
+    ARRAY_LEN,
+    (unaligned, Vec4::<f32>::normalize_fast),
+    (aligned, Vec4A::<f32>::normalize_fast),
+    // This function is not in `glam`, or for SoA vectors.
+);
+
 bench!(
     rem,
     ARRAY_LEN,
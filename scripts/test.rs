@@ -47,6 +47,7 @@ fn main() {
             overflow_checks,
             libm,
             third_party_crates,
+            &[],
         ));
         commands.push(cargo_command(
             "doc",
@@ -56,10 +57,28 @@ fn main() {
             overflow_checks,
             libm,
             third_party_crates,
+            &[],
         ));
     }
 
-    for (debug_assertions, third_party_crates) in [(false, false), (false, true), (true, true)] {
+    commands.push(cargo_command(
+        "clippy",
+        &[],
+        None,
+        false,
+        false,
+        false,
+        true,
+        &["force-fallback", "deterministic"],
+    ));
+
+    // Running every test with `force-fallback` too compares the SIMD backends
+    // against the scalar fallback, and `deterministic` runs the golden-value
+    // tests.
+    for ((debug_assertions, third_party_crates), extra_features) in iproduct!(
+        [(false, false), (false, true), (true, true)],
+        [&[][..], &["force-fallback"], &["deterministic"]],
+    ) {
         let overflow_checks = debug_assertions;
         let libm = debug_assertions;
 
@@ -71,6 +90,7 @@ fn main() {
             overflow_checks,
             libm,
             third_party_crates,
+            extra_features,
         ));
     }
 
@@ -85,6 +105,7 @@ fn cargo_command(
     overflow_checks: bool,
     libm: bool,
     third_party_crates: bool,
+    extra_features: &[&str],
 ) -> Command {
     let mut command = Command::new("cargo");
     let mut rustflags = String::new();
@@ -114,6 +135,11 @@ fn cargo_command(
         features += THIRD_PARTY_CRATES;
     }
 
+    for feature in extra_features {
+        features += " ";
+        features += feature;
+    }
+
     let mut allow_warnings = false;
     for arg in args().skip(1) {
         match arg.as_str() {
@@ -10,6 +10,7 @@ use crate::{
 };
 
 mod float;
+mod number;
 #[cfg(feature = "wide")]
 mod wide;
 #[cfg(feature = "wide")]
@@ -879,6 +880,9 @@ impl_mul!(
     /// For primitive types this operation is cross-platform deterministic and
     /// fully consistent with scalar addition and multiplication, including
     /// floating-point precision and integer panics.
+    ///
+    /// The exception is aligned [`f64`] types when the `fma` target feature is
+    /// enabled, where multiplications and additions may be fused.
 );
 
 macro_rules! impl_mul_matrix {
@@ -953,6 +957,9 @@ impl_mul_matrix!(
     /// For primitive types this operation is cross-platform deterministic and
     /// fully consistent with scalar addition and multiplication, including
     /// floating-point precision and integer panics.
+    ///
+    /// The exception is aligned [`f64`] types when the `fma` target feature is
+    /// enabled, where multiplications and additions may be fused.
 );
 impl_mul_matrix!(
     3,
@@ -967,6 +974,9 @@ impl_mul_matrix!(
     /// For primitive types this operation is cross-platform deterministic and
     /// fully consistent with scalar addition and multiplication, including
     /// floating-point precision and integer panics.
+    ///
+    /// The exception is aligned [`f64`] types when the `fma` target feature is
+    /// enabled, where multiplications and additions may be fused.
 );
 
 macro_rules! impl_matrix_mul {
@@ -1041,6 +1051,9 @@ impl_matrix_mul!(
     /// For primitive types this operation is cross-platform deterministic and
     /// fully consistent with scalar addition and multiplication, including
     /// floating-point precision and integer panics.
+    ///
+    /// The exception is aligned [`f64`] types when the `fma` target feature is
+    /// enabled, where multiplications and additions may be fused.
 );
 impl_matrix_mul!(
     3,
@@ -1055,6 +1068,9 @@ impl_matrix_mul!(
     /// For primitive types this operation is cross-platform deterministic and
     /// fully consistent with scalar addition and multiplication, including
     /// floating-point precision and integer panics.
+    ///
+    /// The exception is aligned [`f64`] types when the `fma` target feature is
+    /// enabled, where multiplications and additions may be fused.
 );
 
 macro_rules! impl_mul_assign {
@@ -1098,6 +1114,9 @@ impl_mul_assign!(
     /// For primitive types this operation is cross-platform deterministic and
     /// fully consistent with scalar addition and multiplication, including
     /// floating-point precision and integer panics.
+    ///
+    /// The exception is aligned [`f64`] types when the `fma` target feature is
+    /// enabled, where multiplications and additions may be fused.
 );
 
 macro_rules! impl_matrix_mul_assign {
@@ -1141,6 +1160,9 @@ impl_matrix_mul_assign!(
     /// For primitive types this operation is cross-platform deterministic and
     /// fully consistent with scalar addition and multiplication, including
     /// floating-point precision and integer panics.
+    ///
+    /// The exception is aligned [`f64`] types when the `fma` target feature is
+    /// enabled, where multiplications and additions may be fused.
 );
 impl_matrix_mul_assign!(
     3,
@@ -1156,6 +1178,9 @@ impl_matrix_mul_assign!(
     /// For primitive types this operation is cross-platform deterministic and
     /// fully consistent with scalar addition and multiplication, including
     /// floating-point precision and integer panics.
+    ///
+    /// The exception is aligned [`f64`] types when the `fma` target feature is
+    /// enabled, where multiplications and additions may be fused.
 );
 
 #[cfg(test)]
@@ -577,7 +577,8 @@ mod tests {
     use crate::{
         Affine, Affine2, EulerRot, Matrix, Quaternion, Vec2, Vector,
         test_utils::{
-            assert_debug_panic, assert_panic_test_eq, assert_test_eq, for_types, random_iter,
+            assert_debug_panic, assert_panic_test_eq, assert_test_eq, contraction_tolerance,
+            for_types, random_iter,
         },
     };
 
@@ -813,6 +814,7 @@ mod tests {
                 assert_test_eq!(
                     Affine::<2, T, A>::from_angle(angle).transform_point(point),
                     point.rotate(angle),
+                    abs <= contraction_tolerance::<T, A>(point.length()),
                     0.0 = -0.0
                 );
             }
@@ -0,0 +1,130 @@
+use crate::{Affine, Alignment, Length, PrimitiveNumber, SupportedLength};
+
+impl<const N: usize, T, A: Alignment> Affine<N, T, A>
+where
+    Length<N>: SupportedLength,
+    T: PrimitiveNumber,
+{
+    /// Converts the elements of `self` to `U` with `as` semantics.
+    ///
+    /// See [`Vector::cast`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ggmath::{Affine2, Vec2};
+    /// #
+    /// let affine = Affine2::from_translation(Vec2::new(1.5, -2.5));
+    ///
+    /// assert_eq!(
+    ///     affine.cast::<i32>(),
+    ///     Affine2::from_translation(Vec2::new(1, -2)),
+    /// );
+    /// ```
+    ///
+    /// [`Vector::cast`]: crate::Vector::cast
+    #[inline]
+    #[must_use]
+    pub fn cast<U: PrimitiveNumber>(&self) -> Affine<N, U, A> {
+        Affine::from_submatrix_translation(self.submatrix.cast(), self.translation.cast())
+    }
+
+    /// Converts the elements of `self` to `U`, returning `None` if any element
+    /// is non-finite or out of the range of `U`.
+    ///
+    /// See [`Vector::try_cast`] for details.
+    ///
+    /// [`Vector::try_cast`]: crate::Vector::try_cast
+    #[inline]
+    #[must_use]
+    pub fn try_cast<U: PrimitiveNumber>(&self) -> Option<Affine<N, U, A>> {
+        Some(Affine::from_submatrix_translation(
+            self.submatrix.try_cast()?,
+            self.translation.try_cast()?,
+        ))
+    }
+
+    /// Converts the elements of `self` to [`f32`] with `as` semantics.
+    ///
+    /// Equivalent to `self.cast::<f32>()`.
+    #[inline]
+    #[must_use]
+    pub fn as_f32(&self) -> Affine<N, f32, A> {
+        self.cast()
+    }
+
+    /// Converts the elements of `self` to [`f64`] with `as` semantics.
+    ///
+    /// Equivalent to `self.cast::<f64>()`.
+    #[inline]
+    #[must_use]
+    pub fn as_f64(&self) -> Affine<N, f64, A> {
+        self.cast()
+    }
+
+    /// Converts the elements of `self` to [`i32`] with `as` semantics.
+    ///
+    /// Equivalent to `self.cast::<i32>()`.
+    #[inline]
+    #[must_use]
+    pub fn as_i32(&self) -> Affine<N, i32, A> {
+        self.cast()
+    }
+
+    /// Converts the elements of `self` to [`u32`] with `as` semantics.
+    ///
+    /// Equivalent to `self.cast::<u32>()`.
+    #[inline]
+    #[must_use]
+    pub fn as_u32(&self) -> Affine<N, u32, A> {
+        self.cast()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Affine, Affine2, Vec2,
+        test_utils::{assert_test_eq, for_types, random_iter},
+    };
+
+    #[test]
+    fn test_cast() {
+        for_types!(|N, T: PrimitiveNumber, A| {
+            for affine in random_iter::<Affine<N, T, A>>() {
+                assert_test_eq!(
+                    affine.cast::<f64>(),
+                    Affine::from_submatrix_translation(
+                        affine.submatrix.cast::<f64>(),
+                        affine.translation.cast::<f64>()
+                    )
+                );
+                assert_eq!(
+                    affine.cast::<u16>(),
+                    Affine::from_submatrix_translation(
+                        affine.submatrix.cast::<u16>(),
+                        affine.translation.cast::<u16>()
+                    )
+                );
+
+                assert_test_eq!(affine.as_f32(), affine.cast::<f32>());
+                assert_test_eq!(affine.as_f64(), affine.cast::<f64>());
+                assert_eq!(affine.as_i32(), affine.cast::<i32>());
+                assert_eq!(affine.as_u32(), affine.cast::<u32>());
+            }
+        });
+    }
+
+    #[test]
+    fn test_try_cast() {
+        let affine = Affine2::from_translation(Vec2::new(1.0, 300.0));
+        assert_eq!(affine.try_cast::<u8>(), None);
+        assert_eq!(
+            affine.try_cast::<u16>(),
+            Some(Affine2::from_translation(Vec2::new(1, 300)))
+        );
+
+        let affine = Affine2::from_scale(Vec2::new(f32::NAN, 1.0));
+        assert_eq!(affine.try_cast::<i32>(), None);
+    }
+}
@@ -1,24 +1,54 @@
 use core::ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Neg, Not, Rem, Shl, Shr, Sub};
 
+#[cfg(feature = "f16")]
+use half::f16;
+
 use crate::{
-    Aligned, Alignment, Length, Mask, PrimitiveFloat, PrimitiveInteger, PrimitiveSigned,
-    Quaternion, Scalar, SupportedLength, Unaligned, Vector,
-    utils::{PrimitiveFloatUtils, Repr2, Repr3, Repr4},
+    Aligned, Alignment, Length, Mask, Matrix, PrimitiveFloat, PrimitiveInteger, PrimitiveNumber,
+    PrimitiveSigned, Quaternion, Scalar, SupportedLength, Unaligned, Vector,
+    utils::{PrimitiveFloatUtils, PrimitiveNumberUtils, Repr2, Repr3, Repr4, specialize},
 };
 
 cfg_select! {
+    feature = "force-fallback" => {
+        mod fallback;
+    }
+    target_feature = "avx" => {
+        mod avx;
+        mod sse2;
+    }
     target_feature = "sse2" => {
         mod sse2;
     }
     all(target_arch = "aarch64", target_feature = "neon") => {
         mod neon;
     }
+    all(target_arch = "wasm32", target_feature = "simd128") => {
+        mod wasm32;
+    }
     _ => {
         mod fallback;
     }
 }
 
-pub(crate) trait DefaultBackend<const N: usize, A: Alignment>: Scalar {}
+/// Implements the backend traits for `Self` with scalar code.
+///
+/// # Safety
+///
+/// [`Self::Repr`] must follow the requirements of [`VectorBackend::Inner`].
+pub(crate) unsafe trait DefaultBackend<const N: usize, A: Alignment>: Scalar {
+    /// Controls the internal representation of [`Vector<N, Self, A>`].
+    ///
+    /// This is [`DefaultRepr<N, Self>`] unless the `force-fallback` feature
+    /// replaces a SIMD backend, in which case this is a padded and aligned type
+    /// with the same layout as the SIMD representation.
+    type Repr: Copy;
+}
+
+/// The representation of [`Vector<N, T, A>`] for types without a SIMD
+/// layout.
+pub(crate) type DefaultRepr<const N: usize, T> =
+    <Length<N> as SupportedLength>::Select<Repr2<T>, Repr3<T>, Repr4<T>>;
 
 /// # Safety
 ///
@@ -131,6 +161,22 @@ where
     where
         Self: Scalar + Mul<Output = Self>;
 
+    #[track_caller]
+    fn vector_dot(vector: Vector<N, Self, A>, rhs: Vector<N, Self, A>) -> Self
+    where
+        Self: Scalar + Add<Output = Self> + Mul<Output = Self>;
+
+    /// Computes `(vector * a) + b`, which may or may not be fused into a single
+    /// rounding.
+    #[track_caller]
+    fn vector_mul_add_contracted(
+        vector: Vector<N, Self, A>,
+        a: Vector<N, Self, A>,
+        b: Vector<N, Self, A>,
+    ) -> Vector<N, Self, A>
+    where
+        Self: Scalar + Add<Output = Self> + Mul<Output = Self>;
+
     fn vector_eq_mask(vector: Vector<N, Self, A>, other: Vector<N, Self, A>) -> Mask<N, Self, A>
     where
         Self: Scalar + PartialEq;
@@ -167,6 +213,29 @@ pub(crate) trait QuaternionBackend<A: Alignment> {
             + Mul<Output = Self>;
 }
 
+pub(crate) trait MatrixBackend<const N: usize, A: Alignment>
+where
+    Length<N>: SupportedLength,
+{
+    fn matrix_transpose(matrix: &Matrix<N, Self, A>) -> Matrix<N, Self, A>
+    where
+        Self: Scalar;
+
+    #[track_caller]
+    fn matrix_mul(matrix: &Matrix<N, Self, A>, rhs: &Matrix<N, Self, A>) -> Matrix<N, Self, A>
+    where
+        Self: Scalar + Add<Output = Self> + Mul<Output = Self>;
+
+    #[track_caller]
+    fn matrix_determinant(matrix: &Matrix<N, Self, A>) -> Self
+    where
+        Self: Scalar
+            + Neg<Output = Self>
+            + Add<Output = Self>
+            + Sub<Output = Self>
+            + Mul<Output = Self>;
+}
+
 /// # Safety
 ///
 /// [`Self::Inner`] must be implemented correctly. All other items are safe to
@@ -250,6 +319,40 @@ where
         Self: Scalar;
 }
 
+pub(crate) trait CastBackend<const N: usize, A: Alignment>: Scalar
+where
+    Length<N>: SupportedLength,
+{
+    fn vector_as_f32(vector: Vector<N, Self, A>) -> Vector<N, f32, A>;
+
+    fn vector_as_f64(vector: Vector<N, Self, A>) -> Vector<N, f64, A>;
+
+    fn vector_as_i32(vector: Vector<N, Self, A>) -> Vector<N, i32, A>;
+
+    fn vector_as_u32(vector: Vector<N, Self, A>) -> Vector<N, u32, A>;
+}
+
+/// Selects the conversion used by [`Vector::cast`] based on the target type,
+/// so that conversions to `f32`, `f64`, `i32` and `u32` can use
+/// [`CastBackend`].
+pub(crate) trait CastTargetBackend: Scalar {
+    fn vector_cast_from<const N: usize, T: PrimitiveNumber, A: Alignment>(
+        vector: Vector<N, T, A>,
+    ) -> Vector<N, Self, A>
+    where
+        Length<N>: SupportedLength;
+}
+
+#[cfg(feature = "f16")]
+pub(crate) trait HalfBackend<const N: usize, A: Alignment>: Scalar
+where
+    Length<N>: SupportedLength,
+{
+    fn vector_to_f16(vector: Vector<N, f32, A>) -> Vector<N, f16, A>;
+
+    fn vector_from_f16(vector: Vector<N, f16, A>) -> Vector<N, f32, A>;
+}
+
 pub(crate) trait FloatVectorBackend<const N: usize, A: Alignment>: Scalar
 where
     Length<N>: SupportedLength,
@@ -300,6 +403,18 @@ where
 
     fn vector_sqrt(vector: Vector<N, Self, A>) -> Vector<N, Self, A>;
 
+    /// Computes `1 / sqrt(vector)`, which may be approximated.
+    fn vector_rsqrt(vector: Vector<N, Self, A>) -> Vector<N, Self, A>;
+
+    /// Computes `1 / vector`, which may be approximated.
+    fn vector_recip_fast(vector: Vector<N, Self, A>) -> Vector<N, Self, A>;
+
+    /// Computes `1 / vector.length()`, which may be approximated.
+    fn vector_length_recip_fast(vector: Vector<N, Self, A>) -> Self;
+
+    /// Computes `vector / vector.length()`, which may be approximated.
+    fn vector_normalize_fast(vector: Vector<N, Self, A>) -> Vector<N, Self, A>;
+
     fn vector_exp(vector: Vector<N, Self, A>) -> Vector<N, Self, A>;
 
     fn vector_exp2(vector: Vector<N, Self, A>) -> Vector<N, Self, A>;
@@ -323,6 +438,13 @@ where
     fn vector_sin_cos(vector: Vector<N, Self, A>) -> (Vector<N, Self, A>, Vector<N, Self, A>);
 }
 
+pub(crate) trait FloatMatrixBackend<const N: usize, A: Alignment>: Scalar
+where
+    Length<N>: SupportedLength,
+{
+    fn matrix_inverse_and_determinant(matrix: &Matrix<N, Self, A>) -> (Matrix<N, Self, A>, Self);
+}
+
 pub(crate) trait IntegerVectorBackend<const N: usize, A: Alignment>: Scalar
 where
     Length<N>: SupportedLength,
@@ -422,44 +544,172 @@ where
     fn vector_negative_mask(vector: Vector<N, Self, A>) -> Mask<N, Self, A>;
 }
 
-impl DefaultBackend<2, Aligned> for f32 {}
+/// Evaluates `f` for every index in `0..N` and discards the results.
+///
+/// SIMD integer operations always wrap, while scalar operations panic on
+/// overflow when overflow checks are enabled. Repeating an operation with
+/// scalars through this function makes SIMD backends panic in the same cases.
+/// When overflow checks are disabled the results are unused and this is
+/// optimized away.
+#[cfg_attr(
+    any(
+        feature = "force-fallback",
+        not(any(
+            target_feature = "sse2",
+            all(target_arch = "aarch64", target_feature = "neon")
+        ))
+    ),
+    expect(dead_code)
+)]
+#[inline(always)]
+#[track_caller]
+fn overflow_check<const N: usize, T>(f: impl Fn(usize) -> T) {
+    for i in 0..N {
+        let _ = f(i);
+    }
+}
 
-impl<const N: usize> DefaultBackend<N, Unaligned> for f32 {}
+// SAFETY: `DefaultRepr` follows the requirements of `VectorBackend::Inner`.
+unsafe impl DefaultBackend<2, Aligned> for f32 {
+    type Repr = DefaultRepr<2, f32>;
+}
 
-impl<const N: usize, A: Alignment> DefaultBackend<N, A> for f64 {}
+// SAFETY: `DefaultRepr` follows the requirements of `VectorBackend::Inner`.
+unsafe impl<const N: usize> DefaultBackend<N, Unaligned> for f32
+where
+    Length<N>: SupportedLength,
+{
+    type Repr = DefaultRepr<N, f32>;
+}
 
-impl<const N: usize, A: Alignment> DefaultBackend<N, A> for i8 {}
+// SAFETY: `DefaultRepr` follows the requirements of `VectorBackend::Inner`.
+unsafe impl<const N: usize> DefaultBackend<N, Unaligned> for f64
+where
+    Length<N>: SupportedLength,
+{
+    type Repr = DefaultRepr<N, f64>;
+}
 
-impl<const N: usize, A: Alignment> DefaultBackend<N, A> for i16 {}
+// SAFETY: `DefaultRepr` follows the requirements of `VectorBackend::Inner`.
+unsafe impl<const N: usize, A: Alignment> DefaultBackend<N, A> for i8
+where
+    Length<N>: SupportedLength,
+{
+    type Repr = DefaultRepr<N, i8>;
+}
 
-impl<const N: usize, A: Alignment> DefaultBackend<N, A> for i32 {}
+// SAFETY: `DefaultRepr` follows the requirements of `VectorBackend::Inner`.
+unsafe impl<const N: usize, A: Alignment> DefaultBackend<N, A> for i16
+where
+    Length<N>: SupportedLength,
+{
+    type Repr = DefaultRepr<N, i16>;
+}
 
-impl<const N: usize, A: Alignment> DefaultBackend<N, A> for i64 {}
+// SAFETY: `DefaultRepr` follows the requirements of `VectorBackend::Inner`.
+unsafe impl DefaultBackend<2, Aligned> for i32 {
+    type Repr = DefaultRepr<2, i32>;
+}
 
-impl<const N: usize, A: Alignment> DefaultBackend<N, A> for i128 {}
+// SAFETY: `DefaultRepr` follows the requirements of `VectorBackend::Inner`.
+unsafe impl<const N: usize> DefaultBackend<N, Unaligned> for i32
+where
+    Length<N>: SupportedLength,
+{
+    type Repr = DefaultRepr<N, i32>;
+}
 
-impl<const N: usize, A: Alignment> DefaultBackend<N, A> for isize {}
+// SAFETY: `DefaultRepr` follows the requirements of `VectorBackend::Inner`.
+unsafe impl<const N: usize, A: Alignment> DefaultBackend<N, A> for i64
+where
+    Length<N>: SupportedLength,
+{
+    type Repr = DefaultRepr<N, i64>;
+}
 
-impl<const N: usize, A: Alignment> DefaultBackend<N, A> for u8 {}
+// SAFETY: `DefaultRepr` follows the requirements of `VectorBackend::Inner`.
+unsafe impl<const N: usize, A: Alignment> DefaultBackend<N, A> for i128
+where
+    Length<N>: SupportedLength,
+{
+    type Repr = DefaultRepr<N, i128>;
+}
 
-impl<const N: usize, A: Alignment> DefaultBackend<N, A> for u16 {}
+// SAFETY: `DefaultRepr` follows the requirements of `VectorBackend::Inner`.
+unsafe impl<const N: usize, A: Alignment> DefaultBackend<N, A> for isize
+where
+    Length<N>: SupportedLength,
+{
+    type Repr = DefaultRepr<N, isize>;
+}
 
-impl<const N: usize, A: Alignment> DefaultBackend<N, A> for u32 {}
+// SAFETY: `DefaultRepr` follows the requirements of `VectorBackend::Inner`.
+unsafe impl<const N: usize, A: Alignment> DefaultBackend<N, A> for u8
+where
+    Length<N>: SupportedLength,
+{
+    type Repr = DefaultRepr<N, u8>;
+}
 
-impl<const N: usize, A: Alignment> DefaultBackend<N, A> for u64 {}
+// SAFETY: `DefaultRepr` follows the requirements of `VectorBackend::Inner`.
+unsafe impl<const N: usize, A: Alignment> DefaultBackend<N, A> for u16
+where
+    Length<N>: SupportedLength,
+{
+    type Repr = DefaultRepr<N, u16>;
+}
 
-impl<const N: usize, A: Alignment> DefaultBackend<N, A> for u128 {}
+// SAFETY: `DefaultRepr` follows the requirements of `VectorBackend::Inner`.
+unsafe impl DefaultBackend<2, Aligned> for u32 {
+    type Repr = DefaultRepr<2, u32>;
+}
 
-impl<const N: usize, A: Alignment> DefaultBackend<N, A> for usize {}
+// SAFETY: `DefaultRepr` follows the requirements of `VectorBackend::Inner`.
+unsafe impl<const N: usize> DefaultBackend<N, Unaligned> for u32
+where
+    Length<N>: SupportedLength,
+{
+    type Repr = DefaultRepr<N, u32>;
+}
+
+// SAFETY: `DefaultRepr` follows the requirements of `VectorBackend::Inner`.
+unsafe impl<const N: usize, A: Alignment> DefaultBackend<N, A> for u64
+where
+    Length<N>: SupportedLength,
+{
+    type Repr = DefaultRepr<N, u64>;
+}
 
-impl<const N: usize, A: Alignment> DefaultBackend<N, A> for bool {}
+// SAFETY: `DefaultRepr` follows the requirements of `VectorBackend::Inner`.
+unsafe impl<const N: usize, A: Alignment> DefaultBackend<N, A> for u128
+where
+    Length<N>: SupportedLength,
+{
+    type Repr = DefaultRepr<N, u128>;
+}
+
+// SAFETY: `DefaultRepr` follows the requirements of `VectorBackend::Inner`.
+unsafe impl<const N: usize, A: Alignment> DefaultBackend<N, A> for usize
+where
+    Length<N>: SupportedLength,
+{
+    type Repr = DefaultRepr<N, usize>;
+}
+
+// SAFETY: `DefaultRepr` follows the requirements of `VectorBackend::Inner`.
+unsafe impl<const N: usize, A: Alignment> DefaultBackend<N, A> for bool
+where
+    Length<N>: SupportedLength,
+{
+    type Repr = DefaultRepr<N, bool>;
+}
 
 // SAFETY: `Inner` follows its requirements.
 unsafe impl<T, A: Alignment> VectorBackend<2, A> for T
 where
     T: DefaultBackend<2, A>,
 {
-    type Inner = Repr2<T>;
+    type Inner = T::Repr;
 
     #[inline]
     fn vector_eq(vector: &Vector<2, Self, A>, other: &Vector<2, Self, A>) -> bool
@@ -589,6 +839,26 @@ where
         vector.x * vector.y
     }
 
+    #[inline]
+    fn vector_dot(vector: Vector<2, Self, A>, rhs: Vector<2, Self, A>) -> Self
+    where
+        Self: Add<Output = Self> + Mul<Output = Self>,
+    {
+        vector.x * rhs.x + vector.y * rhs.y
+    }
+
+    #[inline]
+    fn vector_mul_add_contracted(
+        vector: Vector<2, Self, A>,
+        a: Vector<2, Self, A>,
+        b: Vector<2, Self, A>,
+    ) -> Vector<2, Self, A>
+    where
+        Self: Add<Output = Self> + Mul<Output = Self>,
+    {
+        vector * a + b
+    }
+
     #[inline]
     fn vector_eq_mask(vector: Vector<2, Self, A>, other: Vector<2, Self, A>) -> Mask<2, Self, A>
     where
@@ -643,7 +913,7 @@ unsafe impl<T, A: Alignment> VectorBackend<3, A> for T
 where
     T: DefaultBackend<3, A>,
 {
-    type Inner = Repr3<T>;
+    type Inner = T::Repr;
 
     #[inline]
     fn vector_eq(vector: &Vector<3, Self, A>, other: &Vector<3, Self, A>) -> bool
@@ -773,6 +1043,26 @@ where
         vector.x * vector.y * vector.z
     }
 
+    #[inline]
+    fn vector_dot(vector: Vector<3, Self, A>, rhs: Vector<3, Self, A>) -> Self
+    where
+        Self: Add<Output = Self> + Mul<Output = Self>,
+    {
+        vector.x * rhs.x + vector.y * rhs.y + vector.z * rhs.z
+    }
+
+    #[inline]
+    fn vector_mul_add_contracted(
+        vector: Vector<3, Self, A>,
+        a: Vector<3, Self, A>,
+        b: Vector<3, Self, A>,
+    ) -> Vector<3, Self, A>
+    where
+        Self: Add<Output = Self> + Mul<Output = Self>,
+    {
+        vector * a + b
+    }
+
     #[inline]
     fn vector_eq_mask(vector: Vector<3, Self, A>, other: Vector<3, Self, A>) -> Mask<3, Self, A>
     where
@@ -843,7 +1133,7 @@ unsafe impl<T, A: Alignment> VectorBackend<4, A> for T
 where
     T: DefaultBackend<4, A>,
 {
-    type Inner = Repr4<T>;
+    type Inner = T::Repr;
 
     #[inline]
     fn vector_eq(vector: &Vector<4, Self, A>, other: &Vector<4, Self, A>) -> bool
@@ -1023,6 +1313,26 @@ where
         vector.x * vector.y * (vector.z * vector.w)
     }
 
+    #[inline]
+    fn vector_dot(vector: Vector<4, Self, A>, rhs: Vector<4, Self, A>) -> Self
+    where
+        Self: Add<Output = Self> + Mul<Output = Self>,
+    {
+        vector.x * rhs.x + vector.y * rhs.y + (vector.z * rhs.z + vector.w * rhs.w)
+    }
+
+    #[inline]
+    fn vector_mul_add_contracted(
+        vector: Vector<4, Self, A>,
+        a: Vector<4, Self, A>,
+        b: Vector<4, Self, A>,
+    ) -> Vector<4, Self, A>
+    where
+        Self: Add<Output = Self> + Mul<Output = Self>,
+    {
+        vector * a + b
+    }
+
     #[inline]
     fn vector_eq_mask(vector: Vector<4, Self, A>, other: Vector<4, Self, A>) -> Mask<4, Self, A>
     where
@@ -1123,6 +1433,84 @@ where
     }
 }
 
+impl<T, A: Alignment> MatrixBackend<2, A> for T
+where
+    T: DefaultBackend<2, A>,
+{
+    #[inline]
+    fn matrix_transpose(matrix: &Matrix<2, Self, A>) -> Matrix<2, Self, A> {
+        matrix.transpose_generic()
+    }
+
+    #[inline]
+    fn matrix_mul(matrix: &Matrix<2, Self, A>, rhs: &Matrix<2, Self, A>) -> Matrix<2, Self, A>
+    where
+        Self: Add<Output = Self> + Mul<Output = Self>,
+    {
+        matrix.mul_generic(rhs)
+    }
+
+    #[inline]
+    fn matrix_determinant(matrix: &Matrix<2, Self, A>) -> Self
+    where
+        Self: Neg<Output = Self> + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>,
+    {
+        matrix.determinant_generic()
+    }
+}
+
+impl<T, A: Alignment> MatrixBackend<3, A> for T
+where
+    T: DefaultBackend<3, A>,
+{
+    #[inline]
+    fn matrix_transpose(matrix: &Matrix<3, Self, A>) -> Matrix<3, Self, A> {
+        matrix.transpose_generic()
+    }
+
+    #[inline]
+    fn matrix_mul(matrix: &Matrix<3, Self, A>, rhs: &Matrix<3, Self, A>) -> Matrix<3, Self, A>
+    where
+        Self: Add<Output = Self> + Mul<Output = Self>,
+    {
+        matrix.mul_generic(rhs)
+    }
+
+    #[inline]
+    fn matrix_determinant(matrix: &Matrix<3, Self, A>) -> Self
+    where
+        Self: Neg<Output = Self> + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>,
+    {
+        matrix.determinant_generic()
+    }
+}
+
+impl<T, A: Alignment> MatrixBackend<4, A> for T
+where
+    T: DefaultBackend<4, A>,
+{
+    #[inline]
+    fn matrix_transpose(matrix: &Matrix<4, Self, A>) -> Matrix<4, Self, A> {
+        matrix.transpose_generic()
+    }
+
+    #[inline]
+    fn matrix_mul(matrix: &Matrix<4, Self, A>, rhs: &Matrix<4, Self, A>) -> Matrix<4, Self, A>
+    where
+        Self: Add<Output = Self> + Mul<Output = Self>,
+    {
+        matrix.mul_generic(rhs)
+    }
+
+    #[inline]
+    fn matrix_determinant(matrix: &Matrix<4, Self, A>) -> Self
+    where
+        Self: Neg<Output = Self> + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>,
+    {
+        matrix.determinant_generic()
+    }
+}
+
 // SAFETY: `Inner` follows its requirements.
 unsafe impl<T, A: Alignment> MaskBackend<2, A> for T
 where
@@ -1502,6 +1890,194 @@ where
     }
 }
 
+impl<T, A: Alignment> CastBackend<2, A> for T
+where
+    T: PrimitiveNumber + DefaultBackend<2, A>,
+{
+    #[inline]
+    fn vector_as_f32(vector: Vector<2, Self, A>) -> Vector<2, f32, A> {
+        Vector::<2, f32, A>::new(vector.x.as_f32(), vector.y.as_f32())
+    }
+
+    #[inline]
+    fn vector_as_f64(vector: Vector<2, Self, A>) -> Vector<2, f64, A> {
+        Vector::<2, f64, A>::new(vector.x.as_f64(), vector.y.as_f64())
+    }
+
+    #[inline]
+    fn vector_as_i32(vector: Vector<2, Self, A>) -> Vector<2, i32, A> {
+        Vector::<2, i32, A>::new(vector.x.as_i32(), vector.y.as_i32())
+    }
+
+    #[inline]
+    fn vector_as_u32(vector: Vector<2, Self, A>) -> Vector<2, u32, A> {
+        Vector::<2, u32, A>::new(vector.x.as_u32(), vector.y.as_u32())
+    }
+}
+
+impl<T, A: Alignment> CastBackend<3, A> for T
+where
+    T: PrimitiveNumber + DefaultBackend<3, A>,
+{
+    #[inline]
+    fn vector_as_f32(vector: Vector<3, Self, A>) -> Vector<3, f32, A> {
+        Vector::<3, f32, A>::new(vector.x.as_f32(), vector.y.as_f32(), vector.z.as_f32())
+    }
+
+    #[inline]
+    fn vector_as_f64(vector: Vector<3, Self, A>) -> Vector<3, f64, A> {
+        Vector::<3, f64, A>::new(vector.x.as_f64(), vector.y.as_f64(), vector.z.as_f64())
+    }
+
+    #[inline]
+    fn vector_as_i32(vector: Vector<3, Self, A>) -> Vector<3, i32, A> {
+        Vector::<3, i32, A>::new(vector.x.as_i32(), vector.y.as_i32(), vector.z.as_i32())
+    }
+
+    #[inline]
+    fn vector_as_u32(vector: Vector<3, Self, A>) -> Vector<3, u32, A> {
+        Vector::<3, u32, A>::new(vector.x.as_u32(), vector.y.as_u32(), vector.z.as_u32())
+    }
+}
+
+impl<T, A: Alignment> CastBackend<4, A> for T
+where
+    T: PrimitiveNumber + DefaultBackend<4, A>,
+{
+    #[inline]
+    fn vector_as_f32(vector: Vector<4, Self, A>) -> Vector<4, f32, A> {
+        Vector::<4, f32, A>::new(
+            vector.x.as_f32(),
+            vector.y.as_f32(),
+            vector.z.as_f32(),
+            vector.w.as_f32(),
+        )
+    }
+
+    #[inline]
+    fn vector_as_f64(vector: Vector<4, Self, A>) -> Vector<4, f64, A> {
+        Vector::<4, f64, A>::new(
+            vector.x.as_f64(),
+            vector.y.as_f64(),
+            vector.z.as_f64(),
+            vector.w.as_f64(),
+        )
+    }
+
+    #[inline]
+    fn vector_as_i32(vector: Vector<4, Self, A>) -> Vector<4, i32, A> {
+        Vector::<4, i32, A>::new(
+            vector.x.as_i32(),
+            vector.y.as_i32(),
+            vector.z.as_i32(),
+            vector.w.as_i32(),
+        )
+    }
+
+    #[inline]
+    fn vector_as_u32(vector: Vector<4, Self, A>) -> Vector<4, u32, A> {
+        Vector::<4, u32, A>::new(
+            vector.x.as_u32(),
+            vector.y.as_u32(),
+            vector.z.as_u32(),
+            vector.w.as_u32(),
+        )
+    }
+}
+
+macro_rules! impl_cast_target {
+    ($T:ident, $f:ident) => {
+        impl CastTargetBackend for $T {
+            #[inline]
+            fn vector_cast_from<const N: usize, T: PrimitiveNumber, A: Alignment>(
+                vector: Vector<N, T, A>,
+            ) -> Vector<N, Self, A>
+            where
+                Length<N>: SupportedLength,
+            {
+                specialize!(<T as CastBackend<N, A>>::$f(vector))
+            }
+        }
+    };
+    ($T:ident) => {
+        impl CastTargetBackend for $T {
+            #[inline]
+            fn vector_cast_from<const N: usize, T: PrimitiveNumber, A: Alignment>(
+                vector: Vector<N, T, A>,
+            ) -> Vector<N, Self, A>
+            where
+                Length<N>: SupportedLength,
+            {
+                vector.map(Self::cast_from)
+            }
+        }
+    };
+}
+impl_cast_target!(f32, vector_as_f32);
+impl_cast_target!(f64, vector_as_f64);
+impl_cast_target!(i8);
+impl_cast_target!(i16);
+impl_cast_target!(i32, vector_as_i32);
+impl_cast_target!(i64);
+impl_cast_target!(i128);
+impl_cast_target!(isize);
+impl_cast_target!(u8);
+impl_cast_target!(u16);
+impl_cast_target!(u32, vector_as_u32);
+impl_cast_target!(u64);
+impl_cast_target!(u128);
+impl_cast_target!(usize);
+
+#[cfg(feature = "f16")]
+impl<A: Alignment> HalfBackend<2, A> for f32 {
+    #[inline]
+    fn vector_to_f16(vector: Vector<2, f32, A>) -> Vector<2, f16, A> {
+        vector.map(f16::from_f32)
+    }
+
+    #[inline]
+    fn vector_from_f16(vector: Vector<2, f16, A>) -> Vector<2, f32, A> {
+        vector.map(f16::to_f32)
+    }
+}
+
+#[cfg(feature = "f16")]
+impl<A: Alignment> HalfBackend<3, A> for f32 {
+    #[inline]
+    fn vector_to_f16(vector: Vector<3, f32, A>) -> Vector<3, f16, A> {
+        vector.map(f16::from_f32)
+    }
+
+    #[inline]
+    fn vector_from_f16(vector: Vector<3, f16, A>) -> Vector<3, f32, A> {
+        vector.map(f16::to_f32)
+    }
+}
+
+// SIMD backends with half-precision conversion instructions implement
+// `HalfBackend<4, A>` for `f32` themselves.
+#[cfg(all(
+    feature = "f16",
+    any(
+        feature = "force-fallback",
+        not(any(
+            target_feature = "f16c",
+            all(target_arch = "aarch64", target_feature = "neon")
+        )),
+    ),
+))]
+impl<A: Alignment> HalfBackend<4, A> for f32 {
+    #[inline]
+    fn vector_to_f16(vector: Vector<4, f32, A>) -> Vector<4, f16, A> {
+        vector.map(f16::from_f32)
+    }
+
+    #[inline]
+    fn vector_from_f16(vector: Vector<4, f16, A>) -> Vector<4, f32, A> {
+        vector.map(f16::to_f32)
+    }
+}
+
 impl<T, A: Alignment> FloatVectorBackend<2, A> for T
 where
     T: PrimitiveFloat + DefaultBackend<2, A>,
@@ -1673,6 +2249,29 @@ where
         )
     }
 
+    #[inline]
+    fn vector_rsqrt(vector: Vector<2, Self, A>) -> Vector<2, Self, A> {
+        Vector::<2, Self, A>::new(
+            Self::ONE / PrimitiveFloatUtils::sqrt(vector.x),
+            Self::ONE / PrimitiveFloatUtils::sqrt(vector.y),
+        )
+    }
+
+    #[inline]
+    fn vector_recip_fast(vector: Vector<2, Self, A>) -> Vector<2, Self, A> {
+        Vector::<2, Self, A>::new(Self::ONE / vector.x, Self::ONE / vector.y)
+    }
+
+    #[inline]
+    fn vector_length_recip_fast(vector: Vector<2, Self, A>) -> Self {
+        Self::ONE / vector.length()
+    }
+
+    #[inline]
+    fn vector_normalize_fast(vector: Vector<2, Self, A>) -> Vector<2, Self, A> {
+        vector / vector.length()
+    }
+
     #[inline]
     fn vector_exp(vector: Vector<2, Self, A>) -> Vector<2, Self, A> {
         Vector::<2, Self, A>::new(
@@ -1977,6 +2576,34 @@ where
         )
     }
 
+    #[inline]
+    fn vector_rsqrt(vector: Vector<3, Self, A>) -> Vector<3, Self, A> {
+        Vector::<3, Self, A>::new(
+            Self::ONE / PrimitiveFloatUtils::sqrt(vector.x),
+            Self::ONE / PrimitiveFloatUtils::sqrt(vector.y),
+            Self::ONE / PrimitiveFloatUtils::sqrt(vector.z),
+        )
+    }
+
+    #[inline]
+    fn vector_recip_fast(vector: Vector<3, Self, A>) -> Vector<3, Self, A> {
+        Vector::<3, Self, A>::new(
+            Self::ONE / vector.x,
+            Self::ONE / vector.y,
+            Self::ONE / vector.z,
+        )
+    }
+
+    #[inline]
+    fn vector_length_recip_fast(vector: Vector<3, Self, A>) -> Self {
+        Self::ONE / vector.length()
+    }
+
+    #[inline]
+    fn vector_normalize_fast(vector: Vector<3, Self, A>) -> Vector<3, Self, A> {
+        vector / vector.length()
+    }
+
     #[inline]
     fn vector_exp(vector: Vector<3, Self, A>) -> Vector<3, Self, A> {
         Vector::<3, Self, A>::new(
@@ -2336,6 +2963,36 @@ where
         )
     }
 
+    #[inline]
+    fn vector_rsqrt(vector: Vector<4, Self, A>) -> Vector<4, Self, A> {
+        Vector::<4, Self, A>::new(
+            Self::ONE / PrimitiveFloatUtils::sqrt(vector.x),
+            Self::ONE / PrimitiveFloatUtils::sqrt(vector.y),
+            Self::ONE / PrimitiveFloatUtils::sqrt(vector.z),
+            Self::ONE / PrimitiveFloatUtils::sqrt(vector.w),
+        )
+    }
+
+    #[inline]
+    fn vector_recip_fast(vector: Vector<4, Self, A>) -> Vector<4, Self, A> {
+        Vector::<4, Self, A>::new(
+            Self::ONE / vector.x,
+            Self::ONE / vector.y,
+            Self::ONE / vector.z,
+            Self::ONE / vector.w,
+        )
+    }
+
+    #[inline]
+    fn vector_length_recip_fast(vector: Vector<4, Self, A>) -> Self {
+        Self::ONE / vector.length()
+    }
+
+    #[inline]
+    fn vector_normalize_fast(vector: Vector<4, Self, A>) -> Vector<4, Self, A> {
+        vector / vector.length()
+    }
+
     #[inline]
     fn vector_exp(vector: Vector<4, Self, A>) -> Vector<4, Self, A> {
         Vector::<4, Self, A>::new(
@@ -2450,6 +3107,36 @@ where
     }
 }
 
+impl<T, A: Alignment> FloatMatrixBackend<2, A> for T
+where
+    T: PrimitiveFloat + DefaultBackend<2, A>,
+{
+    #[inline]
+    fn matrix_inverse_and_determinant(matrix: &Matrix<2, Self, A>) -> (Matrix<2, Self, A>, Self) {
+        matrix.inverse_and_determinant_generic()
+    }
+}
+
+impl<T, A: Alignment> FloatMatrixBackend<3, A> for T
+where
+    T: PrimitiveFloat + DefaultBackend<3, A>,
+{
+    #[inline]
+    fn matrix_inverse_and_determinant(matrix: &Matrix<3, Self, A>) -> (Matrix<3, Self, A>, Self) {
+        matrix.inverse_and_determinant_generic()
+    }
+}
+
+impl<T, A: Alignment> FloatMatrixBackend<4, A> for T
+where
+    T: PrimitiveFloat + DefaultBackend<4, A>,
+{
+    #[inline]
+    fn matrix_inverse_and_determinant(matrix: &Matrix<4, Self, A>) -> (Matrix<4, Self, A>, Self) {
+        matrix.inverse_and_determinant_generic()
+    }
+}
+
 impl<T, A: Alignment> IntegerVectorBackend<2, A> for T
 where
     T: PrimitiveInteger + DefaultBackend<2, A>,
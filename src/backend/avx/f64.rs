@@ -0,0 +1,1302 @@
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+use crate::utils::PrimitiveFloatUtils;
+use crate::{
+    Aligned, Mask, Mask3A, Mask4A, Mat3A, Mat4A, QuatA, Quaternion, Vec3A, Vec4A, Vector,
+    backend::{
+        CastBackend, FloatMatrixBackend, FloatVectorBackend, MaskBackend, MatrixBackend,
+        QuaternionBackend, VectorBackend,
+    },
+    utils::safe_target_feature,
+};
+
+// `Self::Inner` follows its requirements. The last element is padding.
+unsafe impl VectorBackend<3, Aligned> for f64 {
+    type Inner = __m256d;
+
+    safe_target_feature! {
+        #[inline]
+        fn vector_eq(vector: &Vec3A<f64>, other: &Vec3A<f64>) -> bool {
+            _mm256_movemask_pd(_mm256_cmp_pd::<_CMP_EQ_OQ>(vector.0, other.0)) & 0x7 == 0x7
+        }
+
+        #[inline]
+        fn vector_ne(vector: &Vec3A<f64>, other: &Vec3A<f64>) -> bool {
+            _mm256_movemask_pd(_mm256_cmp_pd::<_CMP_NEQ_UQ>(vector.0, other.0)) & 0x7 != 0
+        }
+
+        #[inline]
+        fn vector_neg(vector: Vec3A<f64>) -> Vec3A<f64> {
+            Vector(neg(vector.0))
+        }
+
+        #[inline]
+        fn vector_not(vector: Vec3A<f64>) -> Vec3A<f64> {
+            Vector(not(vector.0))
+        }
+
+        #[inline]
+        fn vector_add(vector: Vec3A<f64>, rhs: Vec3A<f64>) -> Vec3A<f64> {
+            Vector(_mm256_add_pd(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_sub(vector: Vec3A<f64>, rhs: Vec3A<f64>) -> Vec3A<f64> {
+            Vector(_mm256_sub_pd(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_mul(vector: Vec3A<f64>, rhs: Vec3A<f64>) -> Vec3A<f64> {
+            Vector(_mm256_mul_pd(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_div(vector: Vec3A<f64>, rhs: Vec3A<f64>) -> Vec3A<f64> {
+            Vector(_mm256_div_pd(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_rem(vector: Vec3A<f64>, rhs: Vec3A<f64>) -> Vec3A<f64> {
+            Vec3A::new(vector.x % rhs.x, vector.y % rhs.y, vector.z % rhs.z)
+        }
+
+        #[inline]
+        fn vector_shl(vector: Vec3A<f64>, rhs: Vec3A<f64>) -> Vec3A<f64> {
+            Vec3A::<f64>::from_bits(vector.to_bits() << rhs.to_bits())
+        }
+
+        #[inline]
+        fn vector_shr(vector: Vec3A<f64>, rhs: Vec3A<f64>) -> Vec3A<f64> {
+            Vec3A::<f64>::from_bits(vector.to_bits() >> rhs.to_bits())
+        }
+
+        #[inline]
+        fn vector_bitand(vector: Vec3A<f64>, rhs: Vec3A<f64>) -> Vec3A<f64> {
+            Vector(_mm256_and_pd(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_bitor(vector: Vec3A<f64>, rhs: Vec3A<f64>) -> Vec3A<f64> {
+            Vector(_mm256_or_pd(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_bitxor(vector: Vec3A<f64>, rhs: Vec3A<f64>) -> Vec3A<f64> {
+            Vector(_mm256_xor_pd(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_element_sum(vector: Vec3A<f64>) -> f64 {
+            let (xy, z) = halves(vector.0);
+            let sum = _mm_add_sd(xy, _mm_unpackhi_pd(xy, xy));
+            _mm_cvtsd_f64(_mm_add_sd(sum, z))
+        }
+
+        #[inline]
+        fn vector_element_product(vector: Vec3A<f64>) -> f64 {
+            let (xy, z) = halves(vector.0);
+            let product = _mm_mul_sd(xy, _mm_unpackhi_pd(xy, xy));
+            _mm_cvtsd_f64(_mm_mul_sd(product, z))
+        }
+
+        #[inline]
+        fn vector_dot(vector: Vec3A<f64>, rhs: Vec3A<f64>) -> f64 {
+            let (vector_xy, vector_z) = halves(vector.0);
+            let (rhs_xy, rhs_z) = halves(rhs.0);
+            let result = _mm_mul_sd(vector_xy, rhs_xy);
+            let result = mul_add_sd(
+                _mm_unpackhi_pd(vector_xy, vector_xy),
+                _mm_unpackhi_pd(rhs_xy, rhs_xy),
+                result,
+            );
+            _mm_cvtsd_f64(mul_add_sd(vector_z, rhs_z, result))
+        }
+
+        #[inline]
+        fn vector_mul_add_contracted(vector: Vec3A<f64>, a: Vec3A<f64>, b: Vec3A<f64>) -> Vec3A<f64> {
+            Vector(mul_add_contracted(vector.0, a.0, b.0))
+        }
+
+        #[inline]
+        fn vector_eq_mask(vector: Vec3A<f64>, other: Vec3A<f64>) -> Mask3A<f64> {
+            Mask(_mm256_cmp_pd::<_CMP_EQ_OQ>(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_ne_mask(vector: Vec3A<f64>, other: Vec3A<f64>) -> Mask3A<f64> {
+            Mask(_mm256_cmp_pd::<_CMP_NEQ_UQ>(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_lt_mask(vector: Vec3A<f64>, other: Vec3A<f64>) -> Mask3A<f64> {
+            Mask(_mm256_cmp_pd::<_CMP_LT_OQ>(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_gt_mask(vector: Vec3A<f64>, other: Vec3A<f64>) -> Mask3A<f64> {
+            Mask(_mm256_cmp_pd::<_CMP_GT_OQ>(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_le_mask(vector: Vec3A<f64>, other: Vec3A<f64>) -> Mask3A<f64> {
+            Mask(_mm256_cmp_pd::<_CMP_LE_OQ>(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_ge_mask(vector: Vec3A<f64>, other: Vec3A<f64>) -> Mask3A<f64> {
+            Mask(_mm256_cmp_pd::<_CMP_GE_OQ>(vector.0, other.0))
+        }
+    }
+}
+
+// `Self::Inner` follows its requirements.
+unsafe impl VectorBackend<4, Aligned> for f64 {
+    type Inner = __m256d;
+
+    safe_target_feature! {
+        #[inline]
+        fn vector_eq(vector: &Vec4A<f64>, other: &Vec4A<f64>) -> bool {
+            _mm256_movemask_pd(_mm256_cmp_pd::<_CMP_EQ_OQ>(vector.0, other.0)) == 0xf
+        }
+
+        #[inline]
+        fn vector_ne(vector: &Vec4A<f64>, other: &Vec4A<f64>) -> bool {
+            _mm256_movemask_pd(_mm256_cmp_pd::<_CMP_NEQ_UQ>(vector.0, other.0)) != 0
+        }
+
+        #[inline]
+        fn vector_neg(vector: Vec4A<f64>) -> Vec4A<f64> {
+            Vector(neg(vector.0))
+        }
+
+        #[inline]
+        fn vector_not(vector: Vec4A<f64>) -> Vec4A<f64> {
+            Vector(not(vector.0))
+        }
+
+        #[inline]
+        fn vector_add(vector: Vec4A<f64>, rhs: Vec4A<f64>) -> Vec4A<f64> {
+            Vector(_mm256_add_pd(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_sub(vector: Vec4A<f64>, rhs: Vec4A<f64>) -> Vec4A<f64> {
+            Vector(_mm256_sub_pd(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_mul(vector: Vec4A<f64>, rhs: Vec4A<f64>) -> Vec4A<f64> {
+            Vector(_mm256_mul_pd(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_div(vector: Vec4A<f64>, rhs: Vec4A<f64>) -> Vec4A<f64> {
+            Vector(_mm256_div_pd(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_rem(vector: Vec4A<f64>, rhs: Vec4A<f64>) -> Vec4A<f64> {
+            Vec4A::new(
+                vector.x % rhs.x,
+                vector.y % rhs.y,
+                vector.z % rhs.z,
+                vector.w % rhs.w,
+            )
+        }
+
+        #[inline]
+        fn vector_shl(vector: Vec4A<f64>, rhs: Vec4A<f64>) -> Vec4A<f64> {
+            Vec4A::<f64>::from_bits(vector.to_bits() << rhs.to_bits())
+        }
+
+        #[inline]
+        fn vector_shr(vector: Vec4A<f64>, rhs: Vec4A<f64>) -> Vec4A<f64> {
+            Vec4A::<f64>::from_bits(vector.to_bits() >> rhs.to_bits())
+        }
+
+        #[inline]
+        fn vector_bitand(vector: Vec4A<f64>, rhs: Vec4A<f64>) -> Vec4A<f64> {
+            Vector(_mm256_and_pd(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_bitor(vector: Vec4A<f64>, rhs: Vec4A<f64>) -> Vec4A<f64> {
+            Vector(_mm256_or_pd(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_bitxor(vector: Vec4A<f64>, rhs: Vec4A<f64>) -> Vec4A<f64> {
+            Vector(_mm256_xor_pd(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_element_sum(vector: Vec4A<f64>) -> f64 {
+            let (xy, zw) = halves(vector.0);
+            // `[x + y, z + w]`
+            let sums = _mm_add_pd(_mm_unpacklo_pd(xy, zw), _mm_unpackhi_pd(xy, zw));
+            _mm_cvtsd_f64(_mm_add_sd(sums, _mm_unpackhi_pd(sums, sums)))
+        }
+
+        #[inline]
+        fn vector_element_product(vector: Vec4A<f64>) -> f64 {
+            let (xy, zw) = halves(vector.0);
+            // `[x * y, z * w]`
+            let products = _mm_mul_pd(_mm_unpacklo_pd(xy, zw), _mm_unpackhi_pd(xy, zw));
+            _mm_cvtsd_f64(_mm_mul_sd(products, _mm_unpackhi_pd(products, products)))
+        }
+
+        #[inline]
+        fn vector_dot(vector: Vec4A<f64>, rhs: Vec4A<f64>) -> f64 {
+            let (vector_xy, vector_zw) = halves(vector.0);
+            let (rhs_xy, rhs_zw) = halves(rhs.0);
+            // `[x * x, z * z]`
+            let products = _mm_mul_pd(
+                _mm_unpacklo_pd(vector_xy, vector_zw),
+                _mm_unpacklo_pd(rhs_xy, rhs_zw),
+            );
+            // `[x * x + y * y, z * z + w * w]`
+            let sums = mul_add_pd(
+                _mm_unpackhi_pd(vector_xy, vector_zw),
+                _mm_unpackhi_pd(rhs_xy, rhs_zw),
+                products,
+            );
+            _mm_cvtsd_f64(_mm_add_sd(sums, _mm_unpackhi_pd(sums, sums)))
+        }
+
+        #[inline]
+        fn vector_mul_add_contracted(vector: Vec4A<f64>, a: Vec4A<f64>, b: Vec4A<f64>) -> Vec4A<f64> {
+            Vector(mul_add_contracted(vector.0, a.0, b.0))
+        }
+
+        #[inline]
+        fn vector_eq_mask(vector: Vec4A<f64>, other: Vec4A<f64>) -> Mask4A<f64> {
+            Mask(_mm256_cmp_pd::<_CMP_EQ_OQ>(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_ne_mask(vector: Vec4A<f64>, other: Vec4A<f64>) -> Mask4A<f64> {
+            Mask(_mm256_cmp_pd::<_CMP_NEQ_UQ>(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_lt_mask(vector: Vec4A<f64>, other: Vec4A<f64>) -> Mask4A<f64> {
+            Mask(_mm256_cmp_pd::<_CMP_LT_OQ>(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_gt_mask(vector: Vec4A<f64>, other: Vec4A<f64>) -> Mask4A<f64> {
+            Mask(_mm256_cmp_pd::<_CMP_GT_OQ>(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_le_mask(vector: Vec4A<f64>, other: Vec4A<f64>) -> Mask4A<f64> {
+            Mask(_mm256_cmp_pd::<_CMP_LE_OQ>(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_ge_mask(vector: Vec4A<f64>, other: Vec4A<f64>) -> Mask4A<f64> {
+            Mask(_mm256_cmp_pd::<_CMP_GE_OQ>(vector.0, other.0))
+        }
+    }
+}
+
+impl QuaternionBackend<Aligned> for f64 {
+    safe_target_feature! {
+        #[inline]
+        fn quat_mul(quat: QuatA<f64>, rhs: QuatA<f64>) -> QuatA<f64> {
+            const PNPN: Vec4A<f64> = Vec4A::new(0.0, -0.0, 0.0, -0.0);
+            const PPNN: Vec4A<f64> = Vec4A::new(0.0, 0.0, -0.0, -0.0);
+            const NPPN: Vec4A<f64> = Vec4A::new(-0.0, 0.0, 0.0, -0.0);
+
+            // Flipping signs before the multiplication is equivalent to
+            // flipping them after it, and lets the additions fuse with FMA.
+            let result = _mm256_mul_pd(quat.0.0, rhs.0.wwww().0);
+            let result = mul_add_contracted(
+                _mm256_xor_pd(quat.0.wzyx().0, PNPN.0),
+                rhs.0.xxxx().0,
+                result,
+            );
+            let result = mul_add_contracted(
+                _mm256_xor_pd(quat.0.zwxy().0, PPNN.0),
+                rhs.0.yyyy().0,
+                result,
+            );
+            let result = mul_add_contracted(
+                _mm256_xor_pd(quat.0.yxwz().0, NPPN.0),
+                rhs.0.zzzz().0,
+                result,
+            );
+
+            Quaternion(Vector(result))
+        }
+    }
+}
+
+impl MatrixBackend<3, Aligned> for f64 {
+    #[inline]
+    fn matrix_transpose(matrix: &Mat3A<f64>) -> Mat3A<f64> {
+        matrix.transpose_generic()
+    }
+
+    #[inline]
+    fn matrix_mul(matrix: &Mat3A<f64>, rhs: &Mat3A<f64>) -> Mat3A<f64> {
+        matrix.mul_generic(rhs)
+    }
+
+    #[inline]
+    fn matrix_determinant(matrix: &Mat3A<f64>) -> f64 {
+        matrix.determinant_generic()
+    }
+}
+
+impl MatrixBackend<4, Aligned> for f64 {
+    #[inline]
+    fn matrix_transpose(matrix: &Mat4A<f64>) -> Mat4A<f64> {
+        matrix.transpose_generic()
+    }
+
+    #[inline]
+    fn matrix_mul(matrix: &Mat4A<f64>, rhs: &Mat4A<f64>) -> Mat4A<f64> {
+        matrix.mul_generic(rhs)
+    }
+
+    #[inline]
+    fn matrix_determinant(matrix: &Mat4A<f64>) -> f64 {
+        matrix.determinant_generic()
+    }
+}
+
+// `Self::Inner` follows its requirements.
+unsafe impl MaskBackend<3, Aligned> for f64 {
+    type Inner = __m256d;
+
+    safe_target_feature! {
+        #[inline]
+        fn mask_from_array(array: [bool; 3]) -> Mask3A<f64> {
+            Mask(_mm256_castsi256_pd(_mm256_set_epi64x(
+                -(array[2] as i64),
+                -(array[2] as i64),
+                -(array[1] as i64),
+                -(array[0] as i64),
+            )))
+        }
+
+        #[inline]
+        fn mask_splat(value: bool) -> Mask3A<f64> {
+            Mask(_mm256_castsi256_pd(_mm256_set1_epi64x(-(value as i64))))
+        }
+
+        #[inline]
+        fn mask_to_array(mask: Mask3A<f64>) -> [bool; 3] {
+            let bits = _mm256_movemask_pd(mask.0);
+            [bits & 0x1 != 0, bits & 0x2 != 0, bits & 0x4 != 0]
+        }
+
+        #[inline]
+        fn mask_all(mask: Mask3A<f64>) -> bool {
+            _mm256_movemask_pd(mask.0) & 0x7 == 0x7
+        }
+
+        #[inline]
+        fn mask_any(mask: Mask3A<f64>) -> bool {
+            _mm256_movemask_pd(mask.0) & 0x7 != 0
+        }
+
+        #[inline]
+        fn mask_select(mask: Mask3A<f64>, if_true: Vec3A<f64>, if_false: Vec3A<f64>) -> Vec3A<f64> {
+            Vector(_mm256_blendv_pd(if_false.0, if_true.0, mask.0))
+        }
+
+        #[inline]
+        fn mask_get(mask: Mask3A<f64>, index: usize) -> bool {
+            match index {
+                0 => _mm256_movemask_pd(mask.0) & 0x1 != 0,
+                1 => _mm256_movemask_pd(mask.0) & 0x2 != 0,
+                2 => _mm256_movemask_pd(mask.0) & 0x4 != 0,
+                _ => panic!("index out of bounds"),
+            }
+        }
+
+        #[inline]
+        fn mask_set(mask: &mut Mask3A<f64>, index: usize, value: bool) {
+            if index < 3 {
+                // SAFETY: `*mut __m256d` is valid as `*mut i64` for 4 values.
+                // Adding `index` is valid because it was just checked to be
+                // less then 3, and the result is a pointer to a valid `i64`.
+                let slot = unsafe {
+                    core::ptr::from_mut::<__m256d>(&mut mask.0)
+                        .cast::<i64>()
+                        .add(index)
+                        .as_mut()
+                        .unwrap_unchecked()
+                };
+
+                *slot = -(value as i64);
+            } else {
+                panic!("index out of bounds")
+            }
+        }
+
+        #[inline]
+        fn mask_eq(mask: &Mask3A<f64>, other: &Mask3A<f64>) -> bool {
+            _mm256_movemask_pd(mask.0) & 0x7 == _mm256_movemask_pd(other.0) & 0x7
+        }
+
+        #[inline]
+        fn mask_ne(mask: &Mask3A<f64>, other: &Mask3A<f64>) -> bool {
+            !(mask == other)
+        }
+
+        #[inline]
+        fn mask_not(mask: Mask3A<f64>) -> Mask3A<f64> {
+            Mask(not(mask.0))
+        }
+
+        #[inline]
+        fn mask_bitand(mask: Mask3A<f64>, rhs: Mask3A<f64>) -> Mask3A<f64> {
+            Mask(_mm256_and_pd(mask.0, rhs.0))
+        }
+
+        #[inline]
+        fn mask_bitor(mask: Mask3A<f64>, rhs: Mask3A<f64>) -> Mask3A<f64> {
+            Mask(_mm256_or_pd(mask.0, rhs.0))
+        }
+
+        #[inline]
+        fn mask_bitxor(mask: Mask3A<f64>, rhs: Mask3A<f64>) -> Mask3A<f64> {
+            Mask(_mm256_xor_pd(mask.0, rhs.0))
+        }
+    }
+}
+
+// `Self::Inner` follows its requirements.
+unsafe impl MaskBackend<4, Aligned> for f64 {
+    type Inner = __m256d;
+
+    safe_target_feature! {
+        #[inline]
+        fn mask_from_array(array: [bool; 4]) -> Mask4A<f64> {
+            Mask(_mm256_castsi256_pd(_mm256_set_epi64x(
+                -(array[3] as i64),
+                -(array[2] as i64),
+                -(array[1] as i64),
+                -(array[0] as i64),
+            )))
+        }
+
+        #[inline]
+        fn mask_splat(value: bool) -> Mask4A<f64> {
+            Mask(_mm256_castsi256_pd(_mm256_set1_epi64x(-(value as i64))))
+        }
+
+        #[inline]
+        fn mask_to_array(mask: Mask4A<f64>) -> [bool; 4] {
+            let bits = _mm256_movemask_pd(mask.0);
+            [
+                bits & 0x1 != 0,
+                bits & 0x2 != 0,
+                bits & 0x4 != 0,
+                bits & 0x8 != 0,
+            ]
+        }
+
+        #[inline]
+        fn mask_all(mask: Mask4A<f64>) -> bool {
+            _mm256_movemask_pd(mask.0) == 0xf
+        }
+
+        #[inline]
+        fn mask_any(mask: Mask4A<f64>) -> bool {
+            _mm256_movemask_pd(mask.0) != 0
+        }
+
+        #[inline]
+        fn mask_select(mask: Mask4A<f64>, if_true: Vec4A<f64>, if_false: Vec4A<f64>) -> Vec4A<f64> {
+            Vector(_mm256_blendv_pd(if_false.0, if_true.0, mask.0))
+        }
+
+        #[inline]
+        fn mask_get(mask: Mask4A<f64>, index: usize) -> bool {
+            match index {
+                0 => _mm256_movemask_pd(mask.0) & 0x1 != 0,
+                1 => _mm256_movemask_pd(mask.0) & 0x2 != 0,
+                2 => _mm256_movemask_pd(mask.0) & 0x4 != 0,
+                3 => _mm256_movemask_pd(mask.0) & 0x8 != 0,
+                _ => panic!("index out of bounds"),
+            }
+        }
+
+        #[inline]
+        fn mask_set(mask: &mut Mask4A<f64>, index: usize, value: bool) {
+            if index < 4 {
+                // SAFETY: `*mut __m256d` is valid as `*mut i64` for 4 values.
+                // Adding `index` is valid because it was just checked to be
+                // less then 4, and the result is a pointer to a valid `i64`.
+                let slot = unsafe {
+                    core::ptr::from_mut::<__m256d>(&mut mask.0)
+                        .cast::<i64>()
+                        .add(index)
+                        .as_mut()
+                        .unwrap_unchecked()
+                };
+
+                *slot = -(value as i64);
+            } else {
+                panic!("index out of bounds")
+            }
+        }
+
+        #[inline]
+        fn mask_eq(mask: &Mask4A<f64>, other: &Mask4A<f64>) -> bool {
+            _mm256_movemask_pd(mask.0) == _mm256_movemask_pd(other.0)
+        }
+
+        #[inline]
+        fn mask_ne(mask: &Mask4A<f64>, other: &Mask4A<f64>) -> bool {
+            !(mask == other)
+        }
+
+        #[inline]
+        fn mask_not(mask: Mask4A<f64>) -> Mask4A<f64> {
+            Mask(not(mask.0))
+        }
+
+        #[inline]
+        fn mask_bitand(mask: Mask4A<f64>, rhs: Mask4A<f64>) -> Mask4A<f64> {
+            Mask(_mm256_and_pd(mask.0, rhs.0))
+        }
+
+        #[inline]
+        fn mask_bitor(mask: Mask4A<f64>, rhs: Mask4A<f64>) -> Mask4A<f64> {
+            Mask(_mm256_or_pd(mask.0, rhs.0))
+        }
+
+        #[inline]
+        fn mask_bitxor(mask: Mask4A<f64>, rhs: Mask4A<f64>) -> Mask4A<f64> {
+            Mask(_mm256_xor_pd(mask.0, rhs.0))
+        }
+    }
+}
+
+impl FloatVectorBackend<3, Aligned> for f64 {
+    safe_target_feature! {
+        #[inline]
+        fn vector_nan_mask(vector: Vec3A<f64>) -> Mask3A<f64> {
+            Mask(_mm256_cmp_pd::<_CMP_UNORD_Q>(vector.0, vector.0))
+        }
+
+        #[inline]
+        fn vector_finite_mask(vector: Vec3A<f64>) -> Mask3A<f64> {
+            Mask(finite_mask(vector.0))
+        }
+
+        #[inline]
+        fn vector_sign_positive_mask(vector: Vec3A<f64>) -> Mask3A<f64> {
+            Mask(not(sign_negative_mask(vector.0)))
+        }
+
+        #[inline]
+        fn vector_sign_negative_mask(vector: Vec3A<f64>) -> Mask3A<f64> {
+            Mask(sign_negative_mask(vector.0))
+        }
+
+        #[inline]
+        fn vector_max(vector: Vec3A<f64>, other: Vec3A<f64>) -> Vec3A<f64> {
+            Vector(_mm256_max_pd(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_min(vector: Vec3A<f64>, other: Vec3A<f64>) -> Vec3A<f64> {
+            Vector(_mm256_min_pd(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_abs(vector: Vec3A<f64>) -> Vec3A<f64> {
+            Vector(abs(vector.0))
+        }
+
+        #[inline]
+        fn vector_signum(vector: Vec3A<f64>) -> Vec3A<f64> {
+            Vector(signum(vector.0))
+        }
+
+        #[inline]
+        fn vector_copysign(vector: Vec3A<f64>, sign: Vec3A<f64>) -> Vec3A<f64> {
+            Vector(copysign(vector.0, sign.0))
+        }
+
+        #[inline]
+        fn vector_max_element(vector: Vec3A<f64>) -> f64 {
+            let (xy, z) = halves(vector.0);
+            let max = _mm_max_sd(xy, _mm_unpackhi_pd(xy, xy));
+            _mm_cvtsd_f64(_mm_max_sd(max, z))
+        }
+
+        #[inline]
+        fn vector_min_element(vector: Vec3A<f64>) -> f64 {
+            let (xy, z) = halves(vector.0);
+            let min = _mm_min_sd(xy, _mm_unpackhi_pd(xy, xy));
+            _mm_cvtsd_f64(_mm_min_sd(min, z))
+        }
+
+        #[inline(always)]
+        fn vector_floor(vector: Vec3A<f64>) -> Vec3A<f64> {
+            Vector(_mm256_floor_pd(vector.0))
+        }
+
+        #[inline(always)]
+        fn vector_ceil(vector: Vec3A<f64>) -> Vec3A<f64> {
+            Vector(_mm256_ceil_pd(vector.0))
+        }
+
+        #[inline(always)]
+        fn vector_round(vector: Vec3A<f64>) -> Vec3A<f64> {
+            Vector(round(vector.0))
+        }
+
+        #[inline(always)]
+        fn vector_trunc(vector: Vec3A<f64>) -> Vec3A<f64> {
+            Vector(trunc(vector.0))
+        }
+
+        #[cfg(target_feature = "fma")]
+        #[inline(always)]
+        fn vector_mul_add(vector: Vec3A<f64>, a: Vec3A<f64>, b: Vec3A<f64>) -> Vec3A<f64> {
+            Vector(_mm256_fmadd_pd(vector.0, a.0, b.0))
+        }
+
+        #[cfg(not(target_feature = "fma"))]
+        #[inline(always)]
+        fn vector_mul_add(vector: Vec3A<f64>, a: Vec3A<f64>, b: Vec3A<f64>) -> Vec3A<f64> {
+            Vec3A::new(
+                vector.x.mul_add(a.x, b.x),
+                vector.y.mul_add(a.y, b.y),
+                vector.z.mul_add(a.z, b.z),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_div_euclid(vector: Vec3A<f64>, rhs: Vec3A<f64>) -> Vec3A<f64> {
+            Vec3A::new(
+                vector.x.div_euclid(rhs.x),
+                vector.y.div_euclid(rhs.y),
+                vector.z.div_euclid(rhs.z),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_rem_euclid(vector: Vec3A<f64>, rhs: Vec3A<f64>) -> Vec3A<f64> {
+            Vec3A::new(
+                vector.x.rem_euclid(rhs.x),
+                vector.y.rem_euclid(rhs.y),
+                vector.z.rem_euclid(rhs.z),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_powf(vector: Vec3A<f64>, n: f64) -> Vec3A<f64> {
+            Vec3A::new(
+                PrimitiveFloatUtils::powf(vector.x, n),
+                PrimitiveFloatUtils::powf(vector.y, n),
+                PrimitiveFloatUtils::powf(vector.z, n),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_sqrt(vector: Vec3A<f64>) -> Vec3A<f64> {
+            Vector(_mm256_sqrt_pd(vector.0))
+        }
+
+        #[inline]
+        fn vector_rsqrt(vector: Vec3A<f64>) -> Vec3A<f64> {
+            Vec3A::ONE / vector.sqrt()
+        }
+
+        #[inline]
+        fn vector_recip_fast(vector: Vec3A<f64>) -> Vec3A<f64> {
+            vector.recip()
+        }
+
+        #[inline]
+        fn vector_length_recip_fast(vector: Vec3A<f64>) -> f64 {
+            vector.length().recip()
+        }
+
+        #[inline]
+        fn vector_normalize_fast(vector: Vec3A<f64>) -> Vec3A<f64> {
+            vector / vector.length()
+        }
+
+        #[inline(always)]
+        fn vector_exp(vector: Vec3A<f64>) -> Vec3A<f64> {
+            Vec3A::new(
+                PrimitiveFloatUtils::exp(vector.x),
+                PrimitiveFloatUtils::exp(vector.y),
+                PrimitiveFloatUtils::exp(vector.z),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_exp2(vector: Vec3A<f64>) -> Vec3A<f64> {
+            Vec3A::new(
+                PrimitiveFloatUtils::exp2(vector.x),
+                PrimitiveFloatUtils::exp2(vector.y),
+                PrimitiveFloatUtils::exp2(vector.z),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_ln(vector: Vec3A<f64>) -> Vec3A<f64> {
+            Vec3A::new(
+                PrimitiveFloatUtils::ln(vector.x),
+                PrimitiveFloatUtils::ln(vector.y),
+                PrimitiveFloatUtils::ln(vector.z),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_log2(vector: Vec3A<f64>) -> Vec3A<f64> {
+            Vec3A::new(
+                PrimitiveFloatUtils::log2(vector.x),
+                PrimitiveFloatUtils::log2(vector.y),
+                PrimitiveFloatUtils::log2(vector.z),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_sin(vector: Vec3A<f64>) -> Vec3A<f64> {
+            Vec3A::new(
+                PrimitiveFloatUtils::sin(vector.x),
+                PrimitiveFloatUtils::sin(vector.y),
+                PrimitiveFloatUtils::sin(vector.z),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_cos(vector: Vec3A<f64>) -> Vec3A<f64> {
+            Vec3A::new(
+                PrimitiveFloatUtils::cos(vector.x),
+                PrimitiveFloatUtils::cos(vector.y),
+                PrimitiveFloatUtils::cos(vector.z),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_tan(vector: Vec3A<f64>) -> Vec3A<f64> {
+            Vec3A::new(
+                PrimitiveFloatUtils::tan(vector.x),
+                PrimitiveFloatUtils::tan(vector.y),
+                PrimitiveFloatUtils::tan(vector.z),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_asin(vector: Vec3A<f64>) -> Vec3A<f64> {
+            Vec3A::new(
+                PrimitiveFloatUtils::asin(vector.x),
+                PrimitiveFloatUtils::asin(vector.y),
+                PrimitiveFloatUtils::asin(vector.z),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_acos(vector: Vec3A<f64>) -> Vec3A<f64> {
+            Vec3A::new(
+                PrimitiveFloatUtils::acos(vector.x),
+                PrimitiveFloatUtils::acos(vector.y),
+                PrimitiveFloatUtils::acos(vector.z),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_atan(vector: Vec3A<f64>) -> Vec3A<f64> {
+            Vec3A::new(
+                PrimitiveFloatUtils::atan(vector.x),
+                PrimitiveFloatUtils::atan(vector.y),
+                PrimitiveFloatUtils::atan(vector.z),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_sin_cos(vector: Vec3A<f64>) -> (Vec3A<f64>, Vec3A<f64>) {
+            let x_sin_cos = PrimitiveFloatUtils::sin_cos(vector.x);
+            let y_sin_cos = PrimitiveFloatUtils::sin_cos(vector.y);
+            let z_sin_cos = PrimitiveFloatUtils::sin_cos(vector.z);
+            (
+                Vec3A::new(x_sin_cos.0, y_sin_cos.0, z_sin_cos.0),
+                Vec3A::new(x_sin_cos.1, y_sin_cos.1, z_sin_cos.1),
+            )
+        }
+    }
+}
+
+impl FloatVectorBackend<4, Aligned> for f64 {
+    safe_target_feature! {
+        #[inline]
+        fn vector_nan_mask(vector: Vec4A<f64>) -> Mask4A<f64> {
+            Mask(_mm256_cmp_pd::<_CMP_UNORD_Q>(vector.0, vector.0))
+        }
+
+        #[inline]
+        fn vector_finite_mask(vector: Vec4A<f64>) -> Mask4A<f64> {
+            Mask(finite_mask(vector.0))
+        }
+
+        #[inline]
+        fn vector_sign_positive_mask(vector: Vec4A<f64>) -> Mask4A<f64> {
+            Mask(not(sign_negative_mask(vector.0)))
+        }
+
+        #[inline]
+        fn vector_sign_negative_mask(vector: Vec4A<f64>) -> Mask4A<f64> {
+            Mask(sign_negative_mask(vector.0))
+        }
+
+        #[inline]
+        fn vector_max(vector: Vec4A<f64>, other: Vec4A<f64>) -> Vec4A<f64> {
+            Vector(_mm256_max_pd(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_min(vector: Vec4A<f64>, other: Vec4A<f64>) -> Vec4A<f64> {
+            Vector(_mm256_min_pd(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_abs(vector: Vec4A<f64>) -> Vec4A<f64> {
+            Vector(abs(vector.0))
+        }
+
+        #[inline]
+        fn vector_signum(vector: Vec4A<f64>) -> Vec4A<f64> {
+            Vector(signum(vector.0))
+        }
+
+        #[inline]
+        fn vector_copysign(vector: Vec4A<f64>, sign: Vec4A<f64>) -> Vec4A<f64> {
+            Vector(copysign(vector.0, sign.0))
+        }
+
+        #[inline]
+        fn vector_max_element(vector: Vec4A<f64>) -> f64 {
+            let (xy, zw) = halves(vector.0);
+            let vector = _mm_max_pd(xy, zw);
+            _mm_cvtsd_f64(_mm_max_sd(vector, _mm_unpackhi_pd(vector, vector)))
+        }
+
+        #[inline]
+        fn vector_min_element(vector: Vec4A<f64>) -> f64 {
+            let (xy, zw) = halves(vector.0);
+            let vector = _mm_min_pd(xy, zw);
+            _mm_cvtsd_f64(_mm_min_sd(vector, _mm_unpackhi_pd(vector, vector)))
+        }
+
+        #[inline(always)]
+        fn vector_floor(vector: Vec4A<f64>) -> Vec4A<f64> {
+            Vector(_mm256_floor_pd(vector.0))
+        }
+
+        #[inline(always)]
+        fn vector_ceil(vector: Vec4A<f64>) -> Vec4A<f64> {
+            Vector(_mm256_ceil_pd(vector.0))
+        }
+
+        #[inline(always)]
+        fn vector_round(vector: Vec4A<f64>) -> Vec4A<f64> {
+            Vector(round(vector.0))
+        }
+
+        #[inline(always)]
+        fn vector_trunc(vector: Vec4A<f64>) -> Vec4A<f64> {
+            Vector(trunc(vector.0))
+        }
+
+        #[cfg(target_feature = "fma")]
+        #[inline(always)]
+        fn vector_mul_add(vector: Vec4A<f64>, a: Vec4A<f64>, b: Vec4A<f64>) -> Vec4A<f64> {
+            Vector(_mm256_fmadd_pd(vector.0, a.0, b.0))
+        }
+
+        #[cfg(not(target_feature = "fma"))]
+        #[inline(always)]
+        fn vector_mul_add(vector: Vec4A<f64>, a: Vec4A<f64>, b: Vec4A<f64>) -> Vec4A<f64> {
+            Vec4A::new(
+                vector.x.mul_add(a.x, b.x),
+                vector.y.mul_add(a.y, b.y),
+                vector.z.mul_add(a.z, b.z),
+                vector.w.mul_add(a.w, b.w),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_div_euclid(vector: Vec4A<f64>, rhs: Vec4A<f64>) -> Vec4A<f64> {
+            Vec4A::new(
+                vector.x.div_euclid(rhs.x),
+                vector.y.div_euclid(rhs.y),
+                vector.z.div_euclid(rhs.z),
+                vector.w.div_euclid(rhs.w),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_rem_euclid(vector: Vec4A<f64>, rhs: Vec4A<f64>) -> Vec4A<f64> {
+            Vec4A::new(
+                vector.x.rem_euclid(rhs.x),
+                vector.y.rem_euclid(rhs.y),
+                vector.z.rem_euclid(rhs.z),
+                vector.w.rem_euclid(rhs.w),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_powf(vector: Vec4A<f64>, n: f64) -> Vec4A<f64> {
+            Vec4A::new(
+                PrimitiveFloatUtils::powf(vector.x, n),
+                PrimitiveFloatUtils::powf(vector.y, n),
+                PrimitiveFloatUtils::powf(vector.z, n),
+                PrimitiveFloatUtils::powf(vector.w, n),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_sqrt(vector: Vec4A<f64>) -> Vec4A<f64> {
+            Vector(_mm256_sqrt_pd(vector.0))
+        }
+
+        #[inline]
+        fn vector_rsqrt(vector: Vec4A<f64>) -> Vec4A<f64> {
+            Vec4A::ONE / vector.sqrt()
+        }
+
+        #[inline]
+        fn vector_recip_fast(vector: Vec4A<f64>) -> Vec4A<f64> {
+            vector.recip()
+        }
+
+        #[inline]
+        fn vector_length_recip_fast(vector: Vec4A<f64>) -> f64 {
+            vector.length().recip()
+        }
+
+        #[inline]
+        fn vector_normalize_fast(vector: Vec4A<f64>) -> Vec4A<f64> {
+            vector / vector.length()
+        }
+
+        #[inline(always)]
+        fn vector_exp(vector: Vec4A<f64>) -> Vec4A<f64> {
+            Vec4A::new(
+                PrimitiveFloatUtils::exp(vector.x),
+                PrimitiveFloatUtils::exp(vector.y),
+                PrimitiveFloatUtils::exp(vector.z),
+                PrimitiveFloatUtils::exp(vector.w),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_exp2(vector: Vec4A<f64>) -> Vec4A<f64> {
+            Vec4A::new(
+                PrimitiveFloatUtils::exp2(vector.x),
+                PrimitiveFloatUtils::exp2(vector.y),
+                PrimitiveFloatUtils::exp2(vector.z),
+                PrimitiveFloatUtils::exp2(vector.w),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_ln(vector: Vec4A<f64>) -> Vec4A<f64> {
+            Vec4A::new(
+                PrimitiveFloatUtils::ln(vector.x),
+                PrimitiveFloatUtils::ln(vector.y),
+                PrimitiveFloatUtils::ln(vector.z),
+                PrimitiveFloatUtils::ln(vector.w),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_log2(vector: Vec4A<f64>) -> Vec4A<f64> {
+            Vec4A::new(
+                PrimitiveFloatUtils::log2(vector.x),
+                PrimitiveFloatUtils::log2(vector.y),
+                PrimitiveFloatUtils::log2(vector.z),
+                PrimitiveFloatUtils::log2(vector.w),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_sin(vector: Vec4A<f64>) -> Vec4A<f64> {
+            Vec4A::new(
+                PrimitiveFloatUtils::sin(vector.x),
+                PrimitiveFloatUtils::sin(vector.y),
+                PrimitiveFloatUtils::sin(vector.z),
+                PrimitiveFloatUtils::sin(vector.w),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_cos(vector: Vec4A<f64>) -> Vec4A<f64> {
+            Vec4A::new(
+                PrimitiveFloatUtils::cos(vector.x),
+                PrimitiveFloatUtils::cos(vector.y),
+                PrimitiveFloatUtils::cos(vector.z),
+                PrimitiveFloatUtils::cos(vector.w),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_tan(vector: Vec4A<f64>) -> Vec4A<f64> {
+            Vec4A::new(
+                PrimitiveFloatUtils::tan(vector.x),
+                PrimitiveFloatUtils::tan(vector.y),
+                PrimitiveFloatUtils::tan(vector.z),
+                PrimitiveFloatUtils::tan(vector.w),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_asin(vector: Vec4A<f64>) -> Vec4A<f64> {
+            Vec4A::new(
+                PrimitiveFloatUtils::asin(vector.x),
+                PrimitiveFloatUtils::asin(vector.y),
+                PrimitiveFloatUtils::asin(vector.z),
+                PrimitiveFloatUtils::asin(vector.w),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_acos(vector: Vec4A<f64>) -> Vec4A<f64> {
+            Vec4A::new(
+                PrimitiveFloatUtils::acos(vector.x),
+                PrimitiveFloatUtils::acos(vector.y),
+                PrimitiveFloatUtils::acos(vector.z),
+                PrimitiveFloatUtils::acos(vector.w),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_atan(vector: Vec4A<f64>) -> Vec4A<f64> {
+            Vec4A::new(
+                PrimitiveFloatUtils::atan(vector.x),
+                PrimitiveFloatUtils::atan(vector.y),
+                PrimitiveFloatUtils::atan(vector.z),
+                PrimitiveFloatUtils::atan(vector.w),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_sin_cos(vector: Vec4A<f64>) -> (Vec4A<f64>, Vec4A<f64>) {
+            let x_sin_cos = PrimitiveFloatUtils::sin_cos(vector.x);
+            let y_sin_cos = PrimitiveFloatUtils::sin_cos(vector.y);
+            let z_sin_cos = PrimitiveFloatUtils::sin_cos(vector.z);
+            let w_sin_cos = PrimitiveFloatUtils::sin_cos(vector.w);
+            (
+                Vec4A::new(x_sin_cos.0, y_sin_cos.0, z_sin_cos.0, w_sin_cos.0),
+                Vec4A::new(x_sin_cos.1, y_sin_cos.1, z_sin_cos.1, w_sin_cos.1),
+            )
+        }
+    }
+}
+
+impl FloatMatrixBackend<3, Aligned> for f64 {
+    #[inline]
+    fn matrix_inverse_and_determinant(matrix: &Mat3A<f64>) -> (Mat3A<f64>, f64) {
+        matrix.inverse_and_determinant_generic()
+    }
+}
+
+impl FloatMatrixBackend<4, Aligned> for f64 {
+    #[inline]
+    fn matrix_inverse_and_determinant(matrix: &Mat4A<f64>) -> (Mat4A<f64>, f64) {
+        matrix.inverse_and_determinant_generic()
+    }
+}
+
+impl CastBackend<3, Aligned> for f64 {
+    safe_target_feature! {
+        #[inline]
+        fn vector_as_f32(vector: Vec3A<f64>) -> Vec3A<f32> {
+            Vector(_mm256_cvtpd_ps(vector.0))
+        }
+
+        #[inline]
+        fn vector_as_f64(vector: Vec3A<f64>) -> Vec3A<f64> {
+            vector
+        }
+
+        #[inline]
+        fn vector_as_i32(vector: Vec3A<f64>) -> Vec3A<i32> {
+            Vector(cvt_i32(vector.0))
+        }
+
+        #[inline]
+        fn vector_as_u32(vector: Vec3A<f64>) -> Vec3A<u32> {
+            Vector(cvt_u32(vector.0))
+        }
+    }
+}
+
+impl CastBackend<4, Aligned> for f64 {
+    safe_target_feature! {
+        #[inline]
+        fn vector_as_f32(vector: Vec4A<f64>) -> Vec4A<f32> {
+            Vector(_mm256_cvtpd_ps(vector.0))
+        }
+
+        #[inline]
+        fn vector_as_f64(vector: Vec4A<f64>) -> Vec4A<f64> {
+            vector
+        }
+
+        #[inline]
+        fn vector_as_i32(vector: Vec4A<f64>) -> Vec4A<i32> {
+            Vector(cvt_i32(vector.0))
+        }
+
+        #[inline]
+        fn vector_as_u32(vector: Vec4A<f64>) -> Vec4A<u32> {
+            Vector(cvt_u32(vector.0))
+        }
+    }
+}
+
+safe_target_feature! {
+    #[inline]
+    fn halves(vector: __m256d) -> (__m128d, __m128d) {
+        (_mm256_castpd256_pd128(vector), _mm256_extractf128_pd::<1>(vector))
+    }
+
+    #[cfg(any(not(target_feature = "fma"), feature = "deterministic"))]
+    #[inline]
+    fn mul_add_contracted(vector: __m256d, a: __m256d, b: __m256d) -> __m256d {
+        _mm256_add_pd(_mm256_mul_pd(vector, a), b)
+    }
+
+    #[cfg(all(target_feature = "fma", not(feature = "deterministic")))]
+    #[inline]
+    fn mul_add_contracted(vector: __m256d, a: __m256d, b: __m256d) -> __m256d {
+        _mm256_fmadd_pd(vector, a, b)
+    }
+
+    #[cfg(any(not(target_feature = "fma"), feature = "deterministic"))]
+    #[inline]
+    fn mul_add_pd(vector: __m128d, a: __m128d, b: __m128d) -> __m128d {
+        _mm_add_pd(_mm_mul_pd(vector, a), b)
+    }
+
+    #[cfg(all(target_feature = "fma", not(feature = "deterministic")))]
+    #[inline]
+    fn mul_add_pd(vector: __m128d, a: __m128d, b: __m128d) -> __m128d {
+        _mm_fmadd_pd(vector, a, b)
+    }
+
+    #[cfg(any(not(target_feature = "fma"), feature = "deterministic"))]
+    #[inline]
+    fn mul_add_sd(vector: __m128d, a: __m128d, b: __m128d) -> __m128d {
+        _mm_add_sd(_mm_mul_sd(vector, a), b)
+    }
+
+    #[cfg(all(target_feature = "fma", not(feature = "deterministic")))]
+    #[inline]
+    fn mul_add_sd(vector: __m128d, a: __m128d, b: __m128d) -> __m128d {
+        _mm_fmadd_sd(vector, a, b)
+    }
+
+    #[inline]
+    fn neg(vector: __m256d) -> __m256d {
+        _mm256_xor_pd(vector, _mm256_set1_pd(-0.0))
+    }
+
+    #[inline]
+    fn not(vector: __m256d) -> __m256d {
+        _mm256_xor_pd(vector, _mm256_castsi256_pd(_mm256_set1_epi64x(-1)))
+    }
+
+    #[inline]
+    fn finite_mask(vector: __m256d) -> __m256d {
+        _mm256_cmp_pd::<_CMP_LT_OQ>(abs(vector), _mm256_set1_pd(f64::INFINITY))
+    }
+
+    #[inline]
+    fn sign_negative_mask(vector: __m256d) -> __m256d {
+        // AVX has no 64-bit arithmetic shift, but `blendv` selects based on
+        // the sign bit.
+        _mm256_blendv_pd(
+            _mm256_setzero_pd(),
+            _mm256_castsi256_pd(_mm256_set1_epi64x(-1)),
+            vector,
+        )
+    }
+
+    #[inline]
+    fn abs(vector: __m256d) -> __m256d {
+        _mm256_andnot_pd(_mm256_set1_pd(-0.0), vector)
+    }
+
+    #[inline]
+    fn signum(vector: __m256d) -> __m256d {
+        let result = _mm256_or_pd(_mm256_set1_pd(1.0), _mm256_and_pd(vector, _mm256_set1_pd(-0.0)));
+        let nan_mask = _mm256_cmp_pd::<_CMP_UNORD_Q>(vector, vector);
+
+        _mm256_blendv_pd(result, vector, nan_mask)
+    }
+
+    #[inline]
+    fn copysign(vector: __m256d, sign: __m256d) -> __m256d {
+        _mm256_or_pd(
+            _mm256_and_pd(_mm256_set1_pd(-0.0), sign),
+            _mm256_andnot_pd(_mm256_set1_pd(-0.0), vector),
+        )
+    }
+
+    #[inline]
+    fn trunc(vector: __m256d) -> __m256d {
+        _mm256_round_pd::<{ _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC }>(vector)
+    }
+
+    #[inline]
+    fn round(vector: __m256d) -> __m256d {
+        let vector_abs = abs(vector);
+        let trunc_abs = trunc(vector_abs);
+        // The fraction is computed exactly, and is NaN for infinity and NaN
+        // which leaves them unchanged.
+        let round_up_mask = _mm256_cmp_pd::<_CMP_GE_OQ>(
+            _mm256_sub_pd(vector_abs, trunc_abs),
+            _mm256_set1_pd(0.5),
+        );
+        let result_abs = _mm256_add_pd(trunc_abs, _mm256_and_pd(round_up_mask, _mm256_set1_pd(1.0)));
+
+        copysign(result_abs, vector)
+    }
+
+    #[inline]
+    fn cvt_i32(vector: __m256d) -> __m128i {
+        // Every `i32` is exactly representable, so clamping makes
+        // `_mm256_cvttpd_epi32` saturate like `as`. NaN is cleared to `0`
+        // first.
+        let vector = _mm256_and_pd(vector, _mm256_cmp_pd::<_CMP_ORD_Q>(vector, vector));
+        let vector = _mm256_min_pd(
+            _mm256_max_pd(vector, _mm256_set1_pd(-2147483648.0)),
+            _mm256_set1_pd(2147483647.0),
+        );
+
+        _mm256_cvttpd_epi32(vector)
+    }
+
+    #[inline]
+    fn cvt_u32(vector: __m256d) -> __m128i {
+        // `_mm256_max_pd` returns the second operand for NaN.
+        let vector = _mm256_min_pd(
+            _mm256_max_pd(vector, _mm256_setzero_pd()),
+            _mm256_set1_pd(4294967295.0),
+        );
+
+        // The floored value minus `2^31` fits in `i32`, and flipping the sign
+        // bit adds `2^31` back.
+        let offset = _mm256_sub_pd(_mm256_floor_pd(vector), _mm256_set1_pd(2147483648.0));
+
+        _mm_xor_si128(_mm256_cvttpd_epi32(offset), _mm_set1_epi32(i32::MIN))
+    }
+}
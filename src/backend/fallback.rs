@@ -1,5 +1,121 @@
 use crate::{Aligned, backend::DefaultBackend};
 
-impl DefaultBackend<3, Aligned> for f32 {}
+// With the `force-fallback` feature, types that have a SIMD backend on the
+// target architecture keep the layout of their SIMD representation.
+cfg_select! {
+    target_feature = "sse2" => {
+        use crate::utils::{Repr2, Repr4};
 
-impl DefaultBackend<4, Aligned> for f32 {}
+        type F32Repr3 = Align16<Repr4<f32>>;
+        type F32Repr4 = Align16<Repr4<f32>>;
+        type F64Repr2 = Align16<Repr2<f64>>;
+        type F64Repr3 = Align32<Repr4<f64>>;
+        type F64Repr4 = Align32<Repr4<f64>>;
+        type I32Repr3 = Align16<Repr4<i32>>;
+        type I32Repr4 = Align16<Repr4<i32>>;
+        type U32Repr3 = Align16<Repr4<u32>>;
+        type U32Repr4 = Align16<Repr4<u32>>;
+    }
+    all(target_arch = "aarch64", target_feature = "neon") => {
+        use crate::{backend::DefaultRepr, utils::Repr4};
+
+        type F32Repr3 = Align16<Repr4<f32>>;
+        type F32Repr4 = Align16<Repr4<f32>>;
+        type F64Repr2 = DefaultRepr<2, f64>;
+        type F64Repr3 = DefaultRepr<3, f64>;
+        type F64Repr4 = DefaultRepr<4, f64>;
+        type I32Repr3 = Align16<Repr4<i32>>;
+        type I32Repr4 = Align16<Repr4<i32>>;
+        type U32Repr3 = Align16<Repr4<u32>>;
+        type U32Repr4 = Align16<Repr4<u32>>;
+    }
+    all(target_arch = "wasm32", target_feature = "simd128") => {
+        use crate::{backend::DefaultRepr, utils::Repr4};
+
+        type F32Repr3 = Align16<Repr4<f32>>;
+        type F32Repr4 = Align16<Repr4<f32>>;
+        type F64Repr2 = DefaultRepr<2, f64>;
+        type F64Repr3 = DefaultRepr<3, f64>;
+        type F64Repr4 = DefaultRepr<4, f64>;
+        type I32Repr3 = DefaultRepr<3, i32>;
+        type I32Repr4 = DefaultRepr<4, i32>;
+        type U32Repr3 = DefaultRepr<3, u32>;
+        type U32Repr4 = DefaultRepr<4, u32>;
+    }
+    _ => {
+        use crate::backend::DefaultRepr;
+
+        type F32Repr3 = DefaultRepr<3, f32>;
+        type F32Repr4 = DefaultRepr<4, f32>;
+        type F64Repr2 = DefaultRepr<2, f64>;
+        type F64Repr3 = DefaultRepr<3, f64>;
+        type F64Repr4 = DefaultRepr<4, f64>;
+        type I32Repr3 = DefaultRepr<3, i32>;
+        type I32Repr4 = DefaultRepr<4, i32>;
+        type U32Repr3 = DefaultRepr<3, u32>;
+        type U32Repr4 = DefaultRepr<4, u32>;
+    }
+}
+
+// SAFETY: Every representation is either `DefaultRepr` or an aligned
+// `Repr2`/`Repr4` without padding bytes, which follows the requirements of
+// `VectorBackend::Inner`.
+unsafe impl DefaultBackend<3, Aligned> for f32 {
+    type Repr = F32Repr3;
+}
+
+// SAFETY: See above.
+unsafe impl DefaultBackend<4, Aligned> for f32 {
+    type Repr = F32Repr4;
+}
+
+// SAFETY: See above.
+unsafe impl DefaultBackend<2, Aligned> for f64 {
+    type Repr = F64Repr2;
+}
+
+// SAFETY: See above.
+unsafe impl DefaultBackend<3, Aligned> for f64 {
+    type Repr = F64Repr3;
+}
+
+// SAFETY: See above.
+unsafe impl DefaultBackend<4, Aligned> for f64 {
+    type Repr = F64Repr4;
+}
+
+// SAFETY: See above.
+unsafe impl DefaultBackend<3, Aligned> for i32 {
+    type Repr = I32Repr3;
+}
+
+// SAFETY: See above.
+unsafe impl DefaultBackend<4, Aligned> for i32 {
+    type Repr = I32Repr4;
+}
+
+// SAFETY: See above.
+unsafe impl DefaultBackend<3, Aligned> for u32 {
+    type Repr = U32Repr3;
+}
+
+// SAFETY: See above.
+unsafe impl DefaultBackend<4, Aligned> for u32 {
+    type Repr = U32Repr4;
+}
+
+/// Contains `T` aligned to 16 bytes.
+#[cfg(any(
+    target_feature = "sse2",
+    all(target_arch = "aarch64", target_feature = "neon"),
+    all(target_arch = "wasm32", target_feature = "simd128")
+))]
+#[repr(C, align(16))]
+#[derive(Clone, Copy)]
+pub(crate) struct Align16<T>(T);
+
+/// Contains `T` aligned to 32 bytes.
+#[cfg(target_feature = "sse2")]
+#[repr(C, align(32))]
+#[derive(Clone, Copy)]
+pub(crate) struct Align32<T>(T);
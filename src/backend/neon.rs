@@ -1 +1,25 @@
+use crate::{
+    Aligned,
+    backend::{DefaultBackend, DefaultRepr},
+};
+
 mod f32;
+#[cfg(feature = "f16")]
+mod f16;
+mod i32;
+mod u32;
+
+// SAFETY: `DefaultRepr` follows the requirements of `VectorBackend::Inner`.
+unsafe impl DefaultBackend<2, Aligned> for f64 {
+    type Repr = DefaultRepr<2, f64>;
+}
+
+// SAFETY: `DefaultRepr` follows the requirements of `VectorBackend::Inner`.
+unsafe impl DefaultBackend<3, Aligned> for f64 {
+    type Repr = DefaultRepr<3, f64>;
+}
+
+// SAFETY: `DefaultRepr` follows the requirements of `VectorBackend::Inner`.
+unsafe impl DefaultBackend<4, Aligned> for f64 {
+    type Repr = DefaultRepr<4, f64>;
+}
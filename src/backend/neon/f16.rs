@@ -0,0 +1,37 @@
+use core::{arch::aarch64::*, mem::transmute};
+
+use half::f16;
+
+use crate::{Alignment, Vector, backend::HalfBackend, utils::safe_target_feature};
+
+impl<A: Alignment> HalfBackend<4, A> for f32 {
+    #[inline]
+    fn vector_to_f16(vector: Vector<4, f32, A>) -> Vector<4, f16, A> {
+        Vector::from_array(f32x4_to_f16x4(vector.to_array()))
+    }
+
+    #[inline]
+    fn vector_from_f16(vector: Vector<4, f16, A>) -> Vector<4, f32, A> {
+        Vector::from_array(f16x4_to_f32x4(vector.to_array()))
+    }
+}
+
+safe_target_feature! {
+    #[inline]
+    fn f32x4_to_f16x4(array: [f32; 4]) -> [f16; 4] {
+        // SAFETY: `float32x4_t` accepts all bit-patterns.
+        let vector = unsafe { transmute::<[f32; 4], float32x4_t>(array) };
+
+        // SAFETY: `f16` accepts all bit-patterns.
+        unsafe { transmute::<float16x4_t, [f16; 4]>(vcvt_f16_f32(vector)) }
+    }
+
+    #[inline]
+    fn f16x4_to_f32x4(array: [f16; 4]) -> [f32; 4] {
+        // SAFETY: `float16x4_t` accepts all bit-patterns.
+        let vector = unsafe { transmute::<[f16; 4], float16x4_t>(array) };
+
+        // SAFETY: `f32` accepts all bit-patterns.
+        unsafe { transmute::<float32x4_t, [f32; 4]>(vcvt_f32_f16(vector)) }
+    }
+}
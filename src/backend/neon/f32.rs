@@ -1,13 +1,19 @@
 use core::{arch::aarch64::*, mem::transmute};
 
-#[allow(unused_imports, reason = "rustc incorrectly thinks this is unused")]
 use crate::utils::PrimitiveFloatUtils;
 use crate::{
-    Aligned, Mask, Mask3A, Mask4A, QuatA, Quaternion, Vec3A, Vec4A, Vector,
-    backend::{FloatVectorBackend, MaskBackend, QuaternionBackend, VectorBackend},
+    Aligned, Mask, Mask3A, Mask4A, Mat3A, Mat4A, Matrix, QuatA, Quaternion, Vec3A, Vec4A, Vector,
+    backend::{
+        CastBackend, FloatMatrixBackend, FloatVectorBackend, MaskBackend, MatrixBackend,
+        QuaternionBackend, VectorBackend,
+    },
     utils::{Repr4, safe_target_feature},
 };
 
+/// The largest magnitude for which the polynomial sine and cosine are
+/// accurate. Larger inputs use the scalar implementation.
+const SIN_COS_MAX: f32 = 8192.0;
+
 // `Self::Inner` follows its requirements.
 unsafe impl VectorBackend<3, Aligned> for f32 {
     type Inner = float32x4_t;
@@ -104,6 +110,16 @@ unsafe impl VectorBackend<3, Aligned> for f32 {
             vector.x * vector.y * vector.z
         }
 
+        #[inline]
+        fn vector_dot(vector: Vec3A<f32>, rhs: Vec3A<f32>) -> f32 {
+            (vector * rhs).element_sum()
+        }
+
+        #[inline]
+        fn vector_mul_add_contracted(vector: Vec3A<f32>, a: Vec3A<f32>, b: Vec3A<f32>) -> Vec3A<f32> {
+            vector * a + b
+        }
+
         #[inline]
         fn vector_eq_mask(vector: Vec3A<f32>, other: Vec3A<f32>) -> Mask3A<f32> {
             Mask(vceqq_f32(vector.0, other.0))
@@ -233,6 +249,16 @@ unsafe impl VectorBackend<4, Aligned> for f32 {
             vgetq_lane_f32::<0>(temp) * vgetq_lane_f32::<2>(temp)
         }
 
+        #[inline]
+        fn vector_dot(vector: Vec4A<f32>, rhs: Vec4A<f32>) -> f32 {
+            (vector * rhs).element_sum()
+        }
+
+        #[inline]
+        fn vector_mul_add_contracted(vector: Vec4A<f32>, a: Vec4A<f32>, b: Vec4A<f32>) -> Vec4A<f32> {
+            vector * a + b
+        }
+
         #[inline]
         fn vector_eq_mask(vector: Vec4A<f32>, other: Vec4A<f32>) -> Mask4A<f32> {
             Mask(vceqq_f32(vector.0, other.0))
@@ -287,6 +313,120 @@ impl QuaternionBackend<Aligned> for f32 {
     }
 }
 
+impl MatrixBackend<3, Aligned> for f32 {
+    #[inline]
+    fn matrix_transpose(matrix: &Mat3A<f32>) -> Mat3A<f32> {
+        matrix.transpose_generic()
+    }
+
+    #[inline]
+    fn matrix_mul(matrix: &Mat3A<f32>, rhs: &Mat3A<f32>) -> Mat3A<f32> {
+        matrix.mul_generic(rhs)
+    }
+
+    #[inline]
+    fn matrix_determinant(matrix: &Mat3A<f32>) -> f32 {
+        matrix.determinant_generic()
+    }
+}
+
+impl MatrixBackend<4, Aligned> for f32 {
+    safe_target_feature! {
+        #[inline]
+        fn matrix_transpose(matrix: &Mat4A<f32>) -> Mat4A<f32> {
+            // `[x0, y0, x2, y2]` and `[x1, y1, x3, y3]`.
+            let xy = vtrnq_f32(matrix.x_axis.0, matrix.y_axis.0);
+            // `[z0, w0, z2, w2]` and `[z1, w1, z3, w3]`.
+            let zw = vtrnq_f32(matrix.z_axis.0, matrix.w_axis.0);
+
+            Matrix::from_rows(&[
+                Vector(vcombine_f32(vget_low_f32(xy.0), vget_low_f32(zw.0))),
+                Vector(vcombine_f32(vget_low_f32(xy.1), vget_low_f32(zw.1))),
+                Vector(vcombine_f32(vget_high_f32(xy.0), vget_high_f32(zw.0))),
+                Vector(vcombine_f32(vget_high_f32(xy.1), vget_high_f32(zw.1))),
+            ])
+        }
+
+        #[inline]
+        fn matrix_mul(matrix: &Mat4A<f32>, rhs: &Mat4A<f32>) -> Mat4A<f32> {
+            Matrix::from_rows(&[
+                Vector(vector_mul_matrix(matrix.x_axis.0, rhs)),
+                Vector(vector_mul_matrix(matrix.y_axis.0, rhs)),
+                Vector(vector_mul_matrix(matrix.z_axis.0, rhs)),
+                Vector(vector_mul_matrix(matrix.w_axis.0, rhs)),
+            ])
+        }
+
+        #[inline]
+        fn matrix_determinant(matrix: &Mat4A<f32>) -> f32 {
+            // Performs the same operations as the generic implementation.
+            let (y, z, w) = (matrix.y_axis.0, matrix.z_axis.0, matrix.w_axis.0);
+
+            // `[det_23_23, det_13_23, det_12_23, det_03_23]`
+            let dets_23_1234 = {
+                let z_zyyx = vcopyq_laneq_f32::<3, 0>(
+                    vcopyq_laneq_f32::<0, 2>(vdupq_laneq_f32::<1>(z), z),
+                    z,
+                );
+                let w_zyyx = vcopyq_laneq_f32::<3, 0>(
+                    vcopyq_laneq_f32::<0, 2>(vdupq_laneq_f32::<1>(w), w),
+                    w,
+                );
+                let z_wwzw = vcopyq_laneq_f32::<2, 2>(vdupq_laneq_f32::<3>(z), z);
+                let w_wwzw = vcopyq_laneq_f32::<2, 2>(vdupq_laneq_f32::<3>(w), w);
+
+                vsubq_f32(vmulq_f32(z_zyyx, w_wwzw), vmulq_f32(z_wwzw, w_zyyx))
+            };
+
+            // `[det_02_23, det_01_23, _, _]`
+            let dets_23_56 = {
+                let z_xxzy = vcopyq_laneq_f32::<3, 1>(
+                    vcopyq_laneq_f32::<2, 2>(vdupq_laneq_f32::<0>(z), z),
+                    z,
+                );
+                let w_zyxx = vcopyq_laneq_f32::<1, 1>(
+                    vcopyq_laneq_f32::<0, 2>(vdupq_laneq_f32::<0>(w), w),
+                    w,
+                );
+                let products = vmulq_f32(z_xxzy, w_zyxx);
+
+                vsubq_f32(products, vextq_f32::<2>(products, products))
+            };
+
+            // `[det_123_123, det_023_123, det_013_123, det_012_123]`
+            let dets_123 = {
+                let dets_23_1123 = vextq_f32::<3>(vdupq_laneq_f32::<0>(dets_23_1234), dets_23_1234);
+                let dets_23_2445 = vcopyq_laneq_f32::<3, 0>(
+                    vcopyq_laneq_f32::<0, 1>(vdupq_laneq_f32::<3>(dets_23_1234), dets_23_1234),
+                    dets_23_56,
+                );
+                let dets_23_3566 = vcopyq_laneq_f32::<1, 0>(
+                    vcopyq_laneq_f32::<0, 2>(vdupq_laneq_f32::<1>(dets_23_56), dets_23_1234),
+                    dets_23_56,
+                );
+
+                let y_yxxx = vcopyq_laneq_f32::<0, 1>(vdupq_laneq_f32::<0>(y), y);
+                let y_zzyy = vcombine_f32(vdup_laneq_f32::<2>(y), vdup_laneq_f32::<1>(y));
+                let y_wwwz = vcopyq_laneq_f32::<3, 2>(vdupq_laneq_f32::<3>(y), y);
+
+                vaddq_f32(
+                    vsubq_f32(vmulq_f32(y_yxxx, dets_23_1123), vmulq_f32(y_zzyy, dets_23_2445)),
+                    vmulq_f32(y_wwwz, dets_23_3566),
+                )
+            };
+
+            let cofactors = vmulq_f32(matrix.x_axis.0, dets_123);
+            let negated = vnegq_f32(cofactors);
+            let cofactors: Vec4A<f32> = Vector(vcopyq_laneq_f32::<3, 3>(
+                vcopyq_laneq_f32::<1, 1>(cofactors, negated),
+                negated,
+            ));
+
+            cofactors.element_sum()
+        }
+    }
+}
+
 // `Self::Inner` follows its requirements.
 unsafe impl MaskBackend<3, Aligned> for f32 {
     type Inner = uint32x4_t;
@@ -634,7 +774,11 @@ impl FloatVectorBackend<3, Aligned> for f32 {
 
         #[inline(always)]
         fn vector_powf(vector: Vec3A<f32>, n: f32) -> Vec3A<f32> {
-            Vec3A::new(vector.x.powf(n), vector.y.powf(n), vector.z.powf(n))
+            Vec3A::new(
+                PrimitiveFloatUtils::powf(vector.x, n),
+                PrimitiveFloatUtils::powf(vector.y, n),
+                PrimitiveFloatUtils::powf(vector.z, n),
+            )
         }
 
         #[inline(always)]
@@ -642,65 +786,165 @@ impl FloatVectorBackend<3, Aligned> for f32 {
             Vector(vsqrtq_f32(vector.0))
         }
 
-        #[inline(always)]
+        #[inline]
+        fn vector_rsqrt(vector: Vec3A<f32>) -> Vec3A<f32> {
+            Vector(rsqrt(vector.0))
+        }
+
+        #[inline]
+        fn vector_recip_fast(vector: Vec3A<f32>) -> Vec3A<f32> {
+            Vector(recip_fast(vector.0))
+        }
+
+        #[inline]
+        fn vector_length_recip_fast(vector: Vec3A<f32>) -> f32 {
+            vgetq_lane_f32::<0>(rsqrt(vdupq_n_f32(vector.dot(vector))))
+        }
+
+        #[inline]
+        fn vector_normalize_fast(vector: Vec3A<f32>) -> Vec3A<f32> {
+            Vector(vmulq_f32(vector.0, rsqrt(vdupq_n_f32(vector.dot(vector)))))
+        }
+
+        #[inline]
         fn vector_exp(vector: Vec3A<f32>) -> Vec3A<f32> {
-            Vec3A::new(vector.x.exp(), vector.y.exp(), vector.z.exp())
+            if cfg!(feature = "deterministic") {
+                Vec3A::new(
+                    PrimitiveFloatUtils::exp(vector.x),
+                    PrimitiveFloatUtils::exp(vector.y),
+                    PrimitiveFloatUtils::exp(vector.z),
+                )
+            } else {
+                Vector(exp(vector.0))
+            }
         }
 
-        #[inline(always)]
+        #[inline]
         fn vector_exp2(vector: Vec3A<f32>) -> Vec3A<f32> {
-            Vec3A::new(vector.x.exp2(), vector.y.exp2(), vector.z.exp2())
+            if cfg!(feature = "deterministic") {
+                Vec3A::new(
+                    PrimitiveFloatUtils::exp2(vector.x),
+                    PrimitiveFloatUtils::exp2(vector.y),
+                    PrimitiveFloatUtils::exp2(vector.z),
+                )
+            } else {
+                Vector(exp2(vector.0))
+            }
         }
 
-        #[inline(always)]
+        #[inline]
         fn vector_ln(vector: Vec3A<f32>) -> Vec3A<f32> {
-            Vec3A::new(vector.x.ln(), vector.y.ln(), vector.z.ln())
+            if cfg!(feature = "deterministic") {
+                Vec3A::new(
+                    PrimitiveFloatUtils::ln(vector.x),
+                    PrimitiveFloatUtils::ln(vector.y),
+                    PrimitiveFloatUtils::ln(vector.z),
+                )
+            } else {
+                Vector(ln(vector.0))
+            }
         }
 
-        #[inline(always)]
+        #[inline]
         fn vector_log2(vector: Vec3A<f32>) -> Vec3A<f32> {
-            Vec3A::new(vector.x.log2(), vector.y.log2(), vector.z.log2())
+            if cfg!(feature = "deterministic") {
+                Vec3A::new(
+                    PrimitiveFloatUtils::log2(vector.x),
+                    PrimitiveFloatUtils::log2(vector.y),
+                    PrimitiveFloatUtils::log2(vector.z),
+                )
+            } else {
+                Vector(log2(vector.0))
+            }
         }
 
-        #[inline(always)]
+        #[inline]
         fn vector_sin(vector: Vec3A<f32>) -> Vec3A<f32> {
-            Vec3A::new(vector.x.sin(), vector.y.sin(), vector.z.sin())
+            let out_of_range: Mask3A<f32> = Mask(sin_cos_out_of_range(vector.0));
+            if cfg!(feature = "deterministic") || out_of_range.any() {
+                Vec3A::new(
+                    PrimitiveFloatUtils::sin(vector.x),
+                    PrimitiveFloatUtils::sin(vector.y),
+                    PrimitiveFloatUtils::sin(vector.z),
+                )
+            } else {
+                Vector(sin_cos(vector.0).0)
+            }
         }
 
-        #[inline(always)]
+        #[inline]
         fn vector_cos(vector: Vec3A<f32>) -> Vec3A<f32> {
-            Vec3A::new(vector.x.cos(), vector.y.cos(), vector.z.cos())
+            let out_of_range: Mask3A<f32> = Mask(sin_cos_out_of_range(vector.0));
+            if cfg!(feature = "deterministic") || out_of_range.any() {
+                Vec3A::new(
+                    PrimitiveFloatUtils::cos(vector.x),
+                    PrimitiveFloatUtils::cos(vector.y),
+                    PrimitiveFloatUtils::cos(vector.z),
+                )
+            } else {
+                Vector(sin_cos(vector.0).1)
+            }
         }
 
         #[inline(always)]
         fn vector_tan(vector: Vec3A<f32>) -> Vec3A<f32> {
-            Vec3A::new(vector.x.tan(), vector.y.tan(), vector.z.tan())
+            Vec3A::new(
+                PrimitiveFloatUtils::tan(vector.x),
+                PrimitiveFloatUtils::tan(vector.y),
+                PrimitiveFloatUtils::tan(vector.z),
+            )
         }
 
         #[inline(always)]
         fn vector_asin(vector: Vec3A<f32>) -> Vec3A<f32> {
-            Vec3A::new(vector.x.asin(), vector.y.asin(), vector.z.asin())
+            Vec3A::new(
+                PrimitiveFloatUtils::asin(vector.x),
+                PrimitiveFloatUtils::asin(vector.y),
+                PrimitiveFloatUtils::asin(vector.z),
+            )
         }
 
-        #[inline(always)]
+        #[inline]
         fn vector_acos(vector: Vec3A<f32>) -> Vec3A<f32> {
-            Vec3A::new(vector.x.acos(), vector.y.acos(), vector.z.acos())
+            if cfg!(feature = "deterministic") {
+                Vec3A::new(
+                    PrimitiveFloatUtils::acos(vector.x),
+                    PrimitiveFloatUtils::acos(vector.y),
+                    PrimitiveFloatUtils::acos(vector.z),
+                )
+            } else {
+                Vector(acos(vector.0))
+            }
         }
 
-        #[inline(always)]
+        #[inline]
         fn vector_atan(vector: Vec3A<f32>) -> Vec3A<f32> {
-            Vec3A::new(vector.x.atan(), vector.y.atan(), vector.z.atan())
+            if cfg!(feature = "deterministic") {
+                Vec3A::new(
+                    PrimitiveFloatUtils::atan(vector.x),
+                    PrimitiveFloatUtils::atan(vector.y),
+                    PrimitiveFloatUtils::atan(vector.z),
+                )
+            } else {
+                Vector(atan(vector.0))
+            }
         }
 
-        #[inline(always)]
+        #[inline]
         fn vector_sin_cos(vector: Vec3A<f32>) -> (Vec3A<f32>, Vec3A<f32>) {
-            let x_sin_cos = vector.x.sin_cos();
-            let y_sin_cos = vector.y.sin_cos();
-            let z_sin_cos = vector.z.sin_cos();
-            (
-                Vec3A::new(x_sin_cos.0, y_sin_cos.0, z_sin_cos.0),
-                Vec3A::new(x_sin_cos.1, y_sin_cos.1, z_sin_cos.1),
-            )
+            let out_of_range: Mask3A<f32> = Mask(sin_cos_out_of_range(vector.0));
+            if cfg!(feature = "deterministic") || out_of_range.any() {
+                let x_sin_cos = PrimitiveFloatUtils::sin_cos(vector.x);
+                let y_sin_cos = PrimitiveFloatUtils::sin_cos(vector.y);
+                let z_sin_cos = PrimitiveFloatUtils::sin_cos(vector.z);
+                (
+                    Vec3A::new(x_sin_cos.0, y_sin_cos.0, z_sin_cos.0),
+                    Vec3A::new(x_sin_cos.1, y_sin_cos.1, z_sin_cos.1),
+                )
+            } else {
+                let (sin, cos) = sin_cos(vector.0);
+                (Vector(sin), Vector(cos))
+            }
         }
     }
 }
@@ -809,7 +1053,12 @@ impl FloatVectorBackend<4, Aligned> for f32 {
 
         #[inline(always)]
         fn vector_powf(vector: Vec4A<f32>, n: f32) -> Vec4A<f32> {
-            Vec4A::new(vector.x.powf(n), vector.y.powf(n), vector.z.powf(n), vector.w.powf(n))
+            Vec4A::new(
+                PrimitiveFloatUtils::powf(vector.x, n),
+                PrimitiveFloatUtils::powf(vector.y, n),
+                PrimitiveFloatUtils::powf(vector.z, n),
+                PrimitiveFloatUtils::powf(vector.w, n),
+            )
         }
 
         #[inline(always)]
@@ -817,71 +1066,324 @@ impl FloatVectorBackend<4, Aligned> for f32 {
             Vector(vsqrtq_f32(vector.0))
         }
 
-        #[inline(always)]
+        #[inline]
+        fn vector_rsqrt(vector: Vec4A<f32>) -> Vec4A<f32> {
+            Vector(rsqrt(vector.0))
+        }
+
+        #[inline]
+        fn vector_recip_fast(vector: Vec4A<f32>) -> Vec4A<f32> {
+            Vector(recip_fast(vector.0))
+        }
+
+        #[inline]
+        fn vector_length_recip_fast(vector: Vec4A<f32>) -> f32 {
+            vgetq_lane_f32::<0>(rsqrt(vdupq_n_f32(vector.dot(vector))))
+        }
+
+        #[inline]
+        fn vector_normalize_fast(vector: Vec4A<f32>) -> Vec4A<f32> {
+            Vector(vmulq_f32(vector.0, rsqrt(vdupq_n_f32(vector.dot(vector)))))
+        }
+
+        #[inline]
         fn vector_exp(vector: Vec4A<f32>) -> Vec4A<f32> {
-            Vec4A::new(vector.x.exp(), vector.y.exp(), vector.z.exp(), vector.w.exp())
+            if cfg!(feature = "deterministic") {
+                Vec4A::new(
+                    PrimitiveFloatUtils::exp(vector.x),
+                    PrimitiveFloatUtils::exp(vector.y),
+                    PrimitiveFloatUtils::exp(vector.z),
+                    PrimitiveFloatUtils::exp(vector.w),
+                )
+            } else {
+                Vector(exp(vector.0))
+            }
         }
 
-        #[inline(always)]
+        #[inline]
         fn vector_exp2(vector: Vec4A<f32>) -> Vec4A<f32> {
-            Vec4A::new(vector.x.exp2(), vector.y.exp2(), vector.z.exp2(), vector.w.exp2())
+            if cfg!(feature = "deterministic") {
+                Vec4A::new(
+                    PrimitiveFloatUtils::exp2(vector.x),
+                    PrimitiveFloatUtils::exp2(vector.y),
+                    PrimitiveFloatUtils::exp2(vector.z),
+                    PrimitiveFloatUtils::exp2(vector.w),
+                )
+            } else {
+                Vector(exp2(vector.0))
+            }
         }
 
-        #[inline(always)]
+        #[inline]
         fn vector_ln(vector: Vec4A<f32>) -> Vec4A<f32> {
-            Vec4A::new(vector.x.ln(), vector.y.ln(), vector.z.ln(), vector.w.ln())
+            if cfg!(feature = "deterministic") {
+                Vec4A::new(
+                    PrimitiveFloatUtils::ln(vector.x),
+                    PrimitiveFloatUtils::ln(vector.y),
+                    PrimitiveFloatUtils::ln(vector.z),
+                    PrimitiveFloatUtils::ln(vector.w),
+                )
+            } else {
+                Vector(ln(vector.0))
+            }
         }
 
-        #[inline(always)]
+        #[inline]
         fn vector_log2(vector: Vec4A<f32>) -> Vec4A<f32> {
-            Vec4A::new(vector.x.log2(), vector.y.log2(), vector.z.log2(), vector.w.log2())
+            if cfg!(feature = "deterministic") {
+                Vec4A::new(
+                    PrimitiveFloatUtils::log2(vector.x),
+                    PrimitiveFloatUtils::log2(vector.y),
+                    PrimitiveFloatUtils::log2(vector.z),
+                    PrimitiveFloatUtils::log2(vector.w),
+                )
+            } else {
+                Vector(log2(vector.0))
+            }
         }
 
-        #[inline(always)]
+        #[inline]
         fn vector_sin(vector: Vec4A<f32>) -> Vec4A<f32> {
-            Vec4A::new(vector.x.sin(), vector.y.sin(), vector.z.sin(), vector.w.sin())
+            let out_of_range: Mask4A<f32> = Mask(sin_cos_out_of_range(vector.0));
+            if cfg!(feature = "deterministic") || out_of_range.any() {
+                Vec4A::new(
+                    PrimitiveFloatUtils::sin(vector.x),
+                    PrimitiveFloatUtils::sin(vector.y),
+                    PrimitiveFloatUtils::sin(vector.z),
+                    PrimitiveFloatUtils::sin(vector.w),
+                )
+            } else {
+                Vector(sin_cos(vector.0).0)
+            }
         }
 
-        #[inline(always)]
+        #[inline]
         fn vector_cos(vector: Vec4A<f32>) -> Vec4A<f32> {
-            Vec4A::new(vector.x.cos(), vector.y.cos(), vector.z.cos(), vector.w.cos())
+            let out_of_range: Mask4A<f32> = Mask(sin_cos_out_of_range(vector.0));
+            if cfg!(feature = "deterministic") || out_of_range.any() {
+                Vec4A::new(
+                    PrimitiveFloatUtils::cos(vector.x),
+                    PrimitiveFloatUtils::cos(vector.y),
+                    PrimitiveFloatUtils::cos(vector.z),
+                    PrimitiveFloatUtils::cos(vector.w),
+                )
+            } else {
+                Vector(sin_cos(vector.0).1)
+            }
         }
 
         #[inline(always)]
         fn vector_tan(vector: Vec4A<f32>) -> Vec4A<f32> {
-            Vec4A::new(vector.x.tan(), vector.y.tan(), vector.z.tan(), vector.w.tan())
+            Vec4A::new(
+                PrimitiveFloatUtils::tan(vector.x),
+                PrimitiveFloatUtils::tan(vector.y),
+                PrimitiveFloatUtils::tan(vector.z),
+                PrimitiveFloatUtils::tan(vector.w),
+            )
         }
 
         #[inline(always)]
         fn vector_asin(vector: Vec4A<f32>) -> Vec4A<f32> {
-            Vec4A::new(vector.x.asin(), vector.y.asin(), vector.z.asin(), vector.w.asin())
+            Vec4A::new(
+                PrimitiveFloatUtils::asin(vector.x),
+                PrimitiveFloatUtils::asin(vector.y),
+                PrimitiveFloatUtils::asin(vector.z),
+                PrimitiveFloatUtils::asin(vector.w),
+            )
         }
 
-        #[inline(always)]
+        #[inline]
         fn vector_acos(vector: Vec4A<f32>) -> Vec4A<f32> {
-            Vec4A::new(vector.x.acos(), vector.y.acos(), vector.z.acos(), vector.w.acos())
+            if cfg!(feature = "deterministic") {
+                Vec4A::new(
+                    PrimitiveFloatUtils::acos(vector.x),
+                    PrimitiveFloatUtils::acos(vector.y),
+                    PrimitiveFloatUtils::acos(vector.z),
+                    PrimitiveFloatUtils::acos(vector.w),
+                )
+            } else {
+                Vector(acos(vector.0))
+            }
         }
 
-        #[inline(always)]
+        #[inline]
         fn vector_atan(vector: Vec4A<f32>) -> Vec4A<f32> {
-            Vec4A::new(vector.x.atan(), vector.y.atan(), vector.z.atan(), vector.w.atan())
+            if cfg!(feature = "deterministic") {
+                Vec4A::new(
+                    PrimitiveFloatUtils::atan(vector.x),
+                    PrimitiveFloatUtils::atan(vector.y),
+                    PrimitiveFloatUtils::atan(vector.z),
+                    PrimitiveFloatUtils::atan(vector.w),
+                )
+            } else {
+                Vector(atan(vector.0))
+            }
         }
 
-        #[inline(always)]
+        #[inline]
         fn vector_sin_cos(vector: Vec4A<f32>) -> (Vec4A<f32>, Vec4A<f32>) {
-            let x_sin_cos = vector.x.sin_cos();
-            let y_sin_cos = vector.y.sin_cos();
-            let z_sin_cos = vector.z.sin_cos();
-            let w_sin_cos = vector.w.sin_cos();
-            (
-                Vec4A::new(x_sin_cos.0, y_sin_cos.0, z_sin_cos.0, w_sin_cos.0),
-                Vec4A::new(x_sin_cos.1, y_sin_cos.1, z_sin_cos.1, w_sin_cos.1),
-            )
+            let out_of_range: Mask4A<f32> = Mask(sin_cos_out_of_range(vector.0));
+            if cfg!(feature = "deterministic") || out_of_range.any() {
+                let x_sin_cos = PrimitiveFloatUtils::sin_cos(vector.x);
+                let y_sin_cos = PrimitiveFloatUtils::sin_cos(vector.y);
+                let z_sin_cos = PrimitiveFloatUtils::sin_cos(vector.z);
+                let w_sin_cos = PrimitiveFloatUtils::sin_cos(vector.w);
+                (
+                    Vec4A::new(x_sin_cos.0, y_sin_cos.0, z_sin_cos.0, w_sin_cos.0),
+                    Vec4A::new(x_sin_cos.1, y_sin_cos.1, z_sin_cos.1, w_sin_cos.1),
+                )
+            } else {
+                let (sin, cos) = sin_cos(vector.0);
+                (Vector(sin), Vector(cos))
+            }
+        }
+    }
+}
+
+impl FloatMatrixBackend<3, Aligned> for f32 {
+    #[inline]
+    fn matrix_inverse_and_determinant(matrix: &Mat3A<f32>) -> (Mat3A<f32>, f32) {
+        matrix.inverse_and_determinant_generic()
+    }
+}
+
+impl FloatMatrixBackend<4, Aligned> for f32 {
+    safe_target_feature! {
+        #[inline]
+        fn matrix_inverse_and_determinant(matrix: &Mat4A<f32>) -> (Mat4A<f32>, f32) {
+            // Cofactor expansion in the same order as the generic
+            // implementation, using lane broadcasts instead of scalar elements.
+            let (x, y, z, w) = (matrix.x_axis.0, matrix.y_axis.0, matrix.z_axis.0, matrix.w_axis.0);
+
+            // `[z[i], z[i], y[i], y[i]]`
+            let zzyy0 = vcombine_f32(vdup_laneq_f32::<0>(z), vdup_laneq_f32::<0>(y));
+            let zzyy1 = vcombine_f32(vdup_laneq_f32::<1>(z), vdup_laneq_f32::<1>(y));
+            let zzyy2 = vcombine_f32(vdup_laneq_f32::<2>(z), vdup_laneq_f32::<2>(y));
+            let zzyy3 = vcombine_f32(vdup_laneq_f32::<3>(z), vdup_laneq_f32::<3>(y));
+
+            // `[w[i], w[i], w[i], z[i]]`
+            let wwwz0 = vcopyq_laneq_f32::<3, 0>(vdupq_laneq_f32::<0>(w), z);
+            let wwwz1 = vcopyq_laneq_f32::<3, 1>(vdupq_laneq_f32::<1>(w), z);
+            let wwwz2 = vcopyq_laneq_f32::<3, 2>(vdupq_laneq_f32::<2>(w), z);
+            let wwwz3 = vcopyq_laneq_f32::<3, 3>(vdupq_laneq_f32::<3>(w), z);
+
+            let fac0 = vsubq_f32(vmulq_f32(zzyy2, wwwz3), vmulq_f32(wwwz2, zzyy3));
+            let fac1 = vsubq_f32(vmulq_f32(zzyy1, wwwz3), vmulq_f32(wwwz1, zzyy3));
+            let fac2 = vsubq_f32(vmulq_f32(zzyy1, wwwz2), vmulq_f32(wwwz1, zzyy2));
+            let fac3 = vsubq_f32(vmulq_f32(zzyy0, wwwz3), vmulq_f32(wwwz0, zzyy3));
+            let fac4 = vsubq_f32(vmulq_f32(zzyy0, wwwz2), vmulq_f32(wwwz0, zzyy2));
+            let fac5 = vsubq_f32(vmulq_f32(zzyy0, wwwz1), vmulq_f32(wwwz0, zzyy1));
+
+            // `[y[i], x[i], x[i], x[i]]`
+            let vec0 = vcopyq_laneq_f32::<0, 0>(vdupq_laneq_f32::<0>(x), y);
+            let vec1 = vcopyq_laneq_f32::<0, 1>(vdupq_laneq_f32::<1>(x), y);
+            let vec2 = vcopyq_laneq_f32::<0, 2>(vdupq_laneq_f32::<2>(x), y);
+            let vec3 = vcopyq_laneq_f32::<0, 3>(vdupq_laneq_f32::<3>(x), y);
+
+            let inv0 = vaddq_f32(
+                vsubq_f32(vmulq_f32(vec1, fac0), vmulq_f32(vec2, fac1)),
+                vmulq_f32(vec3, fac2),
+            );
+            let inv1 = vaddq_f32(
+                vsubq_f32(vmulq_f32(vec0, fac0), vmulq_f32(vec2, fac3)),
+                vmulq_f32(vec3, fac4),
+            );
+            let inv2 = vaddq_f32(
+                vsubq_f32(vmulq_f32(vec0, fac1), vmulq_f32(vec1, fac3)),
+                vmulq_f32(vec3, fac5),
+            );
+            let inv3 = vaddq_f32(
+                vsubq_f32(vmulq_f32(vec0, fac2), vmulq_f32(vec1, fac4)),
+                vmulq_f32(vec2, fac5),
+            );
+
+            const SIGN_A: Vec4A<f32> = Vec4A::new(1.0, -1.0, 1.0, -1.0);
+            const SIGN_B: Vec4A<f32> = Vec4A::new(-1.0, 1.0, -1.0, 1.0);
+
+            let inv0 = vmulq_f32(inv0, SIGN_A.0);
+            let inv1 = vmulq_f32(inv1, SIGN_B.0);
+            let inv2 = vmulq_f32(inv2, SIGN_A.0);
+            let inv3 = vmulq_f32(inv3, SIGN_B.0);
+
+            let inverse_column_0 = vcombine_f32(
+                vget_low_f32(vzip1q_f32(inv0, inv1)),
+                vget_low_f32(vzip1q_f32(inv2, inv3)),
+            );
+
+            let determinant = matrix.x_axis.dot(Vector(inverse_column_0));
+            let determinant_splat = vdupq_n_f32(determinant);
+
+            let inverse = Matrix::from_rows(&[
+                Vector(vdivq_f32(inv0, determinant_splat)),
+                Vector(vdivq_f32(inv1, determinant_splat)),
+                Vector(vdivq_f32(inv2, determinant_splat)),
+                Vector(vdivq_f32(inv3, determinant_splat)),
+            ]);
+
+            (inverse, determinant)
+        }
+    }
+}
+
+impl CastBackend<3, Aligned> for f32 {
+    safe_target_feature! {
+        #[inline]
+        fn vector_as_f32(vector: Vec3A<f32>) -> Vec3A<f32> {
+            vector
+        }
+
+        #[inline]
+        fn vector_as_f64(vector: Vec3A<f32>) -> Vec3A<f64> {
+            vector.map(|x| x as f64)
+        }
+
+        #[inline]
+        fn vector_as_i32(vector: Vec3A<f32>) -> Vec3A<i32> {
+            Vector(vcvtq_s32_f32(vector.0))
+        }
+
+        #[inline]
+        fn vector_as_u32(vector: Vec3A<f32>) -> Vec3A<u32> {
+            Vector(vcvtq_u32_f32(vector.0))
+        }
+    }
+}
+
+impl CastBackend<4, Aligned> for f32 {
+    safe_target_feature! {
+        #[inline]
+        fn vector_as_f32(vector: Vec4A<f32>) -> Vec4A<f32> {
+            vector
+        }
+
+        #[inline]
+        fn vector_as_f64(vector: Vec4A<f32>) -> Vec4A<f64> {
+            vector.map(|x| x as f64)
+        }
+
+        #[inline]
+        fn vector_as_i32(vector: Vec4A<f32>) -> Vec4A<i32> {
+            Vector(vcvtq_s32_f32(vector.0))
+        }
+
+        #[inline]
+        fn vector_as_u32(vector: Vec4A<f32>) -> Vec4A<u32> {
+            Vector(vcvtq_u32_f32(vector.0))
         }
     }
 }
 
 safe_target_feature! {
+    #[inline]
+    fn vector_mul_matrix(vector: float32x4_t, matrix: &Mat4A<f32>) -> float32x4_t {
+        // Same operation order as `Vector * Matrix`. This intentionally avoids
+        // fused multiply-add instructions.
+        let result = vmulq_laneq_f32::<0>(matrix.x_axis.0, vector);
+        let result = vaddq_f32(vmulq_laneq_f32::<1>(matrix.y_axis.0, vector), result);
+        let result = vaddq_f32(vmulq_laneq_f32::<2>(matrix.z_axis.0, vector), result);
+        vaddq_f32(vmulq_laneq_f32::<3>(matrix.w_axis.0, vector), result)
+    }
+
     #[inline]
     fn rem(vector: float32x4_t, rhs: float32x4_t) -> float32x4_t {
         let result = vsubq_f32(vector, vmulq_f32(vrndq_f32(vdivq_f32(vector, rhs)), rhs));
@@ -897,6 +1399,340 @@ safe_target_feature! {
         vbslq_f32(zero_mask, vdupq_n_f32(f32::NAN), result)
     }
 
+    #[inline]
+    fn rsqrt(vector: float32x4_t) -> float32x4_t {
+        // Refine the 8-bit estimate with one Newton-Raphson step.
+        // `vrsqrtsq_f32(a, b)` computes `(3 - a * b) / 2`.
+        let estimate = vrsqrteq_f32(vector);
+        let refined = vmulq_f32(
+            estimate,
+            vrsqrtsq_f32(vmulq_f32(vector, estimate), estimate),
+        );
+
+        // Zero and infinite estimates are already exact, and the step turns them
+        // into NaN because `vector * estimate` is zero times infinity.
+        let abs = vabsq_f32(estimate);
+        let zero_or_infinite_mask = vorrq_u32(
+            vceqq_f32(abs, vdupq_n_f32(0.0)),
+            vceqq_f32(abs, vdupq_n_f32(f32::INFINITY)),
+        );
+
+        vbslq_f32(zero_or_infinite_mask, estimate, refined)
+    }
+
+    #[inline]
+    fn recip_fast(vector: float32x4_t) -> float32x4_t {
+        // Refine the 8-bit estimate with one Newton-Raphson step.
+        // `vrecpsq_f32(a, b)` computes `2 - a * b`, and returns `2` for zero
+        // times infinity, which keeps zero and infinite estimates exact.
+        let estimate = vrecpeq_f32(vector);
+        vmulq_f32(estimate, vrecpsq_f32(vector, estimate))
+    }
+
+    // The transcendental functions below are vectorized versions of the
+    // Cephes single precision implementations, and match the x86
+    // implementations operation for operation. Their maximum errors are
+    // documented on the public functions.
+
+    /// Returns `(sin(vector), cos(vector))`. Only accurate when
+    /// `|vector| <= SIN_COS_MAX`.
+    #[inline]
+    fn sin_cos(vector: float32x4_t) -> (float32x4_t, float32x4_t) {
+        let x = vabsq_f32(vector);
+
+        // Reduce `x` to `[-pi / 4, pi / 4]` around an even multiple `j` of
+        // `pi / 4`, with `pi / 4` split into four parts to keep the
+        // subtraction exact.
+        let j = vcvtq_s32_f32(vmulq_f32(x, vdupq_n_f32(4.0 / core::f32::consts::PI)));
+        let j = vandq_s32(vaddq_s32(j, vdupq_n_s32(1)), vdupq_n_s32(!1));
+        let y = vcvtq_f32_s32(j);
+        let x = vaddq_f32(x, vmulq_f32(y, vdupq_n_f32(-0.78515625)));
+        let x = vaddq_f32(x, vmulq_f32(y, vdupq_n_f32(-2.4175644e-4)));
+        let x = vaddq_f32(x, vmulq_f32(y, vdupq_n_f32(-1.5692785e-7)));
+        let x = vaddq_f32(x, vmulq_f32(y, vdupq_n_f32(-3.0385503e-11)));
+
+        // Octants 2 and 6 swap the sine and cosine polynomials, octants 4 and
+        // 6 negate the sine, and octants 2 and 4 negate the cosine.
+        let poly_mask = vceqq_s32(vandq_s32(j, vdupq_n_s32(2)), vdupq_n_s32(0));
+        let sin_sign = veorq_u32(
+            vandq_u32(vreinterpretq_u32_f32(vector), vdupq_n_u32((-0.0f32).to_bits())),
+            vreinterpretq_u32_s32(vshlq_n_s32::<29>(vandq_s32(j, vdupq_n_s32(4)))),
+        );
+        let cos_sign = vreinterpretq_u32_s32(vshlq_n_s32::<29>(vbicq_s32(
+            vdupq_n_s32(4),
+            vsubq_s32(j, vdupq_n_s32(2)),
+        )));
+
+        let z = vmulq_f32(x, x);
+
+        let cos_poly = vdupq_n_f32(2.4433157e-5);
+        let cos_poly = vaddq_f32(vmulq_f32(cos_poly, z), vdupq_n_f32(-1.3887316e-3));
+        let cos_poly = vaddq_f32(vmulq_f32(cos_poly, z), vdupq_n_f32(4.1666646e-2));
+        let cos_poly = vmulq_f32(vmulq_f32(cos_poly, z), z);
+        let cos_poly = vsubq_f32(cos_poly, vmulq_f32(z, vdupq_n_f32(0.5)));
+        let cos_poly = vaddq_f32(cos_poly, vdupq_n_f32(1.0));
+
+        let sin_poly = vdupq_n_f32(-1.9515296e-4);
+        let sin_poly = vaddq_f32(vmulq_f32(sin_poly, z), vdupq_n_f32(8.332161e-3));
+        let sin_poly = vaddq_f32(vmulq_f32(sin_poly, z), vdupq_n_f32(-1.6666655e-1));
+        let sin_poly = vaddq_f32(vmulq_f32(vmulq_f32(sin_poly, z), x), x);
+
+        let sin = vbslq_f32(poly_mask, sin_poly, cos_poly);
+        let cos = vbslq_f32(poly_mask, cos_poly, sin_poly);
+
+        (
+            vreinterpretq_f32_u32(veorq_u32(vreinterpretq_u32_f32(sin), sin_sign)),
+            vreinterpretq_f32_u32(veorq_u32(vreinterpretq_u32_f32(cos), cos_sign)),
+        )
+    }
+
+    /// Returns a mask of the elements for which [`sin_cos`] is not accurate.
+    #[inline]
+    fn sin_cos_out_of_range(vector: float32x4_t) -> uint32x4_t {
+        vcgtq_f32(vabsq_f32(vector), vdupq_n_f32(SIN_COS_MAX))
+    }
+
+    #[inline]
+    fn exp(vector: float32x4_t) -> float32x4_t {
+        // Clamping keeps `n` in the range supported by `scale_by_exp2`. NEON
+        // `min` and `max` propagate NaN.
+        let x = vmaxq_f32(vdupq_n_f32(-110.0), vminq_f32(vdupq_n_f32(110.0), vector));
+
+        // `e^x = 2^n * e^r` where `r = x - n * ln(2)`, with `ln(2)` split into
+        // two parts to keep `r` accurate.
+        let n = vcvtnq_s32_f32(vmulq_f32(x, vdupq_n_f32(core::f32::consts::LOG2_E)));
+        let n_float = vcvtq_f32_s32(n);
+        let r = vsubq_f32(x, vmulq_f32(n_float, vdupq_n_f32(0.6933594)));
+        let r = vsubq_f32(r, vmulq_f32(n_float, vdupq_n_f32(-2.1219444e-4)));
+
+        let poly = vdupq_n_f32(1.9875691e-4);
+        let poly = vaddq_f32(vmulq_f32(poly, r), vdupq_n_f32(1.3981999e-3));
+        let poly = vaddq_f32(vmulq_f32(poly, r), vdupq_n_f32(8.333452e-3));
+        let poly = vaddq_f32(vmulq_f32(poly, r), vdupq_n_f32(4.1665796e-2));
+        let poly = vaddq_f32(vmulq_f32(poly, r), vdupq_n_f32(1.6666666e-1));
+        let poly = vaddq_f32(vmulq_f32(poly, r), vdupq_n_f32(0.5));
+        let poly = vaddq_f32(vmulq_f32(poly, vmulq_f32(r, r)), r);
+        let poly = vaddq_f32(poly, vdupq_n_f32(1.0));
+
+        scale_by_exp2(poly, n)
+    }
+
+    #[inline]
+    fn exp2(vector: float32x4_t) -> float32x4_t {
+        // Clamping keeps `n` in the range supported by `scale_by_exp2`. NEON
+        // `min` and `max` propagate NaN.
+        let x = vmaxq_f32(vdupq_n_f32(-160.0), vminq_f32(vdupq_n_f32(160.0), vector));
+
+        // `2^x = 2^n * 2^r` where `r = x - n` is in `[-0.5, 0.5]`.
+        let n = vcvtnq_s32_f32(x);
+        let r = vsubq_f32(x, vcvtq_f32_s32(n));
+
+        let poly = vdupq_n_f32(1.5353362e-4);
+        let poly = vaddq_f32(vmulq_f32(poly, r), vdupq_n_f32(1.3398874e-3));
+        let poly = vaddq_f32(vmulq_f32(poly, r), vdupq_n_f32(9.618437e-3));
+        let poly = vaddq_f32(vmulq_f32(poly, r), vdupq_n_f32(5.5503324e-2));
+        let poly = vaddq_f32(vmulq_f32(poly, r), vdupq_n_f32(2.4022648e-1));
+        let poly = vaddq_f32(vmulq_f32(poly, r), vdupq_n_f32(6.931472e-1));
+        let poly = vaddq_f32(vmulq_f32(poly, r), vdupq_n_f32(1.0));
+
+        scale_by_exp2(poly, n)
+    }
+
+    /// Computes `vector * 2^n` for `n` in `-160..=160`.
+    #[inline]
+    fn scale_by_exp2(vector: float32x4_t, n: int32x4_t) -> float32x4_t {
+        // Scaling in two steps keeps both factors normal. Only the second
+        // multiplication rounds, so subnormal results are rounded once.
+        let half = vshrq_n_s32::<1>(n);
+        let first = vshlq_n_s32::<23>(vaddq_s32(half, vdupq_n_s32(127)));
+        let second = vshlq_n_s32::<23>(vaddq_s32(vsubq_s32(n, half), vdupq_n_s32(127)));
+
+        vmulq_f32(
+            vmulq_f32(vector, vreinterpretq_f32_s32(first)),
+            vreinterpretq_f32_s32(second),
+        )
+    }
+
+    #[inline]
+    fn ln(vector: float32x4_t) -> float32x4_t {
+        let (m, e, poly) = log_reduce(vector);
+
+        let z = vmulq_f32(m, m);
+        let result = vaddq_f32(poly, vmulq_f32(e, vdupq_n_f32(-2.1219444e-4)));
+        let result = vsubq_f32(result, vmulq_f32(z, vdupq_n_f32(0.5)));
+        let result = vaddq_f32(m, result);
+        let result = vaddq_f32(result, vmulq_f32(e, vdupq_n_f32(0.6933594)));
+
+        log_special_values(vector, result)
+    }
+
+    #[inline]
+    fn log2(vector: float32x4_t) -> float32x4_t {
+        let (m, e, poly) = log_reduce(vector);
+
+        // Multiplying by `log2(e) - 1` and adding the product separately is
+        // more accurate than multiplying by `log2(e)`.
+        let log2_e_minus_one = vdupq_n_f32(0.44269505);
+
+        let z = vmulq_f32(m, m);
+        let y = vsubq_f32(poly, vmulq_f32(z, vdupq_n_f32(0.5)));
+        let result = vaddq_f32(vmulq_f32(m, log2_e_minus_one), vmulq_f32(y, log2_e_minus_one));
+        let result = vaddq_f32(result, y);
+        let result = vaddq_f32(result, m);
+        let result = vaddq_f32(result, e);
+
+        log_special_values(vector, result)
+    }
+
+    /// Splits `vector` into `2^e * (1 + m)` with `1 + m` in
+    /// `[sqrt(0.5), sqrt(2))`, and returns `(m, e, m^3 * P(m))`, which is
+    /// shared by [`ln`] and [`log2`].
+    #[inline]
+    fn log_reduce(vector: float32x4_t) -> (float32x4_t, float32x4_t, float32x4_t) {
+        // Subnormal inputs are normalized first.
+        let subnormal_mask = vcltq_f32(vector, vdupq_n_f32(f32::MIN_POSITIVE));
+        let x = vbslq_f32(
+            subnormal_mask,
+            vmulq_f32(vector, vdupq_n_f32(8388608.0)),
+            vector,
+        );
+
+        let bits = vreinterpretq_u32_f32(x);
+        let e = vsubq_s32(
+            vreinterpretq_s32_u32(vshrq_n_u32::<23>(bits)),
+            vdupq_n_s32(126),
+        );
+        let e = vsubq_s32(
+            e,
+            vreinterpretq_s32_u32(vandq_u32(subnormal_mask, vdupq_n_u32(23))),
+        );
+        let e = vcvtq_f32_s32(e);
+
+        // `m` is in `[0.5, 1)`.
+        let m = vreinterpretq_f32_u32(vorrq_u32(
+            vandq_u32(bits, vdupq_n_u32(0x007fffff)),
+            vdupq_n_u32(0.5f32.to_bits()),
+        ));
+
+        let small_mask = vcltq_f32(m, vdupq_n_f32(core::f32::consts::FRAC_1_SQRT_2));
+        let e = vsubq_f32(
+            e,
+            vreinterpretq_f32_u32(vandq_u32(small_mask, vdupq_n_u32(1.0f32.to_bits()))),
+        );
+        let m = vaddq_f32(
+            vsubq_f32(m, vdupq_n_f32(1.0)),
+            vreinterpretq_f32_u32(vandq_u32(small_mask, vreinterpretq_u32_f32(m))),
+        );
+
+        let poly = vdupq_n_f32(7.0376836e-2);
+        let poly = vaddq_f32(vmulq_f32(poly, m), vdupq_n_f32(-1.151461e-1));
+        let poly = vaddq_f32(vmulq_f32(poly, m), vdupq_n_f32(1.16769984e-1));
+        let poly = vaddq_f32(vmulq_f32(poly, m), vdupq_n_f32(-1.2420141e-1));
+        let poly = vaddq_f32(vmulq_f32(poly, m), vdupq_n_f32(1.4249323e-1));
+        let poly = vaddq_f32(vmulq_f32(poly, m), vdupq_n_f32(-1.6668057e-1));
+        let poly = vaddq_f32(vmulq_f32(poly, m), vdupq_n_f32(2.0000714e-1));
+        let poly = vaddq_f32(vmulq_f32(poly, m), vdupq_n_f32(-2.4999994e-1));
+        let poly = vaddq_f32(vmulq_f32(poly, m), vdupq_n_f32(3.333333e-1));
+        let poly = vmulq_f32(vmulq_f32(poly, m), vmulq_f32(m, m));
+
+        (m, e, poly)
+    }
+
+    #[inline]
+    fn log_special_values(vector: float32x4_t, result: float32x4_t) -> float32x4_t {
+        let result = vbslq_f32(
+            vceqq_f32(vector, vdupq_n_f32(f32::INFINITY)),
+            vector,
+            result,
+        );
+        let result = vbslq_f32(
+            vceqq_f32(vector, vdupq_n_f32(0.0)),
+            vdupq_n_f32(f32::NEG_INFINITY),
+            result,
+        );
+
+        // Negative values and NaN.
+        vbslq_f32(
+            vmvnq_u32(vcgeq_f32(vector, vdupq_n_f32(0.0))),
+            vdupq_n_f32(f32::NAN),
+            result,
+        )
+    }
+
+    #[inline]
+    fn atan(vector: float32x4_t) -> float32x4_t {
+        let x = vabsq_f32(vector);
+
+        // Reduce `x` to `[0, tan(pi / 8)]` with `atan(x) = pi / 2 - atan(1 / x)`
+        // and `atan(x) = pi / 4 + atan((x - 1) / (x + 1))`.
+        let large_mask = vcgtq_f32(x, vdupq_n_f32(2.4142137));
+        let medium_mask = vbicq_u32(vcgtq_f32(x, vdupq_n_f32(0.41421357)), large_mask);
+        let offset = vbslq_f32(
+            large_mask,
+            vdupq_n_f32(core::f32::consts::FRAC_PI_2),
+            vbslq_f32(
+                medium_mask,
+                vdupq_n_f32(core::f32::consts::FRAC_PI_4),
+                vdupq_n_f32(0.0),
+            ),
+        );
+        let x = vbslq_f32(
+            large_mask,
+            vnegq_f32(vdivq_f32(vdupq_n_f32(1.0), x)),
+            vbslq_f32(
+                medium_mask,
+                vdivq_f32(vsubq_f32(x, vdupq_n_f32(1.0)), vaddq_f32(x, vdupq_n_f32(1.0))),
+                x,
+            ),
+        );
+
+        let z = vmulq_f32(x, x);
+        let poly = vdupq_n_f32(8.0537446e-2);
+        let poly = vaddq_f32(vmulq_f32(poly, z), vdupq_n_f32(-1.3877685e-1));
+        let poly = vaddq_f32(vmulq_f32(poly, z), vdupq_n_f32(1.9977711e-1));
+        let poly = vaddq_f32(vmulq_f32(poly, z), vdupq_n_f32(-3.333295e-1));
+        let poly = vaddq_f32(vmulq_f32(vmulq_f32(poly, z), x), x);
+
+        copysign(vaddq_f32(offset, poly), vector)
+    }
+
+    #[inline]
+    fn acos(vector: float32x4_t) -> float32x4_t {
+        let x = vabsq_f32(vector);
+
+        // Compute `asin(s)` where `s = sqrt((1 - |x|) / 2)` for `|x| > 0.5`,
+        // and `s = |x|` otherwise.
+        let large_mask = vcgtq_f32(x, vdupq_n_f32(0.5));
+        let z = vbslq_f32(
+            large_mask,
+            vmulq_f32(vsubq_f32(vdupq_n_f32(1.0), x), vdupq_n_f32(0.5)),
+            vmulq_f32(x, x),
+        );
+        let s = vbslq_f32(large_mask, vsqrtq_f32(z), x);
+
+        let poly = vdupq_n_f32(4.21632e-2);
+        let poly = vaddq_f32(vmulq_f32(poly, z), vdupq_n_f32(2.4181312e-2));
+        let poly = vaddq_f32(vmulq_f32(poly, z), vdupq_n_f32(4.5470025e-2));
+        let poly = vaddq_f32(vmulq_f32(poly, z), vdupq_n_f32(7.4953005e-2));
+        let poly = vaddq_f32(vmulq_f32(poly, z), vdupq_n_f32(1.6666752e-1));
+        let asin = vaddq_f32(vmulq_f32(vmulq_f32(poly, z), s), s);
+
+        // `acos(x) = 2 * asin(s)` for `x > 0.5`, `pi - 2 * asin(s)` for
+        // `x < -0.5`, and `pi / 2 - asin(x)` otherwise.
+        let large = vaddq_f32(asin, asin);
+        let large = vbslq_f32(
+            vcltq_f32(vector, vdupq_n_f32(0.0)),
+            vsubq_f32(vdupq_n_f32(core::f32::consts::PI), large),
+            large,
+        );
+        let small = vsubq_f32(
+            vdupq_n_f32(core::f32::consts::FRAC_PI_2),
+            copysign(asin, vector),
+        );
+
+        vbslq_f32(large_mask, large, small)
+    }
+
     #[inline]
     fn nan_mask(vector: float32x4_t) -> uint32x4_t {
         vmvnq_u32(vceqq_f32(vector, vector))
@@ -0,0 +1,905 @@
+use core::{arch::aarch64::*, mem::transmute};
+
+use crate::{
+    Aligned, Mask, Mask3A, Mask4A, Mat3A, Mat4A, QuatA, Quaternion, Vec3A, Vec4A, Vector,
+    backend::{
+        CastBackend, IntegerVectorBackend, MaskBackend, MatrixBackend, QuaternionBackend,
+        SignedVectorBackend, VectorBackend, overflow_check,
+    },
+    utils::{Repr4, safe_target_feature},
+};
+
+// `Self::Inner` follows its requirements.
+unsafe impl VectorBackend<3, Aligned> for i32 {
+    type Inner = int32x4_t;
+
+    safe_target_feature! {
+        #[inline]
+        fn vector_eq(vector: &Vec3A<i32>, other: &Vec3A<i32>) -> bool {
+            vector.eq_mask(*other).all()
+        }
+
+        #[inline]
+        fn vector_ne(vector: &Vec3A<i32>, other: &Vec3A<i32>) -> bool {
+            !(vector == other)
+        }
+
+        #[inline]
+        fn vector_neg(vector: Vec3A<i32>) -> Vec3A<i32> {
+            overflow_check::<3, _>(|i| -vector[i]);
+            Vector(vnegq_s32(vector.0))
+        }
+
+        #[inline]
+        fn vector_not(vector: Vec3A<i32>) -> Vec3A<i32> {
+            Vector(vmvnq_s32(vector.0))
+        }
+
+        #[inline]
+        fn vector_add(vector: Vec3A<i32>, rhs: Vec3A<i32>) -> Vec3A<i32> {
+            overflow_check::<3, _>(|i| vector[i] + rhs[i]);
+            Vector(vaddq_s32(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_sub(vector: Vec3A<i32>, rhs: Vec3A<i32>) -> Vec3A<i32> {
+            overflow_check::<3, _>(|i| vector[i] - rhs[i]);
+            Vector(vsubq_s32(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_mul(vector: Vec3A<i32>, rhs: Vec3A<i32>) -> Vec3A<i32> {
+            overflow_check::<3, _>(|i| vector[i] * rhs[i]);
+            Vector(vmulq_s32(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_div(vector: Vec3A<i32>, rhs: Vec3A<i32>) -> Vec3A<i32> {
+            Vec3A::<i32>::new(vector.x / rhs.x, vector.y / rhs.y, vector.z / rhs.z)
+        }
+
+        #[inline]
+        fn vector_rem(vector: Vec3A<i32>, rhs: Vec3A<i32>) -> Vec3A<i32> {
+            Vec3A::<i32>::new(vector.x % rhs.x, vector.y % rhs.y, vector.z % rhs.z)
+        }
+
+        #[inline]
+        fn vector_shl(vector: Vec3A<i32>, rhs: Vec3A<i32>) -> Vec3A<i32> {
+            overflow_check::<3, _>(|i| vector[i] << rhs[i]);
+            Vector(vshlq_s32(vector.0, shift_amount(rhs.0)))
+        }
+
+        #[inline]
+        fn vector_shr(vector: Vec3A<i32>, rhs: Vec3A<i32>) -> Vec3A<i32> {
+            overflow_check::<3, _>(|i| vector[i] >> rhs[i]);
+            // Negative shift amounts shift right.
+            Vector(vshlq_s32(vector.0, vnegq_s32(shift_amount(rhs.0))))
+        }
+
+        #[inline]
+        fn vector_bitand(vector: Vec3A<i32>, rhs: Vec3A<i32>) -> Vec3A<i32> {
+            Vector(vandq_s32(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_bitor(vector: Vec3A<i32>, rhs: Vec3A<i32>) -> Vec3A<i32> {
+            Vector(vorrq_s32(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_bitxor(vector: Vec3A<i32>, rhs: Vec3A<i32>) -> Vec3A<i32> {
+            Vector(veorq_s32(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_element_sum(vector: Vec3A<i32>) -> i32 {
+            vector.x + vector.y + vector.z
+        }
+
+        #[inline]
+        fn vector_element_product(vector: Vec3A<i32>) -> i32 {
+            vector.x * vector.y * vector.z
+        }
+
+        #[inline]
+        fn vector_dot(vector: Vec3A<i32>, rhs: Vec3A<i32>) -> i32 {
+            (vector * rhs).element_sum()
+        }
+
+        #[inline]
+        fn vector_mul_add_contracted(vector: Vec3A<i32>, a: Vec3A<i32>, b: Vec3A<i32>) -> Vec3A<i32> {
+            vector * a + b
+        }
+
+        #[inline]
+        fn vector_eq_mask(vector: Vec3A<i32>, other: Vec3A<i32>) -> Mask3A<i32> {
+            Mask(vceqq_s32(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_ne_mask(vector: Vec3A<i32>, other: Vec3A<i32>) -> Mask3A<i32> {
+            Mask(vmvnq_u32(vceqq_s32(vector.0, other.0)))
+        }
+
+        #[inline]
+        fn vector_lt_mask(vector: Vec3A<i32>, other: Vec3A<i32>) -> Mask3A<i32> {
+            Mask(vcltq_s32(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_gt_mask(vector: Vec3A<i32>, other: Vec3A<i32>) -> Mask3A<i32> {
+            Mask(vcgtq_s32(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_le_mask(vector: Vec3A<i32>, other: Vec3A<i32>) -> Mask3A<i32> {
+            Mask(vcleq_s32(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_ge_mask(vector: Vec3A<i32>, other: Vec3A<i32>) -> Mask3A<i32> {
+            Mask(vcgeq_s32(vector.0, other.0))
+        }
+    }
+}
+
+// `Self::Inner` follows its requirements.
+unsafe impl VectorBackend<4, Aligned> for i32 {
+    type Inner = int32x4_t;
+
+    safe_target_feature! {
+        #[inline]
+        fn vector_eq(vector: &Vec4A<i32>, other: &Vec4A<i32>) -> bool {
+            vector.eq_mask(*other).all()
+        }
+
+        #[inline]
+        fn vector_ne(vector: &Vec4A<i32>, other: &Vec4A<i32>) -> bool {
+            !(vector == other)
+        }
+
+        #[inline]
+        fn vector_neg(vector: Vec4A<i32>) -> Vec4A<i32> {
+            overflow_check::<4, _>(|i| -vector[i]);
+            Vector(vnegq_s32(vector.0))
+        }
+
+        #[inline]
+        fn vector_not(vector: Vec4A<i32>) -> Vec4A<i32> {
+            Vector(vmvnq_s32(vector.0))
+        }
+
+        #[inline]
+        fn vector_add(vector: Vec4A<i32>, rhs: Vec4A<i32>) -> Vec4A<i32> {
+            overflow_check::<4, _>(|i| vector[i] + rhs[i]);
+            Vector(vaddq_s32(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_sub(vector: Vec4A<i32>, rhs: Vec4A<i32>) -> Vec4A<i32> {
+            overflow_check::<4, _>(|i| vector[i] - rhs[i]);
+            Vector(vsubq_s32(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_mul(vector: Vec4A<i32>, rhs: Vec4A<i32>) -> Vec4A<i32> {
+            overflow_check::<4, _>(|i| vector[i] * rhs[i]);
+            Vector(vmulq_s32(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_div(vector: Vec4A<i32>, rhs: Vec4A<i32>) -> Vec4A<i32> {
+            Vec4A::<i32>::new(vector.x / rhs.x, vector.y / rhs.y, vector.z / rhs.z, vector.w / rhs.w)
+        }
+
+        #[inline]
+        fn vector_rem(vector: Vec4A<i32>, rhs: Vec4A<i32>) -> Vec4A<i32> {
+            Vec4A::<i32>::new(vector.x % rhs.x, vector.y % rhs.y, vector.z % rhs.z, vector.w % rhs.w)
+        }
+
+        #[inline]
+        fn vector_shl(vector: Vec4A<i32>, rhs: Vec4A<i32>) -> Vec4A<i32> {
+            overflow_check::<4, _>(|i| vector[i] << rhs[i]);
+            Vector(vshlq_s32(vector.0, shift_amount(rhs.0)))
+        }
+
+        #[inline]
+        fn vector_shr(vector: Vec4A<i32>, rhs: Vec4A<i32>) -> Vec4A<i32> {
+            overflow_check::<4, _>(|i| vector[i] >> rhs[i]);
+            // Negative shift amounts shift right.
+            Vector(vshlq_s32(vector.0, vnegq_s32(shift_amount(rhs.0))))
+        }
+
+        #[inline]
+        fn vector_bitand(vector: Vec4A<i32>, rhs: Vec4A<i32>) -> Vec4A<i32> {
+            Vector(vandq_s32(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_bitor(vector: Vec4A<i32>, rhs: Vec4A<i32>) -> Vec4A<i32> {
+            Vector(vorrq_s32(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_bitxor(vector: Vec4A<i32>, rhs: Vec4A<i32>) -> Vec4A<i32> {
+            Vector(veorq_s32(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_element_sum(vector: Vec4A<i32>) -> i32 {
+            vector.x + vector.y + (vector.z + vector.w)
+        }
+
+        #[inline]
+        fn vector_element_product(vector: Vec4A<i32>) -> i32 {
+            vector.x * vector.y * (vector.z * vector.w)
+        }
+
+        #[inline]
+        fn vector_dot(vector: Vec4A<i32>, rhs: Vec4A<i32>) -> i32 {
+            (vector * rhs).element_sum()
+        }
+
+        #[inline]
+        fn vector_mul_add_contracted(vector: Vec4A<i32>, a: Vec4A<i32>, b: Vec4A<i32>) -> Vec4A<i32> {
+            vector * a + b
+        }
+
+        #[inline]
+        fn vector_eq_mask(vector: Vec4A<i32>, other: Vec4A<i32>) -> Mask4A<i32> {
+            Mask(vceqq_s32(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_ne_mask(vector: Vec4A<i32>, other: Vec4A<i32>) -> Mask4A<i32> {
+            Mask(vmvnq_u32(vceqq_s32(vector.0, other.0)))
+        }
+
+        #[inline]
+        fn vector_lt_mask(vector: Vec4A<i32>, other: Vec4A<i32>) -> Mask4A<i32> {
+            Mask(vcltq_s32(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_gt_mask(vector: Vec4A<i32>, other: Vec4A<i32>) -> Mask4A<i32> {
+            Mask(vcgtq_s32(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_le_mask(vector: Vec4A<i32>, other: Vec4A<i32>) -> Mask4A<i32> {
+            Mask(vcleq_s32(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_ge_mask(vector: Vec4A<i32>, other: Vec4A<i32>) -> Mask4A<i32> {
+            Mask(vcgeq_s32(vector.0, other.0))
+        }
+    }
+}
+
+impl QuaternionBackend<Aligned> for i32 {
+    #[inline]
+    fn quat_mul(quat: QuatA<i32>, rhs: QuatA<i32>) -> QuatA<i32> {
+        let [x0, y0, z0, w0] = quat.to_array();
+        let [x1, y1, z1, w1] = rhs.to_array();
+
+        Quaternion::from_xyzw(
+            x0 * w1 + w0 * x1 + z0 * y1 - y0 * z1,
+            y0 * w1 - z0 * x1 + w0 * y1 + x0 * z1,
+            z0 * w1 + y0 * x1 - x0 * y1 + w0 * z1,
+            w0 * w1 - x0 * x1 - y0 * y1 - z0 * z1,
+        )
+    }
+}
+
+impl MatrixBackend<3, Aligned> for i32 {
+    #[inline]
+    fn matrix_transpose(matrix: &Mat3A<i32>) -> Mat3A<i32> {
+        matrix.transpose_generic()
+    }
+
+    #[inline]
+    fn matrix_mul(matrix: &Mat3A<i32>, rhs: &Mat3A<i32>) -> Mat3A<i32> {
+        matrix.mul_generic(rhs)
+    }
+
+    #[inline]
+    fn matrix_determinant(matrix: &Mat3A<i32>) -> i32 {
+        matrix.determinant_generic()
+    }
+}
+
+impl MatrixBackend<4, Aligned> for i32 {
+    #[inline]
+    fn matrix_transpose(matrix: &Mat4A<i32>) -> Mat4A<i32> {
+        matrix.transpose_generic()
+    }
+
+    #[inline]
+    fn matrix_mul(matrix: &Mat4A<i32>, rhs: &Mat4A<i32>) -> Mat4A<i32> {
+        matrix.mul_generic(rhs)
+    }
+
+    #[inline]
+    fn matrix_determinant(matrix: &Mat4A<i32>) -> i32 {
+        matrix.determinant_generic()
+    }
+}
+
+// `Self::Inner` follows its requirements.
+unsafe impl MaskBackend<3, Aligned> for i32 {
+    type Inner = uint32x4_t;
+
+    safe_target_feature! {
+        #[inline]
+        fn mask_from_array(array: [bool; 3]) -> Mask3A<i32> {
+            // SAFETY: Both types accept all bit-patterns.
+            Mask(unsafe {
+                transmute::<Repr4<i32>, uint32x4_t>(Repr4(
+                    -(array[0] as i32),
+                    -(array[1] as i32),
+                    -(array[2] as i32),
+                    -(array[2] as i32),
+                ))
+            })
+        }
+
+        #[inline]
+        fn mask_splat(value: bool) -> Mask3A<i32> {
+            Mask(vdupq_n_u32(-(value as i32) as u32))
+        }
+
+        #[inline]
+        fn mask_to_array(mask: Mask3A<i32>) -> [bool; 3] {
+            [
+                vgetq_lane_u32::<0>(mask.0) != 0,
+                vgetq_lane_u32::<1>(mask.0) != 0,
+                vgetq_lane_u32::<2>(mask.0) != 0,
+            ]
+        }
+
+        #[inline]
+        fn mask_all(mask: Mask3A<i32>) -> bool {
+            // SAFETY: Both types accept all bit-patterns.
+            const MASK: uint32x4_t = unsafe { transmute::<[u32; 4], uint32x4_t>([0b001, 0b010, 0b100, 0]) };
+
+            let masked = vandq_u32(mask.0, MASK);
+            let reduce_2 = vorr_u32(vget_low_u32(masked), vget_high_u32(masked));
+            let bitmask = vget_lane_u32::<0>(reduce_2) | vget_lane_u32::<1>(reduce_2);
+
+            bitmask == 0b111
+        }
+
+        #[inline]
+        fn mask_any(mask: Mask3A<i32>) -> bool {
+            // SAFETY: Both types accept all bit-patterns.
+            const MASK: uint32x4_t = unsafe { transmute::<[u32; 4], uint32x4_t>([0b001, 0b010, 0b100, 0]) };
+
+            let masked = vandq_u32(mask.0, MASK);
+            let reduce_2 = vorr_u32(vget_low_u32(masked), vget_high_u32(masked));
+            let bitmask = vget_lane_u32::<0>(reduce_2) | vget_lane_u32::<1>(reduce_2);
+
+            bitmask != 0
+        }
+
+        #[inline]
+        fn mask_select(mask: Mask3A<i32>, if_true: Vec3A<i32>, if_false: Vec3A<i32>) -> Vec3A<i32> {
+            Vector(vbslq_s32(mask.0, if_true.0, if_false.0))
+        }
+
+        #[inline]
+        fn mask_get(mask: Mask3A<i32>, index: usize) -> bool {
+            match index {
+                0 => vgetq_lane_u32::<0>(mask.0) != 0,
+                1 => vgetq_lane_u32::<1>(mask.0) != 0,
+                2 => vgetq_lane_u32::<2>(mask.0) != 0,
+                _ => panic!("index out of bounds"),
+            }
+        }
+
+        #[inline]
+        fn mask_set(mask: &mut Mask3A<i32>, index: usize, value: bool) {
+            let value = -(value as i32) as u32;
+            mask.0 = match index {
+                0 => vsetq_lane_u32::<0>(value, mask.0),
+                1 => vsetq_lane_u32::<1>(value, mask.0),
+                2 => vsetq_lane_u32::<2>(value, mask.0),
+                _ => panic!("index out of bounds"),
+            };
+        }
+
+        #[inline]
+        fn mask_eq(mask: &Mask3A<i32>, other: &Mask3A<i32>) -> bool {
+            // SAFETY: Both types accept all bit-patterns.
+            const MASK: uint32x4_t = unsafe { transmute::<[u32; 4], uint32x4_t>([0b001, 0b010, 0b100, 0]) };
+
+            let masked = vandq_u32(vceqq_u32(mask.0, other.0), MASK);
+            let reduce_2 = vorr_u32(vget_low_u32(masked), vget_high_u32(masked));
+            let bitmask = vget_lane_u32::<0>(reduce_2) | vget_lane_u32::<1>(reduce_2);
+
+            bitmask == 0b111
+        }
+
+        #[inline]
+        fn mask_ne(mask: &Mask3A<i32>, other: &Mask3A<i32>) -> bool {
+            !(mask == other)
+        }
+
+        #[inline]
+        fn mask_not(mask: Mask3A<i32>) -> Mask3A<i32> {
+            Mask(vmvnq_u32(mask.0))
+        }
+
+        #[inline]
+        fn mask_bitand(mask: Mask3A<i32>, rhs: Mask3A<i32>) -> Mask3A<i32> {
+            Mask(vandq_u32(mask.0, rhs.0))
+        }
+
+        #[inline]
+        fn mask_bitor(mask: Mask3A<i32>, rhs: Mask3A<i32>) -> Mask3A<i32> {
+            Mask(vorrq_u32(mask.0, rhs.0))
+        }
+
+        #[inline]
+        fn mask_bitxor(mask: Mask3A<i32>, rhs: Mask3A<i32>) -> Mask3A<i32> {
+            Mask(veorq_u32(mask.0, rhs.0))
+        }
+    }
+}
+
+// `Self::Inner` follows its requirements.
+unsafe impl MaskBackend<4, Aligned> for i32 {
+    type Inner = uint32x4_t;
+
+    safe_target_feature! {
+        #[inline]
+        fn mask_from_array(array: [bool; 4]) -> Mask4A<i32> {
+            // SAFETY: Both types accept all bit-patterns.
+            Mask(unsafe {
+                transmute::<Repr4<i32>, uint32x4_t>(Repr4(
+                    -(array[0] as i32),
+                    -(array[1] as i32),
+                    -(array[2] as i32),
+                    -(array[3] as i32),
+                ))
+            })
+        }
+
+        #[inline]
+        fn mask_splat(value: bool) -> Mask4A<i32> {
+            Mask(vdupq_n_u32(-(value as i32) as u32))
+        }
+
+        #[inline]
+        fn mask_to_array(mask: Mask4A<i32>) -> [bool; 4] {
+            [
+                vgetq_lane_u32::<0>(mask.0) != 0,
+                vgetq_lane_u32::<1>(mask.0) != 0,
+                vgetq_lane_u32::<2>(mask.0) != 0,
+                vgetq_lane_u32::<3>(mask.0) != 0,
+            ]
+        }
+
+        #[inline]
+        fn mask_all(mask: Mask4A<i32>) -> bool {
+            // SAFETY: Both types accept all bit-patterns.
+            const MASK: uint32x4_t = unsafe { transmute::<[u32; 4], uint32x4_t>([0b0001, 0b0010, 0b0100, 0b1000]) };
+
+            let masked = vandq_u32(mask.0, MASK);
+            let reduce_2 = vorr_u32(vget_low_u32(masked), vget_high_u32(masked));
+            let bitmask = vget_lane_u32::<0>(reduce_2) | vget_lane_u32::<1>(reduce_2);
+
+            bitmask == 0b1111
+        }
+
+        #[inline]
+        fn mask_any(mask: Mask4A<i32>) -> bool {
+            // SAFETY: Both types accept all bit-patterns.
+            const MASK: uint32x4_t = unsafe { transmute::<[u32; 4], uint32x4_t>([0b0001, 0b0010, 0b0100, 0b1000]) };
+
+            let masked = vandq_u32(mask.0, MASK);
+            let reduce_2 = vorr_u32(vget_low_u32(masked), vget_high_u32(masked));
+            let bitmask = vget_lane_u32::<0>(reduce_2) | vget_lane_u32::<1>(reduce_2);
+
+            bitmask != 0
+        }
+
+        #[inline]
+        fn mask_select(mask: Mask4A<i32>, if_true: Vec4A<i32>, if_false: Vec4A<i32>) -> Vec4A<i32> {
+            Vector(vbslq_s32(mask.0, if_true.0, if_false.0))
+        }
+
+        #[inline]
+        fn mask_get(mask: Mask4A<i32>, index: usize) -> bool {
+            match index {
+                0 => vgetq_lane_u32::<0>(mask.0) != 0,
+                1 => vgetq_lane_u32::<1>(mask.0) != 0,
+                2 => vgetq_lane_u32::<2>(mask.0) != 0,
+                3 => vgetq_lane_u32::<3>(mask.0) != 0,
+                _ => panic!("index out of bounds"),
+            }
+        }
+
+        #[inline]
+        fn mask_set(mask: &mut Mask4A<i32>, index: usize, value: bool) {
+            let value = -(value as i32) as u32;
+            mask.0 = match index {
+                0 => vsetq_lane_u32::<0>(value, mask.0),
+                1 => vsetq_lane_u32::<1>(value, mask.0),
+                2 => vsetq_lane_u32::<2>(value, mask.0),
+                3 => vsetq_lane_u32::<3>(value, mask.0),
+                _ => panic!("index out of bounds"),
+            };
+        }
+
+        #[inline]
+        fn mask_eq(mask: &Mask4A<i32>, other: &Mask4A<i32>) -> bool {
+            // SAFETY: Both types accept all bit-patterns.
+            const MASK: uint32x4_t = unsafe { transmute::<[u32; 4], uint32x4_t>([0b0001, 0b0010, 0b0100, 0b1000]) };
+
+            let masked = vandq_u32(vceqq_u32(mask.0, other.0), MASK);
+            let reduce_2 = vorr_u32(vget_low_u32(masked), vget_high_u32(masked));
+            let bitmask = vget_lane_u32::<0>(reduce_2) | vget_lane_u32::<1>(reduce_2);
+
+            bitmask == 0b1111
+        }
+
+        #[inline]
+        fn mask_ne(mask: &Mask4A<i32>, other: &Mask4A<i32>) -> bool {
+            !(mask == other)
+        }
+
+        #[inline]
+        fn mask_not(mask: Mask4A<i32>) -> Mask4A<i32> {
+            Mask(vmvnq_u32(mask.0))
+        }
+
+        #[inline]
+        fn mask_bitand(mask: Mask4A<i32>, rhs: Mask4A<i32>) -> Mask4A<i32> {
+            Mask(vandq_u32(mask.0, rhs.0))
+        }
+
+        #[inline]
+        fn mask_bitor(mask: Mask4A<i32>, rhs: Mask4A<i32>) -> Mask4A<i32> {
+            Mask(vorrq_u32(mask.0, rhs.0))
+        }
+
+        #[inline]
+        fn mask_bitxor(mask: Mask4A<i32>, rhs: Mask4A<i32>) -> Mask4A<i32> {
+            Mask(veorq_u32(mask.0, rhs.0))
+        }
+    }
+}
+
+impl IntegerVectorBackend<3, Aligned> for i32 {
+    safe_target_feature! {
+        #[inline]
+        fn vector_max(vector: Vec3A<i32>, other: Vec3A<i32>) -> Vec3A<i32> {
+            Vector(vmaxq_s32(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_min(vector: Vec3A<i32>, other: Vec3A<i32>) -> Vec3A<i32> {
+            Vector(vminq_s32(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_max_element(vector: Vec3A<i32>) -> i32 {
+            vmaxvq_s32(vcopyq_laneq_s32::<3, 2>(vector.0, vector.0))
+        }
+
+        #[inline]
+        fn vector_min_element(vector: Vec3A<i32>) -> i32 {
+            vminvq_s32(vcopyq_laneq_s32::<3, 2>(vector.0, vector.0))
+        }
+
+        #[inline]
+        fn vector_checked_add(vector: Vec3A<i32>, rhs: Vec3A<i32>) -> Option<Vec3A<i32>> {
+            let result = vaddq_s32(vector.0, rhs.0);
+            let saturated = vqaddq_s32(vector.0, rhs.0);
+
+            if Vector::<3, i32, Aligned>(result) == Vector(saturated) {
+                Some(Vector(result))
+            } else {
+                None
+            }
+        }
+
+        #[inline]
+        fn vector_checked_sub(vector: Vec3A<i32>, rhs: Vec3A<i32>) -> Option<Vec3A<i32>> {
+            let result = vsubq_s32(vector.0, rhs.0);
+            let saturated = vqsubq_s32(vector.0, rhs.0);
+
+            if Vector::<3, i32, Aligned>(result) == Vector(saturated) {
+                Some(Vector(result))
+            } else {
+                None
+            }
+        }
+
+        #[inline]
+        fn vector_checked_mul(vector: Vec3A<i32>, rhs: Vec3A<i32>) -> Option<Vec3A<i32>> {
+            Some(Vec3A::<i32>::new(vector.x.checked_mul(rhs.x)?, vector.y.checked_mul(rhs.y)?, vector.z.checked_mul(rhs.z)?))
+        }
+
+        #[inline]
+        fn vector_checked_div(vector: Vec3A<i32>, rhs: Vec3A<i32>) -> Option<Vec3A<i32>> {
+            Some(Vec3A::<i32>::new(vector.x.checked_div(rhs.x)?, vector.y.checked_div(rhs.y)?, vector.z.checked_div(rhs.z)?))
+        }
+
+        #[inline]
+        fn vector_checked_rem(vector: Vec3A<i32>, rhs: Vec3A<i32>) -> Option<Vec3A<i32>> {
+            Some(Vec3A::<i32>::new(vector.x.checked_rem(rhs.x)?, vector.y.checked_rem(rhs.y)?, vector.z.checked_rem(rhs.z)?))
+        }
+
+        #[inline]
+        fn vector_saturating_add(vector: Vec3A<i32>, rhs: Vec3A<i32>) -> Vec3A<i32> {
+            Vector(vqaddq_s32(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_saturating_sub(vector: Vec3A<i32>, rhs: Vec3A<i32>) -> Vec3A<i32> {
+            Vector(vqsubq_s32(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_saturating_mul(vector: Vec3A<i32>, rhs: Vec3A<i32>) -> Vec3A<i32> {
+            Vec3A::<i32>::new(vector.x.saturating_mul(rhs.x), vector.y.saturating_mul(rhs.y), vector.z.saturating_mul(rhs.z))
+        }
+
+        #[inline]
+        fn vector_saturating_div(vector: Vec3A<i32>, rhs: Vec3A<i32>) -> Vec3A<i32> {
+            Vec3A::<i32>::new(vector.x.saturating_div(rhs.x), vector.y.saturating_div(rhs.y), vector.z.saturating_div(rhs.z))
+        }
+
+        #[inline]
+        fn vector_wrapping_add(vector: Vec3A<i32>, rhs: Vec3A<i32>) -> Vec3A<i32> {
+            Vector(vaddq_s32(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_wrapping_sub(vector: Vec3A<i32>, rhs: Vec3A<i32>) -> Vec3A<i32> {
+            Vector(vsubq_s32(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_wrapping_mul(vector: Vec3A<i32>, rhs: Vec3A<i32>) -> Vec3A<i32> {
+            Vector(vmulq_s32(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_wrapping_div(vector: Vec3A<i32>, rhs: Vec3A<i32>) -> Vec3A<i32> {
+            Vec3A::<i32>::new(vector.x.wrapping_div(rhs.x), vector.y.wrapping_div(rhs.y), vector.z.wrapping_div(rhs.z))
+        }
+
+        #[inline]
+        fn vector_wrapping_rem(vector: Vec3A<i32>, rhs: Vec3A<i32>) -> Vec3A<i32> {
+            Vec3A::<i32>::new(vector.x.wrapping_rem(rhs.x), vector.y.wrapping_rem(rhs.y), vector.z.wrapping_rem(rhs.z))
+        }
+    }
+}
+
+impl IntegerVectorBackend<4, Aligned> for i32 {
+    safe_target_feature! {
+        #[inline]
+        fn vector_max(vector: Vec4A<i32>, other: Vec4A<i32>) -> Vec4A<i32> {
+            Vector(vmaxq_s32(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_min(vector: Vec4A<i32>, other: Vec4A<i32>) -> Vec4A<i32> {
+            Vector(vminq_s32(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_max_element(vector: Vec4A<i32>) -> i32 {
+            vmaxvq_s32(vector.0)
+        }
+
+        #[inline]
+        fn vector_min_element(vector: Vec4A<i32>) -> i32 {
+            vminvq_s32(vector.0)
+        }
+
+        #[inline]
+        fn vector_checked_add(vector: Vec4A<i32>, rhs: Vec4A<i32>) -> Option<Vec4A<i32>> {
+            let result = vaddq_s32(vector.0, rhs.0);
+            let saturated = vqaddq_s32(vector.0, rhs.0);
+
+            if Vector::<4, i32, Aligned>(result) == Vector(saturated) {
+                Some(Vector(result))
+            } else {
+                None
+            }
+        }
+
+        #[inline]
+        fn vector_checked_sub(vector: Vec4A<i32>, rhs: Vec4A<i32>) -> Option<Vec4A<i32>> {
+            let result = vsubq_s32(vector.0, rhs.0);
+            let saturated = vqsubq_s32(vector.0, rhs.0);
+
+            if Vector::<4, i32, Aligned>(result) == Vector(saturated) {
+                Some(Vector(result))
+            } else {
+                None
+            }
+        }
+
+        #[inline]
+        fn vector_checked_mul(vector: Vec4A<i32>, rhs: Vec4A<i32>) -> Option<Vec4A<i32>> {
+            Some(Vec4A::<i32>::new(vector.x.checked_mul(rhs.x)?, vector.y.checked_mul(rhs.y)?, vector.z.checked_mul(rhs.z)?, vector.w.checked_mul(rhs.w)?))
+        }
+
+        #[inline]
+        fn vector_checked_div(vector: Vec4A<i32>, rhs: Vec4A<i32>) -> Option<Vec4A<i32>> {
+            Some(Vec4A::<i32>::new(vector.x.checked_div(rhs.x)?, vector.y.checked_div(rhs.y)?, vector.z.checked_div(rhs.z)?, vector.w.checked_div(rhs.w)?))
+        }
+
+        #[inline]
+        fn vector_checked_rem(vector: Vec4A<i32>, rhs: Vec4A<i32>) -> Option<Vec4A<i32>> {
+            Some(Vec4A::<i32>::new(vector.x.checked_rem(rhs.x)?, vector.y.checked_rem(rhs.y)?, vector.z.checked_rem(rhs.z)?, vector.w.checked_rem(rhs.w)?))
+        }
+
+        #[inline]
+        fn vector_saturating_add(vector: Vec4A<i32>, rhs: Vec4A<i32>) -> Vec4A<i32> {
+            Vector(vqaddq_s32(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_saturating_sub(vector: Vec4A<i32>, rhs: Vec4A<i32>) -> Vec4A<i32> {
+            Vector(vqsubq_s32(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_saturating_mul(vector: Vec4A<i32>, rhs: Vec4A<i32>) -> Vec4A<i32> {
+            Vec4A::<i32>::new(vector.x.saturating_mul(rhs.x), vector.y.saturating_mul(rhs.y), vector.z.saturating_mul(rhs.z), vector.w.saturating_mul(rhs.w))
+        }
+
+        #[inline]
+        fn vector_saturating_div(vector: Vec4A<i32>, rhs: Vec4A<i32>) -> Vec4A<i32> {
+            Vec4A::<i32>::new(vector.x.saturating_div(rhs.x), vector.y.saturating_div(rhs.y), vector.z.saturating_div(rhs.z), vector.w.saturating_div(rhs.w))
+        }
+
+        #[inline]
+        fn vector_wrapping_add(vector: Vec4A<i32>, rhs: Vec4A<i32>) -> Vec4A<i32> {
+            Vector(vaddq_s32(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_wrapping_sub(vector: Vec4A<i32>, rhs: Vec4A<i32>) -> Vec4A<i32> {
+            Vector(vsubq_s32(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_wrapping_mul(vector: Vec4A<i32>, rhs: Vec4A<i32>) -> Vec4A<i32> {
+            Vector(vmulq_s32(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_wrapping_div(vector: Vec4A<i32>, rhs: Vec4A<i32>) -> Vec4A<i32> {
+            Vec4A::<i32>::new(vector.x.wrapping_div(rhs.x), vector.y.wrapping_div(rhs.y), vector.z.wrapping_div(rhs.z), vector.w.wrapping_div(rhs.w))
+        }
+
+        #[inline]
+        fn vector_wrapping_rem(vector: Vec4A<i32>, rhs: Vec4A<i32>) -> Vec4A<i32> {
+            Vec4A::<i32>::new(vector.x.wrapping_rem(rhs.x), vector.y.wrapping_rem(rhs.y), vector.z.wrapping_rem(rhs.z), vector.w.wrapping_rem(rhs.w))
+        }
+    }
+}
+
+impl SignedVectorBackend<3, Aligned> for i32 {
+    safe_target_feature! {
+        #[inline]
+        fn vector_wrapping_abs(vector: Vec3A<i32>) -> Vec3A<i32> {
+            Vector(vabsq_s32(vector.0))
+        }
+
+        #[inline]
+        fn vector_signum(vector: Vec3A<i32>) -> Vec3A<i32> {
+            // `-1` for negative values, and `1` for positive values because
+            // their negation is negative. `MIN` is handled by the first
+            // operand.
+            Vector(vorrq_s32(
+                vshrq_n_s32::<31>(vector.0),
+                vreinterpretq_s32_u32(vshrq_n_u32::<31>(vreinterpretq_u32_s32(vnegq_s32(vector.0)))),
+            ))
+        }
+
+        #[inline]
+        fn vector_positive_mask(vector: Vec3A<i32>) -> Mask3A<i32> {
+            Mask(vcgtzq_s32(vector.0))
+        }
+
+        #[inline]
+        fn vector_negative_mask(vector: Vec3A<i32>) -> Mask3A<i32> {
+            Mask(vcltzq_s32(vector.0))
+        }
+    }
+}
+
+impl SignedVectorBackend<4, Aligned> for i32 {
+    safe_target_feature! {
+        #[inline]
+        fn vector_wrapping_abs(vector: Vec4A<i32>) -> Vec4A<i32> {
+            Vector(vabsq_s32(vector.0))
+        }
+
+        #[inline]
+        fn vector_signum(vector: Vec4A<i32>) -> Vec4A<i32> {
+            // `-1` for negative values, and `1` for positive values because
+            // their negation is negative. `MIN` is handled by the first
+            // operand.
+            Vector(vorrq_s32(
+                vshrq_n_s32::<31>(vector.0),
+                vreinterpretq_s32_u32(vshrq_n_u32::<31>(vreinterpretq_u32_s32(vnegq_s32(vector.0)))),
+            ))
+        }
+
+        #[inline]
+        fn vector_positive_mask(vector: Vec4A<i32>) -> Mask4A<i32> {
+            Mask(vcgtzq_s32(vector.0))
+        }
+
+        #[inline]
+        fn vector_negative_mask(vector: Vec4A<i32>) -> Mask4A<i32> {
+            Mask(vcltzq_s32(vector.0))
+        }
+    }
+}
+
+impl CastBackend<3, Aligned> for i32 {
+    safe_target_feature! {
+        #[inline]
+        fn vector_as_f32(vector: Vec3A<i32>) -> Vec3A<f32> {
+            Vector(vcvtq_f32_s32(vector.0))
+        }
+
+        #[inline]
+        fn vector_as_f64(vector: Vec3A<i32>) -> Vec3A<f64> {
+            vector.map(|x| x as f64)
+        }
+
+        #[inline]
+        fn vector_as_i32(vector: Vec3A<i32>) -> Vec3A<i32> {
+            vector
+        }
+
+        #[inline]
+        fn vector_as_u32(vector: Vec3A<i32>) -> Vec3A<u32> {
+            Vector(vreinterpretq_u32_s32(vector.0))
+        }
+    }
+}
+
+impl CastBackend<4, Aligned> for i32 {
+    safe_target_feature! {
+        #[inline]
+        fn vector_as_f32(vector: Vec4A<i32>) -> Vec4A<f32> {
+            Vector(vcvtq_f32_s32(vector.0))
+        }
+
+        #[inline]
+        fn vector_as_f64(vector: Vec4A<i32>) -> Vec4A<f64> {
+            vector.map(|x| x as f64)
+        }
+
+        #[inline]
+        fn vector_as_i32(vector: Vec4A<i32>) -> Vec4A<i32> {
+            vector
+        }
+
+        #[inline]
+        fn vector_as_u32(vector: Vec4A<i32>) -> Vec4A<u32> {
+            Vector(vreinterpretq_u32_s32(vector.0))
+        }
+    }
+}
+
+safe_target_feature! {
+    #[inline]
+    fn shift_amount(rhs: int32x4_t) -> int32x4_t {
+        // Matches the scalar shift operators without overflow checks, which
+        // only use the low 5 bits of the shift amount.
+        vandq_s32(rhs, vdupq_n_s32(31))
+    }
+}
@@ -0,0 +1,835 @@
+use core::{arch::aarch64::*, mem::transmute};
+
+use crate::{
+    Aligned, Mask, Mask3A, Mask4A, Mat3A, Mat4A, QuatA, Vec3A, Vec4A, Vector,
+    backend::{
+        CastBackend, IntegerVectorBackend, MaskBackend, MatrixBackend, QuaternionBackend,
+        VectorBackend, overflow_check,
+    },
+    utils::{Repr4, safe_target_feature},
+};
+
+// `Self::Inner` follows its requirements.
+unsafe impl VectorBackend<3, Aligned> for u32 {
+    type Inner = uint32x4_t;
+
+    safe_target_feature! {
+        #[inline]
+        fn vector_eq(vector: &Vec3A<u32>, other: &Vec3A<u32>) -> bool {
+            vector.eq_mask(*other).all()
+        }
+
+        #[inline]
+        fn vector_ne(vector: &Vec3A<u32>, other: &Vec3A<u32>) -> bool {
+            !(vector == other)
+        }
+
+        #[inline]
+        fn vector_neg(_vector: Vec3A<u32>) -> Vec3A<u32> {
+            unreachable!("`u32` does not implement `Neg`")
+        }
+
+        #[inline]
+        fn vector_not(vector: Vec3A<u32>) -> Vec3A<u32> {
+            Vector(vmvnq_u32(vector.0))
+        }
+
+        #[inline]
+        fn vector_add(vector: Vec3A<u32>, rhs: Vec3A<u32>) -> Vec3A<u32> {
+            overflow_check::<3, _>(|i| vector[i] + rhs[i]);
+            Vector(vaddq_u32(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_sub(vector: Vec3A<u32>, rhs: Vec3A<u32>) -> Vec3A<u32> {
+            overflow_check::<3, _>(|i| vector[i] - rhs[i]);
+            Vector(vsubq_u32(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_mul(vector: Vec3A<u32>, rhs: Vec3A<u32>) -> Vec3A<u32> {
+            overflow_check::<3, _>(|i| vector[i] * rhs[i]);
+            Vector(vmulq_u32(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_div(vector: Vec3A<u32>, rhs: Vec3A<u32>) -> Vec3A<u32> {
+            Vec3A::<u32>::new(vector.x / rhs.x, vector.y / rhs.y, vector.z / rhs.z)
+        }
+
+        #[inline]
+        fn vector_rem(vector: Vec3A<u32>, rhs: Vec3A<u32>) -> Vec3A<u32> {
+            Vec3A::<u32>::new(vector.x % rhs.x, vector.y % rhs.y, vector.z % rhs.z)
+        }
+
+        #[inline]
+        fn vector_shl(vector: Vec3A<u32>, rhs: Vec3A<u32>) -> Vec3A<u32> {
+            overflow_check::<3, _>(|i| vector[i] << rhs[i]);
+            Vector(vshlq_u32(vector.0, shift_amount(rhs.0)))
+        }
+
+        #[inline]
+        fn vector_shr(vector: Vec3A<u32>, rhs: Vec3A<u32>) -> Vec3A<u32> {
+            overflow_check::<3, _>(|i| vector[i] >> rhs[i]);
+            // Negative shift amounts shift right.
+            Vector(vshlq_u32(vector.0, vnegq_s32(shift_amount(rhs.0))))
+        }
+
+        #[inline]
+        fn vector_bitand(vector: Vec3A<u32>, rhs: Vec3A<u32>) -> Vec3A<u32> {
+            Vector(vandq_u32(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_bitor(vector: Vec3A<u32>, rhs: Vec3A<u32>) -> Vec3A<u32> {
+            Vector(vorrq_u32(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_bitxor(vector: Vec3A<u32>, rhs: Vec3A<u32>) -> Vec3A<u32> {
+            Vector(veorq_u32(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_element_sum(vector: Vec3A<u32>) -> u32 {
+            vector.x + vector.y + vector.z
+        }
+
+        #[inline]
+        fn vector_element_product(vector: Vec3A<u32>) -> u32 {
+            vector.x * vector.y * vector.z
+        }
+
+        #[inline]
+        fn vector_dot(vector: Vec3A<u32>, rhs: Vec3A<u32>) -> u32 {
+            (vector * rhs).element_sum()
+        }
+
+        #[inline]
+        fn vector_mul_add_contracted(vector: Vec3A<u32>, a: Vec3A<u32>, b: Vec3A<u32>) -> Vec3A<u32> {
+            vector * a + b
+        }
+
+        #[inline]
+        fn vector_eq_mask(vector: Vec3A<u32>, other: Vec3A<u32>) -> Mask3A<u32> {
+            Mask(vceqq_u32(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_ne_mask(vector: Vec3A<u32>, other: Vec3A<u32>) -> Mask3A<u32> {
+            Mask(vmvnq_u32(vceqq_u32(vector.0, other.0)))
+        }
+
+        #[inline]
+        fn vector_lt_mask(vector: Vec3A<u32>, other: Vec3A<u32>) -> Mask3A<u32> {
+            Mask(vcltq_u32(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_gt_mask(vector: Vec3A<u32>, other: Vec3A<u32>) -> Mask3A<u32> {
+            Mask(vcgtq_u32(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_le_mask(vector: Vec3A<u32>, other: Vec3A<u32>) -> Mask3A<u32> {
+            Mask(vcleq_u32(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_ge_mask(vector: Vec3A<u32>, other: Vec3A<u32>) -> Mask3A<u32> {
+            Mask(vcgeq_u32(vector.0, other.0))
+        }
+    }
+}
+
+// `Self::Inner` follows its requirements.
+unsafe impl VectorBackend<4, Aligned> for u32 {
+    type Inner = uint32x4_t;
+
+    safe_target_feature! {
+        #[inline]
+        fn vector_eq(vector: &Vec4A<u32>, other: &Vec4A<u32>) -> bool {
+            vector.eq_mask(*other).all()
+        }
+
+        #[inline]
+        fn vector_ne(vector: &Vec4A<u32>, other: &Vec4A<u32>) -> bool {
+            !(vector == other)
+        }
+
+        #[inline]
+        fn vector_neg(_vector: Vec4A<u32>) -> Vec4A<u32> {
+            unreachable!("`u32` does not implement `Neg`")
+        }
+
+        #[inline]
+        fn vector_not(vector: Vec4A<u32>) -> Vec4A<u32> {
+            Vector(vmvnq_u32(vector.0))
+        }
+
+        #[inline]
+        fn vector_add(vector: Vec4A<u32>, rhs: Vec4A<u32>) -> Vec4A<u32> {
+            overflow_check::<4, _>(|i| vector[i] + rhs[i]);
+            Vector(vaddq_u32(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_sub(vector: Vec4A<u32>, rhs: Vec4A<u32>) -> Vec4A<u32> {
+            overflow_check::<4, _>(|i| vector[i] - rhs[i]);
+            Vector(vsubq_u32(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_mul(vector: Vec4A<u32>, rhs: Vec4A<u32>) -> Vec4A<u32> {
+            overflow_check::<4, _>(|i| vector[i] * rhs[i]);
+            Vector(vmulq_u32(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_div(vector: Vec4A<u32>, rhs: Vec4A<u32>) -> Vec4A<u32> {
+            Vec4A::<u32>::new(vector.x / rhs.x, vector.y / rhs.y, vector.z / rhs.z, vector.w / rhs.w)
+        }
+
+        #[inline]
+        fn vector_rem(vector: Vec4A<u32>, rhs: Vec4A<u32>) -> Vec4A<u32> {
+            Vec4A::<u32>::new(vector.x % rhs.x, vector.y % rhs.y, vector.z % rhs.z, vector.w % rhs.w)
+        }
+
+        #[inline]
+        fn vector_shl(vector: Vec4A<u32>, rhs: Vec4A<u32>) -> Vec4A<u32> {
+            overflow_check::<4, _>(|i| vector[i] << rhs[i]);
+            Vector(vshlq_u32(vector.0, shift_amount(rhs.0)))
+        }
+
+        #[inline]
+        fn vector_shr(vector: Vec4A<u32>, rhs: Vec4A<u32>) -> Vec4A<u32> {
+            overflow_check::<4, _>(|i| vector[i] >> rhs[i]);
+            // Negative shift amounts shift right.
+            Vector(vshlq_u32(vector.0, vnegq_s32(shift_amount(rhs.0))))
+        }
+
+        #[inline]
+        fn vector_bitand(vector: Vec4A<u32>, rhs: Vec4A<u32>) -> Vec4A<u32> {
+            Vector(vandq_u32(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_bitor(vector: Vec4A<u32>, rhs: Vec4A<u32>) -> Vec4A<u32> {
+            Vector(vorrq_u32(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_bitxor(vector: Vec4A<u32>, rhs: Vec4A<u32>) -> Vec4A<u32> {
+            Vector(veorq_u32(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_element_sum(vector: Vec4A<u32>) -> u32 {
+            vector.x + vector.y + (vector.z + vector.w)
+        }
+
+        #[inline]
+        fn vector_element_product(vector: Vec4A<u32>) -> u32 {
+            vector.x * vector.y * (vector.z * vector.w)
+        }
+
+        #[inline]
+        fn vector_dot(vector: Vec4A<u32>, rhs: Vec4A<u32>) -> u32 {
+            (vector * rhs).element_sum()
+        }
+
+        #[inline]
+        fn vector_mul_add_contracted(vector: Vec4A<u32>, a: Vec4A<u32>, b: Vec4A<u32>) -> Vec4A<u32> {
+            vector * a + b
+        }
+
+        #[inline]
+        fn vector_eq_mask(vector: Vec4A<u32>, other: Vec4A<u32>) -> Mask4A<u32> {
+            Mask(vceqq_u32(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_ne_mask(vector: Vec4A<u32>, other: Vec4A<u32>) -> Mask4A<u32> {
+            Mask(vmvnq_u32(vceqq_u32(vector.0, other.0)))
+        }
+
+        #[inline]
+        fn vector_lt_mask(vector: Vec4A<u32>, other: Vec4A<u32>) -> Mask4A<u32> {
+            Mask(vcltq_u32(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_gt_mask(vector: Vec4A<u32>, other: Vec4A<u32>) -> Mask4A<u32> {
+            Mask(vcgtq_u32(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_le_mask(vector: Vec4A<u32>, other: Vec4A<u32>) -> Mask4A<u32> {
+            Mask(vcleq_u32(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_ge_mask(vector: Vec4A<u32>, other: Vec4A<u32>) -> Mask4A<u32> {
+            Mask(vcgeq_u32(vector.0, other.0))
+        }
+    }
+}
+
+impl QuaternionBackend<Aligned> for u32 {
+    #[inline]
+    fn quat_mul(_quat: QuatA<u32>, _rhs: QuatA<u32>) -> QuatA<u32> {
+        unreachable!("`u32` does not implement `Neg`")
+    }
+}
+
+impl MatrixBackend<3, Aligned> for u32 {
+    #[inline]
+    fn matrix_transpose(matrix: &Mat3A<u32>) -> Mat3A<u32> {
+        matrix.transpose_generic()
+    }
+
+    #[inline]
+    fn matrix_mul(matrix: &Mat3A<u32>, rhs: &Mat3A<u32>) -> Mat3A<u32> {
+        matrix.mul_generic(rhs)
+    }
+
+    #[inline]
+    fn matrix_determinant(_matrix: &Mat3A<u32>) -> u32 {
+        unreachable!("`u32` does not implement `Neg`")
+    }
+}
+
+impl MatrixBackend<4, Aligned> for u32 {
+    #[inline]
+    fn matrix_transpose(matrix: &Mat4A<u32>) -> Mat4A<u32> {
+        matrix.transpose_generic()
+    }
+
+    #[inline]
+    fn matrix_mul(matrix: &Mat4A<u32>, rhs: &Mat4A<u32>) -> Mat4A<u32> {
+        matrix.mul_generic(rhs)
+    }
+
+    #[inline]
+    fn matrix_determinant(_matrix: &Mat4A<u32>) -> u32 {
+        unreachable!("`u32` does not implement `Neg`")
+    }
+}
+
+// `Self::Inner` follows its requirements.
+unsafe impl MaskBackend<3, Aligned> for u32 {
+    type Inner = uint32x4_t;
+
+    safe_target_feature! {
+        #[inline]
+        fn mask_from_array(array: [bool; 3]) -> Mask3A<u32> {
+            // SAFETY: Both types accept all bit-patterns.
+            Mask(unsafe {
+                transmute::<Repr4<i32>, uint32x4_t>(Repr4(
+                    -(array[0] as i32),
+                    -(array[1] as i32),
+                    -(array[2] as i32),
+                    -(array[2] as i32),
+                ))
+            })
+        }
+
+        #[inline]
+        fn mask_splat(value: bool) -> Mask3A<u32> {
+            Mask(vdupq_n_u32(-(value as i32) as u32))
+        }
+
+        #[inline]
+        fn mask_to_array(mask: Mask3A<u32>) -> [bool; 3] {
+            [
+                vgetq_lane_u32::<0>(mask.0) != 0,
+                vgetq_lane_u32::<1>(mask.0) != 0,
+                vgetq_lane_u32::<2>(mask.0) != 0,
+            ]
+        }
+
+        #[inline]
+        fn mask_all(mask: Mask3A<u32>) -> bool {
+            // SAFETY: Both types accept all bit-patterns.
+            const MASK: uint32x4_t = unsafe { transmute::<[u32; 4], uint32x4_t>([0b001, 0b010, 0b100, 0]) };
+
+            let masked = vandq_u32(mask.0, MASK);
+            let reduce_2 = vorr_u32(vget_low_u32(masked), vget_high_u32(masked));
+            let bitmask = vget_lane_u32::<0>(reduce_2) | vget_lane_u32::<1>(reduce_2);
+
+            bitmask == 0b111
+        }
+
+        #[inline]
+        fn mask_any(mask: Mask3A<u32>) -> bool {
+            // SAFETY: Both types accept all bit-patterns.
+            const MASK: uint32x4_t = unsafe { transmute::<[u32; 4], uint32x4_t>([0b001, 0b010, 0b100, 0]) };
+
+            let masked = vandq_u32(mask.0, MASK);
+            let reduce_2 = vorr_u32(vget_low_u32(masked), vget_high_u32(masked));
+            let bitmask = vget_lane_u32::<0>(reduce_2) | vget_lane_u32::<1>(reduce_2);
+
+            bitmask != 0
+        }
+
+        #[inline]
+        fn mask_select(mask: Mask3A<u32>, if_true: Vec3A<u32>, if_false: Vec3A<u32>) -> Vec3A<u32> {
+            Vector(vbslq_u32(mask.0, if_true.0, if_false.0))
+        }
+
+        #[inline]
+        fn mask_get(mask: Mask3A<u32>, index: usize) -> bool {
+            match index {
+                0 => vgetq_lane_u32::<0>(mask.0) != 0,
+                1 => vgetq_lane_u32::<1>(mask.0) != 0,
+                2 => vgetq_lane_u32::<2>(mask.0) != 0,
+                _ => panic!("index out of bounds"),
+            }
+        }
+
+        #[inline]
+        fn mask_set(mask: &mut Mask3A<u32>, index: usize, value: bool) {
+            let value = -(value as i32) as u32;
+            mask.0 = match index {
+                0 => vsetq_lane_u32::<0>(value, mask.0),
+                1 => vsetq_lane_u32::<1>(value, mask.0),
+                2 => vsetq_lane_u32::<2>(value, mask.0),
+                _ => panic!("index out of bounds"),
+            };
+        }
+
+        #[inline]
+        fn mask_eq(mask: &Mask3A<u32>, other: &Mask3A<u32>) -> bool {
+            // SAFETY: Both types accept all bit-patterns.
+            const MASK: uint32x4_t = unsafe { transmute::<[u32; 4], uint32x4_t>([0b001, 0b010, 0b100, 0]) };
+
+            let masked = vandq_u32(vceqq_u32(mask.0, other.0), MASK);
+            let reduce_2 = vorr_u32(vget_low_u32(masked), vget_high_u32(masked));
+            let bitmask = vget_lane_u32::<0>(reduce_2) | vget_lane_u32::<1>(reduce_2);
+
+            bitmask == 0b111
+        }
+
+        #[inline]
+        fn mask_ne(mask: &Mask3A<u32>, other: &Mask3A<u32>) -> bool {
+            !(mask == other)
+        }
+
+        #[inline]
+        fn mask_not(mask: Mask3A<u32>) -> Mask3A<u32> {
+            Mask(vmvnq_u32(mask.0))
+        }
+
+        #[inline]
+        fn mask_bitand(mask: Mask3A<u32>, rhs: Mask3A<u32>) -> Mask3A<u32> {
+            Mask(vandq_u32(mask.0, rhs.0))
+        }
+
+        #[inline]
+        fn mask_bitor(mask: Mask3A<u32>, rhs: Mask3A<u32>) -> Mask3A<u32> {
+            Mask(vorrq_u32(mask.0, rhs.0))
+        }
+
+        #[inline]
+        fn mask_bitxor(mask: Mask3A<u32>, rhs: Mask3A<u32>) -> Mask3A<u32> {
+            Mask(veorq_u32(mask.0, rhs.0))
+        }
+    }
+}
+
+// `Self::Inner` follows its requirements.
+unsafe impl MaskBackend<4, Aligned> for u32 {
+    type Inner = uint32x4_t;
+
+    safe_target_feature! {
+        #[inline]
+        fn mask_from_array(array: [bool; 4]) -> Mask4A<u32> {
+            // SAFETY: Both types accept all bit-patterns.
+            Mask(unsafe {
+                transmute::<Repr4<i32>, uint32x4_t>(Repr4(
+                    -(array[0] as i32),
+                    -(array[1] as i32),
+                    -(array[2] as i32),
+                    -(array[3] as i32),
+                ))
+            })
+        }
+
+        #[inline]
+        fn mask_splat(value: bool) -> Mask4A<u32> {
+            Mask(vdupq_n_u32(-(value as i32) as u32))
+        }
+
+        #[inline]
+        fn mask_to_array(mask: Mask4A<u32>) -> [bool; 4] {
+            [
+                vgetq_lane_u32::<0>(mask.0) != 0,
+                vgetq_lane_u32::<1>(mask.0) != 0,
+                vgetq_lane_u32::<2>(mask.0) != 0,
+                vgetq_lane_u32::<3>(mask.0) != 0,
+            ]
+        }
+
+        #[inline]
+        fn mask_all(mask: Mask4A<u32>) -> bool {
+            // SAFETY: Both types accept all bit-patterns.
+            const MASK: uint32x4_t = unsafe { transmute::<[u32; 4], uint32x4_t>([0b0001, 0b0010, 0b0100, 0b1000]) };
+
+            let masked = vandq_u32(mask.0, MASK);
+            let reduce_2 = vorr_u32(vget_low_u32(masked), vget_high_u32(masked));
+            let bitmask = vget_lane_u32::<0>(reduce_2) | vget_lane_u32::<1>(reduce_2);
+
+            bitmask == 0b1111
+        }
+
+        #[inline]
+        fn mask_any(mask: Mask4A<u32>) -> bool {
+            // SAFETY: Both types accept all bit-patterns.
+            const MASK: uint32x4_t = unsafe { transmute::<[u32; 4], uint32x4_t>([0b0001, 0b0010, 0b0100, 0b1000]) };
+
+            let masked = vandq_u32(mask.0, MASK);
+            let reduce_2 = vorr_u32(vget_low_u32(masked), vget_high_u32(masked));
+            let bitmask = vget_lane_u32::<0>(reduce_2) | vget_lane_u32::<1>(reduce_2);
+
+            bitmask != 0
+        }
+
+        #[inline]
+        fn mask_select(mask: Mask4A<u32>, if_true: Vec4A<u32>, if_false: Vec4A<u32>) -> Vec4A<u32> {
+            Vector(vbslq_u32(mask.0, if_true.0, if_false.0))
+        }
+
+        #[inline]
+        fn mask_get(mask: Mask4A<u32>, index: usize) -> bool {
+            match index {
+                0 => vgetq_lane_u32::<0>(mask.0) != 0,
+                1 => vgetq_lane_u32::<1>(mask.0) != 0,
+                2 => vgetq_lane_u32::<2>(mask.0) != 0,
+                3 => vgetq_lane_u32::<3>(mask.0) != 0,
+                _ => panic!("index out of bounds"),
+            }
+        }
+
+        #[inline]
+        fn mask_set(mask: &mut Mask4A<u32>, index: usize, value: bool) {
+            let value = -(value as i32) as u32;
+            mask.0 = match index {
+                0 => vsetq_lane_u32::<0>(value, mask.0),
+                1 => vsetq_lane_u32::<1>(value, mask.0),
+                2 => vsetq_lane_u32::<2>(value, mask.0),
+                3 => vsetq_lane_u32::<3>(value, mask.0),
+                _ => panic!("index out of bounds"),
+            };
+        }
+
+        #[inline]
+        fn mask_eq(mask: &Mask4A<u32>, other: &Mask4A<u32>) -> bool {
+            // SAFETY: Both types accept all bit-patterns.
+            const MASK: uint32x4_t = unsafe { transmute::<[u32; 4], uint32x4_t>([0b0001, 0b0010, 0b0100, 0b1000]) };
+
+            let masked = vandq_u32(vceqq_u32(mask.0, other.0), MASK);
+            let reduce_2 = vorr_u32(vget_low_u32(masked), vget_high_u32(masked));
+            let bitmask = vget_lane_u32::<0>(reduce_2) | vget_lane_u32::<1>(reduce_2);
+
+            bitmask == 0b1111
+        }
+
+        #[inline]
+        fn mask_ne(mask: &Mask4A<u32>, other: &Mask4A<u32>) -> bool {
+            !(mask == other)
+        }
+
+        #[inline]
+        fn mask_not(mask: Mask4A<u32>) -> Mask4A<u32> {
+            Mask(vmvnq_u32(mask.0))
+        }
+
+        #[inline]
+        fn mask_bitand(mask: Mask4A<u32>, rhs: Mask4A<u32>) -> Mask4A<u32> {
+            Mask(vandq_u32(mask.0, rhs.0))
+        }
+
+        #[inline]
+        fn mask_bitor(mask: Mask4A<u32>, rhs: Mask4A<u32>) -> Mask4A<u32> {
+            Mask(vorrq_u32(mask.0, rhs.0))
+        }
+
+        #[inline]
+        fn mask_bitxor(mask: Mask4A<u32>, rhs: Mask4A<u32>) -> Mask4A<u32> {
+            Mask(veorq_u32(mask.0, rhs.0))
+        }
+    }
+}
+
+impl IntegerVectorBackend<3, Aligned> for u32 {
+    safe_target_feature! {
+        #[inline]
+        fn vector_max(vector: Vec3A<u32>, other: Vec3A<u32>) -> Vec3A<u32> {
+            Vector(vmaxq_u32(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_min(vector: Vec3A<u32>, other: Vec3A<u32>) -> Vec3A<u32> {
+            Vector(vminq_u32(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_max_element(vector: Vec3A<u32>) -> u32 {
+            vmaxvq_u32(vcopyq_laneq_u32::<3, 2>(vector.0, vector.0))
+        }
+
+        #[inline]
+        fn vector_min_element(vector: Vec3A<u32>) -> u32 {
+            vminvq_u32(vcopyq_laneq_u32::<3, 2>(vector.0, vector.0))
+        }
+
+        #[inline]
+        fn vector_checked_add(vector: Vec3A<u32>, rhs: Vec3A<u32>) -> Option<Vec3A<u32>> {
+            let result = vaddq_u32(vector.0, rhs.0);
+            let saturated = vqaddq_u32(vector.0, rhs.0);
+
+            if Vector::<3, u32, Aligned>(result) == Vector(saturated) {
+                Some(Vector(result))
+            } else {
+                None
+            }
+        }
+
+        #[inline]
+        fn vector_checked_sub(vector: Vec3A<u32>, rhs: Vec3A<u32>) -> Option<Vec3A<u32>> {
+            let result = vsubq_u32(vector.0, rhs.0);
+            let saturated = vqsubq_u32(vector.0, rhs.0);
+
+            if Vector::<3, u32, Aligned>(result) == Vector(saturated) {
+                Some(Vector(result))
+            } else {
+                None
+            }
+        }
+
+        #[inline]
+        fn vector_checked_mul(vector: Vec3A<u32>, rhs: Vec3A<u32>) -> Option<Vec3A<u32>> {
+            Some(Vec3A::<u32>::new(vector.x.checked_mul(rhs.x)?, vector.y.checked_mul(rhs.y)?, vector.z.checked_mul(rhs.z)?))
+        }
+
+        #[inline]
+        fn vector_checked_div(vector: Vec3A<u32>, rhs: Vec3A<u32>) -> Option<Vec3A<u32>> {
+            Some(Vec3A::<u32>::new(vector.x.checked_div(rhs.x)?, vector.y.checked_div(rhs.y)?, vector.z.checked_div(rhs.z)?))
+        }
+
+        #[inline]
+        fn vector_checked_rem(vector: Vec3A<u32>, rhs: Vec3A<u32>) -> Option<Vec3A<u32>> {
+            Some(Vec3A::<u32>::new(vector.x.checked_rem(rhs.x)?, vector.y.checked_rem(rhs.y)?, vector.z.checked_rem(rhs.z)?))
+        }
+
+        #[inline]
+        fn vector_saturating_add(vector: Vec3A<u32>, rhs: Vec3A<u32>) -> Vec3A<u32> {
+            Vector(vqaddq_u32(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_saturating_sub(vector: Vec3A<u32>, rhs: Vec3A<u32>) -> Vec3A<u32> {
+            Vector(vqsubq_u32(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_saturating_mul(vector: Vec3A<u32>, rhs: Vec3A<u32>) -> Vec3A<u32> {
+            Vec3A::<u32>::new(vector.x.saturating_mul(rhs.x), vector.y.saturating_mul(rhs.y), vector.z.saturating_mul(rhs.z))
+        }
+
+        #[inline]
+        fn vector_saturating_div(vector: Vec3A<u32>, rhs: Vec3A<u32>) -> Vec3A<u32> {
+            Vec3A::<u32>::new(vector.x.saturating_div(rhs.x), vector.y.saturating_div(rhs.y), vector.z.saturating_div(rhs.z))
+        }
+
+        #[inline]
+        fn vector_wrapping_add(vector: Vec3A<u32>, rhs: Vec3A<u32>) -> Vec3A<u32> {
+            Vector(vaddq_u32(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_wrapping_sub(vector: Vec3A<u32>, rhs: Vec3A<u32>) -> Vec3A<u32> {
+            Vector(vsubq_u32(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_wrapping_mul(vector: Vec3A<u32>, rhs: Vec3A<u32>) -> Vec3A<u32> {
+            Vector(vmulq_u32(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_wrapping_div(vector: Vec3A<u32>, rhs: Vec3A<u32>) -> Vec3A<u32> {
+            Vec3A::<u32>::new(vector.x.wrapping_div(rhs.x), vector.y.wrapping_div(rhs.y), vector.z.wrapping_div(rhs.z))
+        }
+
+        #[inline]
+        fn vector_wrapping_rem(vector: Vec3A<u32>, rhs: Vec3A<u32>) -> Vec3A<u32> {
+            Vec3A::<u32>::new(vector.x.wrapping_rem(rhs.x), vector.y.wrapping_rem(rhs.y), vector.z.wrapping_rem(rhs.z))
+        }
+    }
+}
+
+impl IntegerVectorBackend<4, Aligned> for u32 {
+    safe_target_feature! {
+        #[inline]
+        fn vector_max(vector: Vec4A<u32>, other: Vec4A<u32>) -> Vec4A<u32> {
+            Vector(vmaxq_u32(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_min(vector: Vec4A<u32>, other: Vec4A<u32>) -> Vec4A<u32> {
+            Vector(vminq_u32(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_max_element(vector: Vec4A<u32>) -> u32 {
+            vmaxvq_u32(vector.0)
+        }
+
+        #[inline]
+        fn vector_min_element(vector: Vec4A<u32>) -> u32 {
+            vminvq_u32(vector.0)
+        }
+
+        #[inline]
+        fn vector_checked_add(vector: Vec4A<u32>, rhs: Vec4A<u32>) -> Option<Vec4A<u32>> {
+            let result = vaddq_u32(vector.0, rhs.0);
+            let saturated = vqaddq_u32(vector.0, rhs.0);
+
+            if Vector::<4, u32, Aligned>(result) == Vector(saturated) {
+                Some(Vector(result))
+            } else {
+                None
+            }
+        }
+
+        #[inline]
+        fn vector_checked_sub(vector: Vec4A<u32>, rhs: Vec4A<u32>) -> Option<Vec4A<u32>> {
+            let result = vsubq_u32(vector.0, rhs.0);
+            let saturated = vqsubq_u32(vector.0, rhs.0);
+
+            if Vector::<4, u32, Aligned>(result) == Vector(saturated) {
+                Some(Vector(result))
+            } else {
+                None
+            }
+        }
+
+        #[inline]
+        fn vector_checked_mul(vector: Vec4A<u32>, rhs: Vec4A<u32>) -> Option<Vec4A<u32>> {
+            Some(Vec4A::<u32>::new(vector.x.checked_mul(rhs.x)?, vector.y.checked_mul(rhs.y)?, vector.z.checked_mul(rhs.z)?, vector.w.checked_mul(rhs.w)?))
+        }
+
+        #[inline]
+        fn vector_checked_div(vector: Vec4A<u32>, rhs: Vec4A<u32>) -> Option<Vec4A<u32>> {
+            Some(Vec4A::<u32>::new(vector.x.checked_div(rhs.x)?, vector.y.checked_div(rhs.y)?, vector.z.checked_div(rhs.z)?, vector.w.checked_div(rhs.w)?))
+        }
+
+        #[inline]
+        fn vector_checked_rem(vector: Vec4A<u32>, rhs: Vec4A<u32>) -> Option<Vec4A<u32>> {
+            Some(Vec4A::<u32>::new(vector.x.checked_rem(rhs.x)?, vector.y.checked_rem(rhs.y)?, vector.z.checked_rem(rhs.z)?, vector.w.checked_rem(rhs.w)?))
+        }
+
+        #[inline]
+        fn vector_saturating_add(vector: Vec4A<u32>, rhs: Vec4A<u32>) -> Vec4A<u32> {
+            Vector(vqaddq_u32(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_saturating_sub(vector: Vec4A<u32>, rhs: Vec4A<u32>) -> Vec4A<u32> {
+            Vector(vqsubq_u32(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_saturating_mul(vector: Vec4A<u32>, rhs: Vec4A<u32>) -> Vec4A<u32> {
+            Vec4A::<u32>::new(vector.x.saturating_mul(rhs.x), vector.y.saturating_mul(rhs.y), vector.z.saturating_mul(rhs.z), vector.w.saturating_mul(rhs.w))
+        }
+
+        #[inline]
+        fn vector_saturating_div(vector: Vec4A<u32>, rhs: Vec4A<u32>) -> Vec4A<u32> {
+            Vec4A::<u32>::new(vector.x.saturating_div(rhs.x), vector.y.saturating_div(rhs.y), vector.z.saturating_div(rhs.z), vector.w.saturating_div(rhs.w))
+        }
+
+        #[inline]
+        fn vector_wrapping_add(vector: Vec4A<u32>, rhs: Vec4A<u32>) -> Vec4A<u32> {
+            Vector(vaddq_u32(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_wrapping_sub(vector: Vec4A<u32>, rhs: Vec4A<u32>) -> Vec4A<u32> {
+            Vector(vsubq_u32(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_wrapping_mul(vector: Vec4A<u32>, rhs: Vec4A<u32>) -> Vec4A<u32> {
+            Vector(vmulq_u32(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_wrapping_div(vector: Vec4A<u32>, rhs: Vec4A<u32>) -> Vec4A<u32> {
+            Vec4A::<u32>::new(vector.x.wrapping_div(rhs.x), vector.y.wrapping_div(rhs.y), vector.z.wrapping_div(rhs.z), vector.w.wrapping_div(rhs.w))
+        }
+
+        #[inline]
+        fn vector_wrapping_rem(vector: Vec4A<u32>, rhs: Vec4A<u32>) -> Vec4A<u32> {
+            Vec4A::<u32>::new(vector.x.wrapping_rem(rhs.x), vector.y.wrapping_rem(rhs.y), vector.z.wrapping_rem(rhs.z), vector.w.wrapping_rem(rhs.w))
+        }
+    }
+}
+
+impl CastBackend<3, Aligned> for u32 {
+    safe_target_feature! {
+        #[inline]
+        fn vector_as_f32(vector: Vec3A<u32>) -> Vec3A<f32> {
+            Vector(vcvtq_f32_u32(vector.0))
+        }
+
+        #[inline]
+        fn vector_as_f64(vector: Vec3A<u32>) -> Vec3A<f64> {
+            vector.map(|x| x as f64)
+        }
+
+        #[inline]
+        fn vector_as_i32(vector: Vec3A<u32>) -> Vec3A<i32> {
+            Vector(vreinterpretq_s32_u32(vector.0))
+        }
+
+        #[inline]
+        fn vector_as_u32(vector: Vec3A<u32>) -> Vec3A<u32> {
+            vector
+        }
+    }
+}
+
+impl CastBackend<4, Aligned> for u32 {
+    safe_target_feature! {
+        #[inline]
+        fn vector_as_f32(vector: Vec4A<u32>) -> Vec4A<f32> {
+            Vector(vcvtq_f32_u32(vector.0))
+        }
+
+        #[inline]
+        fn vector_as_f64(vector: Vec4A<u32>) -> Vec4A<f64> {
+            vector.map(|x| x as f64)
+        }
+
+        #[inline]
+        fn vector_as_i32(vector: Vec4A<u32>) -> Vec4A<i32> {
+            Vector(vreinterpretq_s32_u32(vector.0))
+        }
+
+        #[inline]
+        fn vector_as_u32(vector: Vec4A<u32>) -> Vec4A<u32> {
+            vector
+        }
+    }
+}
+
+safe_target_feature! {
+    #[inline]
+    fn shift_amount(rhs: uint32x4_t) -> int32x4_t {
+        // Matches the scalar shift operators without overflow checks, which
+        // only use the low 5 bits of the shift amount.
+        vandq_s32(vreinterpretq_s32_u32(rhs), vdupq_n_s32(31))
+    }
+}
@@ -1 +1,6 @@
 mod f32;
+#[cfg(all(feature = "f16", target_feature = "f16c"))]
+mod f16;
+mod f64;
+mod i32;
+mod u32;
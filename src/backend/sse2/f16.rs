@@ -0,0 +1,46 @@
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+use core::mem::transmute;
+
+use half::f16;
+
+use crate::{Alignment, Vector, backend::HalfBackend, utils::safe_target_feature};
+
+impl<A: Alignment> HalfBackend<4, A> for f32 {
+    #[inline]
+    fn vector_to_f16(vector: Vector<4, f32, A>) -> Vector<4, f16, A> {
+        Vector::from_array(f32x4_to_f16x4(vector.to_array()))
+    }
+
+    #[inline]
+    fn vector_from_f16(vector: Vector<4, f16, A>) -> Vector<4, f32, A> {
+        Vector::from_array(f16x4_to_f32x4(vector.to_array()))
+    }
+}
+
+safe_target_feature! {
+    #[inline]
+    fn f32x4_to_f16x4(array: [f32; 4]) -> [f16; 4] {
+        // SAFETY: `__m128` accepts all bit-patterns.
+        let vector = unsafe { transmute::<[f32; 4], __m128>(array) };
+        let result = _mm_cvtps_ph::<_MM_FROUND_TO_NEAREST_INT>(vector);
+
+        // SAFETY: `f16` accepts all bit-patterns.
+        let [x, y, z, w, ..] = unsafe { transmute::<__m128i, [f16; 8]>(result) };
+        [x, y, z, w]
+    }
+
+    #[inline]
+    fn f16x4_to_f32x4(array: [f16; 4]) -> [f32; 4] {
+        let [x, y, z, w] = array;
+        let padded = [x, y, z, w, f16::ZERO, f16::ZERO, f16::ZERO, f16::ZERO];
+
+        // SAFETY: `__m128i` accepts all bit-patterns.
+        let vector = unsafe { transmute::<[f16; 8], __m128i>(padded) };
+
+        // SAFETY: `f32` accepts all bit-patterns.
+        unsafe { transmute::<__m128, [f32; 4]>(_mm_cvtph_ps(vector)) }
+    }
+}
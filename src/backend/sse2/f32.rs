@@ -3,14 +3,22 @@ use core::arch::x86::*;
 #[cfg(target_arch = "x86_64")]
 use core::arch::x86_64::*;
 
-#[allow(unused_imports, reason = "rustc incorrectly thinks this is unused")]
+#[cfg(not(target_feature = "avx"))]
+use super::f64::M128dPair;
 use crate::utils::PrimitiveFloatUtils;
 use crate::{
-    Aligned, Mask, Mask3A, Mask4A, QuatA, Quaternion, Vec3A, Vec4A, Vector,
-    backend::{FloatVectorBackend, MaskBackend, QuaternionBackend, VectorBackend},
+    Aligned, Mask, Mask3A, Mask4A, Mat3A, Mat4A, Matrix, QuatA, Quaternion, Vec3A, Vec4A, Vector,
+    backend::{
+        CastBackend, FloatMatrixBackend, FloatVectorBackend, MaskBackend, MatrixBackend,
+        QuaternionBackend, VectorBackend,
+    },
     utils::safe_target_feature,
 };
 
+/// The largest magnitude for which the polynomial sine and cosine are
+/// accurate. Larger inputs use the scalar implementation.
+const SIN_COS_MAX: f32 = 8192.0;
+
 // `Self::Inner` follows its requirements.
 unsafe impl VectorBackend<3, Aligned> for f32 {
     type Inner = __m128;
@@ -104,6 +112,28 @@ unsafe impl VectorBackend<3, Aligned> for f32 {
             _mm_cvtss_f32(vector)
         }
 
+        #[cfg(not(target_feature = "sse4.1"))]
+        #[inline]
+        fn vector_dot(vector: Vec3A<f32>, rhs: Vec3A<f32>) -> f32 {
+            (vector * rhs).element_sum()
+        }
+
+        #[cfg(target_feature = "sse4.1")]
+        #[inline]
+        fn vector_dot(vector: Vec3A<f32>, rhs: Vec3A<f32>) -> f32 {
+            // `_mm_dp_ps` computes `(x + y) + (z + w)`. Masking out `w` would
+            // make it `+0.0` which turns a `-0.0` result into `0.0`, so the
+            // padding is replaced to make the `w` product `-0.0` instead.
+            let vector = _mm_blend_ps::<0b1000>(vector.0, _mm_set1_ps(-0.0));
+            let rhs = _mm_blend_ps::<0b1000>(rhs.0, _mm_set1_ps(0.0));
+            _mm_cvtss_f32(_mm_dp_ps::<0xf1>(vector, rhs))
+        }
+
+        #[inline]
+        fn vector_mul_add_contracted(vector: Vec3A<f32>, a: Vec3A<f32>, b: Vec3A<f32>) -> Vec3A<f32> {
+            vector * a + b
+        }
+
         #[inline]
         fn vector_eq_mask(vector: Vec3A<f32>, other: Vec3A<f32>) -> Mask3A<f32> {
             Mask(_mm_cmpeq_ps(vector.0, other.0))
@@ -236,6 +266,25 @@ unsafe impl VectorBackend<4, Aligned> for f32 {
             _mm_cvtss_f32(vector)
         }
 
+        #[cfg(not(target_feature = "sse4.1"))]
+        #[inline]
+        fn vector_dot(vector: Vec4A<f32>, rhs: Vec4A<f32>) -> f32 {
+            (vector * rhs).element_sum()
+        }
+
+        #[cfg(target_feature = "sse4.1")]
+        #[inline]
+        fn vector_dot(vector: Vec4A<f32>, rhs: Vec4A<f32>) -> f32 {
+            // `_mm_dp_ps` computes `(x + y) + (z + w)` which is the same order
+            // as `element_sum`.
+            _mm_cvtss_f32(_mm_dp_ps::<0xf1>(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_mul_add_contracted(vector: Vec4A<f32>, a: Vec4A<f32>, b: Vec4A<f32>) -> Vec4A<f32> {
+            vector * a + b
+        }
+
         #[inline]
         fn vector_eq_mask(vector: Vec4A<f32>, other: Vec4A<f32>) -> Mask4A<f32> {
             Mask(_mm_cmpeq_ps(vector.0, other.0))
@@ -290,6 +339,99 @@ impl QuaternionBackend<Aligned> for f32 {
     }
 }
 
+impl MatrixBackend<3, Aligned> for f32 {
+    #[inline]
+    fn matrix_transpose(matrix: &Mat3A<f32>) -> Mat3A<f32> {
+        matrix.transpose_generic()
+    }
+
+    #[inline]
+    fn matrix_mul(matrix: &Mat3A<f32>, rhs: &Mat3A<f32>) -> Mat3A<f32> {
+        matrix.mul_generic(rhs)
+    }
+
+    #[inline]
+    fn matrix_determinant(matrix: &Mat3A<f32>) -> f32 {
+        matrix.determinant_generic()
+    }
+}
+
+impl MatrixBackend<4, Aligned> for f32 {
+    safe_target_feature! {
+        #[inline]
+        fn matrix_transpose(matrix: &Mat4A<f32>) -> Mat4A<f32> {
+            // Same as `_MM_TRANSPOSE4_PS`.
+            let xy_low = _mm_unpacklo_ps(matrix.x_axis.0, matrix.y_axis.0);
+            let zw_low = _mm_unpacklo_ps(matrix.z_axis.0, matrix.w_axis.0);
+            let xy_high = _mm_unpackhi_ps(matrix.x_axis.0, matrix.y_axis.0);
+            let zw_high = _mm_unpackhi_ps(matrix.z_axis.0, matrix.w_axis.0);
+
+            Matrix::from_rows(&[
+                Vector(_mm_movelh_ps(xy_low, zw_low)),
+                Vector(_mm_movehl_ps(zw_low, xy_low)),
+                Vector(_mm_movelh_ps(xy_high, zw_high)),
+                Vector(_mm_movehl_ps(zw_high, xy_high)),
+            ])
+        }
+
+        #[inline]
+        fn matrix_mul(matrix: &Mat4A<f32>, rhs: &Mat4A<f32>) -> Mat4A<f32> {
+            Matrix::from_rows(&[
+                Vector(vector_mul_matrix(matrix.x_axis.0, rhs)),
+                Vector(vector_mul_matrix(matrix.y_axis.0, rhs)),
+                Vector(vector_mul_matrix(matrix.z_axis.0, rhs)),
+                Vector(vector_mul_matrix(matrix.w_axis.0, rhs)),
+            ])
+        }
+
+        #[inline]
+        fn matrix_determinant(matrix: &Mat4A<f32>) -> f32 {
+            // Performs the same operations as the generic implementation.
+            let (y, z, w) = (matrix.y_axis.0, matrix.z_axis.0, matrix.w_axis.0);
+
+            // `[det_23_23, det_13_23, det_12_23, det_03_23]`
+            let z_zyyx = _mm_shuffle_ps(z, z, 0b00_01_01_10);
+            let z_wwzw = _mm_shuffle_ps(z, z, 0b11_10_11_11);
+            let w_zyyx = _mm_shuffle_ps(w, w, 0b00_01_01_10);
+            let w_wwzw = _mm_shuffle_ps(w, w, 0b11_10_11_11);
+            let dets_23_1234 = _mm_sub_ps(_mm_mul_ps(z_zyyx, w_wwzw), _mm_mul_ps(z_wwzw, w_zyyx));
+
+            // `[det_02_23, det_01_23, _, _]`
+            let dets_23_56 = {
+                let products = _mm_mul_ps(
+                    _mm_shuffle_ps(z, z, 0b01_10_00_00),
+                    _mm_shuffle_ps(w, w, 0b00_00_01_10),
+                );
+
+                _mm_sub_ps(products, _mm_shuffle_ps(products, products, 0b00_00_11_10))
+            };
+
+            // `[det_123_123, det_023_123, det_013_123, det_012_123]`
+            let dets_123 = {
+                let dets_23_1123 = _mm_shuffle_ps(dets_23_1234, dets_23_1234, 0b10_01_00_00);
+                let dets_23_2445 = _mm_shuffle_ps(dets_23_1234, dets_23_56, 0b00_00_11_01);
+                let dets_23_2445 = _mm_shuffle_ps(dets_23_2445, dets_23_2445, 0b11_01_01_00);
+                let dets_23_3566 = _mm_shuffle_ps(dets_23_1234, dets_23_56, 0b01_00_10_10);
+                let dets_23_3566 = _mm_shuffle_ps(dets_23_3566, dets_23_3566, 0b11_11_10_00);
+
+                _mm_add_ps(
+                    _mm_sub_ps(
+                        _mm_mul_ps(_mm_shuffle_ps(y, y, 0b00_00_00_01), dets_23_1123),
+                        _mm_mul_ps(_mm_shuffle_ps(y, y, 0b01_01_10_10), dets_23_2445),
+                    ),
+                    _mm_mul_ps(_mm_shuffle_ps(y, y, 0b10_11_11_11), dets_23_3566),
+                )
+            };
+
+            const PNPN: Vec4A<f32> = Vec4A::new(0.0, -0.0, 0.0, -0.0);
+            let cofactors: Vec4A<f32> =
+                Vector(_mm_xor_ps(_mm_mul_ps(matrix.x_axis.0, dets_123), PNPN.0));
+
+            cofactors.element_sum()
+        }
+    }
+}
+
 // `Self::Inner` follows its requirements.
 unsafe impl MaskBackend<3, Aligned> for f32 {
     type Inner = __m128;
@@ -326,6 +468,7 @@ unsafe impl MaskBackend<3, Aligned> for f32 {
             _mm_movemask_ps(mask.0) & 0x7 != 0
         }
 
+        #[cfg(not(target_feature = "sse4.1"))]
         #[inline]
         fn mask_select(mask: Mask3A<f32>, if_true: Vec3A<f32>, if_false: Vec3A<f32>) -> Vec3A<f32> {
             Vector(_mm_or_ps(
@@ -334,6 +477,12 @@ unsafe impl MaskBackend<3, Aligned> for f32 {
             ))
         }
 
+        #[cfg(target_feature = "sse4.1")]
+        #[inline]
+        fn mask_select(mask: Mask3A<f32>, if_true: Vec3A<f32>, if_false: Vec3A<f32>) -> Vec3A<f32> {
+            Vector(_mm_blendv_ps(if_false.0, if_true.0, mask.0))
+        }
+
         #[inline]
         fn mask_get(mask: Mask3A<f32>, index: usize) -> bool {
             match index {
@@ -437,6 +586,7 @@ unsafe impl MaskBackend<4, Aligned> for f32 {
             _mm_movemask_ps(mask.0) != 0
         }
 
+        #[cfg(not(target_feature = "sse4.1"))]
         #[inline]
         fn mask_select(mask: Mask4A<f32>, if_true: Vec4A<f32>, if_false: Vec4A<f32>) -> Vec4A<f32> {
             Vector(_mm_or_ps(
@@ -445,6 +595,12 @@ unsafe impl MaskBackend<4, Aligned> for f32 {
             ))
         }
 
+        #[cfg(target_feature = "sse4.1")]
+        #[inline]
+        fn mask_select(mask: Mask4A<f32>, if_true: Vec4A<f32>, if_false: Vec4A<f32>) -> Vec4A<f32> {
+            Vector(_mm_blendv_ps(if_false.0, if_true.0, mask.0))
+        }
+
         #[inline]
         fn mask_get(mask: Mask4A<f32>, index: usize) -> bool {
             match index {
@@ -620,7 +776,11 @@ impl FloatVectorBackend<3, Aligned> for f32 {
 
         #[inline(always)]
         fn vector_powf(vector: Vec3A<f32>, n: f32) -> Vec3A<f32> {
-            Vec3A::new(vector.x.powf(n), vector.y.powf(n), vector.z.powf(n))
+            Vec3A::new(
+                PrimitiveFloatUtils::powf(vector.x, n),
+                PrimitiveFloatUtils::powf(vector.y, n),
+                PrimitiveFloatUtils::powf(vector.z, n),
+            )
         }
 
         #[inline(always)]
@@ -628,65 +788,165 @@ impl FloatVectorBackend<3, Aligned> for f32 {
             Vector(_mm_sqrt_ps(vector.0))
         }
 
-        #[inline(always)]
+        #[inline]
+        fn vector_rsqrt(vector: Vec3A<f32>) -> Vec3A<f32> {
+            Vector(rsqrt(vector.0))
+        }
+
+        #[inline]
+        fn vector_recip_fast(vector: Vec3A<f32>) -> Vec3A<f32> {
+            Vector(recip_fast(vector.0))
+        }
+
+        #[inline]
+        fn vector_length_recip_fast(vector: Vec3A<f32>) -> f32 {
+            _mm_cvtss_f32(rsqrt(_mm_set_ss(vector.dot(vector))))
+        }
+
+        #[inline]
+        fn vector_normalize_fast(vector: Vec3A<f32>) -> Vec3A<f32> {
+            Vector(_mm_mul_ps(vector.0, rsqrt(_mm_set1_ps(vector.dot(vector)))))
+        }
+
+        #[inline]
         fn vector_exp(vector: Vec3A<f32>) -> Vec3A<f32> {
-            Vec3A::new(vector.x.exp(), vector.y.exp(), vector.z.exp())
+            if cfg!(feature = "deterministic") {
+                Vec3A::new(
+                    PrimitiveFloatUtils::exp(vector.x),
+                    PrimitiveFloatUtils::exp(vector.y),
+                    PrimitiveFloatUtils::exp(vector.z),
+                )
+            } else {
+                Vector(exp(vector.0))
+            }
         }
 
-        #[inline(always)]
+        #[inline]
         fn vector_exp2(vector: Vec3A<f32>) -> Vec3A<f32> {
-            Vec3A::new(vector.x.exp2(), vector.y.exp2(), vector.z.exp2())
+            if cfg!(feature = "deterministic") {
+                Vec3A::new(
+                    PrimitiveFloatUtils::exp2(vector.x),
+                    PrimitiveFloatUtils::exp2(vector.y),
+                    PrimitiveFloatUtils::exp2(vector.z),
+                )
+            } else {
+                Vector(exp2(vector.0))
+            }
         }
 
-        #[inline(always)]
+        #[inline]
         fn vector_ln(vector: Vec3A<f32>) -> Vec3A<f32> {
-            Vec3A::new(vector.x.ln(), vector.y.ln(), vector.z.ln())
+            if cfg!(feature = "deterministic") {
+                Vec3A::new(
+                    PrimitiveFloatUtils::ln(vector.x),
+                    PrimitiveFloatUtils::ln(vector.y),
+                    PrimitiveFloatUtils::ln(vector.z),
+                )
+            } else {
+                Vector(ln(vector.0))
+            }
         }
 
-        #[inline(always)]
+        #[inline]
         fn vector_log2(vector: Vec3A<f32>) -> Vec3A<f32> {
-            Vec3A::new(vector.x.log2(), vector.y.log2(), vector.z.log2())
+            if cfg!(feature = "deterministic") {
+                Vec3A::new(
+                    PrimitiveFloatUtils::log2(vector.x),
+                    PrimitiveFloatUtils::log2(vector.y),
+                    PrimitiveFloatUtils::log2(vector.z),
+                )
+            } else {
+                Vector(log2(vector.0))
+            }
         }
 
-        #[inline(always)]
+        #[inline]
         fn vector_sin(vector: Vec3A<f32>) -> Vec3A<f32> {
-            Vec3A::new(vector.x.sin(), vector.y.sin(), vector.z.sin())
+            let out_of_range: Mask3A<f32> = Mask(sin_cos_out_of_range(vector.0));
+            if cfg!(feature = "deterministic") || out_of_range.any() {
+                Vec3A::new(
+                    PrimitiveFloatUtils::sin(vector.x),
+                    PrimitiveFloatUtils::sin(vector.y),
+                    PrimitiveFloatUtils::sin(vector.z),
+                )
+            } else {
+                Vector(sin_cos(vector.0).0)
+            }
         }
 
-        #[inline(always)]
+        #[inline]
         fn vector_cos(vector: Vec3A<f32>) -> Vec3A<f32> {
-            Vec3A::new(vector.x.cos(), vector.y.cos(), vector.z.cos())
+            let out_of_range: Mask3A<f32> = Mask(sin_cos_out_of_range(vector.0));
+            if cfg!(feature = "deterministic") || out_of_range.any() {
+                Vec3A::new(
+                    PrimitiveFloatUtils::cos(vector.x),
+                    PrimitiveFloatUtils::cos(vector.y),
+                    PrimitiveFloatUtils::cos(vector.z),
+                )
+            } else {
+                Vector(sin_cos(vector.0).1)
+            }
         }
 
         #[inline(always)]
         fn vector_tan(vector: Vec3A<f32>) -> Vec3A<f32> {
-            Vec3A::new(vector.x.tan(), vector.y.tan(), vector.z.tan())
+            Vec3A::new(
+                PrimitiveFloatUtils::tan(vector.x),
+                PrimitiveFloatUtils::tan(vector.y),
+                PrimitiveFloatUtils::tan(vector.z),
+            )
         }
 
         #[inline(always)]
         fn vector_asin(vector: Vec3A<f32>) -> Vec3A<f32> {
-            Vec3A::new(vector.x.asin(), vector.y.asin(), vector.z.asin())
+            Vec3A::new(
+                PrimitiveFloatUtils::asin(vector.x),
+                PrimitiveFloatUtils::asin(vector.y),
+                PrimitiveFloatUtils::asin(vector.z),
+            )
         }
 
-        #[inline(always)]
+        #[inline]
         fn vector_acos(vector: Vec3A<f32>) -> Vec3A<f32> {
-            Vec3A::new(vector.x.acos(), vector.y.acos(), vector.z.acos())
+            if cfg!(feature = "deterministic") {
+                Vec3A::new(
+                    PrimitiveFloatUtils::acos(vector.x),
+                    PrimitiveFloatUtils::acos(vector.y),
+                    PrimitiveFloatUtils::acos(vector.z),
+                )
+            } else {
+                Vector(acos(vector.0))
+            }
         }
 
-        #[inline(always)]
+        #[inline]
         fn vector_atan(vector: Vec3A<f32>) -> Vec3A<f32> {
-            Vec3A::new(vector.x.atan(), vector.y.atan(), vector.z.atan())
+            if cfg!(feature = "deterministic") {
+                Vec3A::new(
+                    PrimitiveFloatUtils::atan(vector.x),
+                    PrimitiveFloatUtils::atan(vector.y),
+                    PrimitiveFloatUtils::atan(vector.z),
+                )
+            } else {
+                Vector(atan(vector.0))
+            }
         }
 
-        #[inline(always)]
+        #[inline]
         fn vector_sin_cos(vector: Vec3A<f32>) -> (Vec3A<f32>, Vec3A<f32>) {
-            let x_sin_cos = vector.x.sin_cos();
-            let y_sin_cos = vector.y.sin_cos();
-            let z_sin_cos = vector.z.sin_cos();
-            (
-                Vec3A::new(x_sin_cos.0, y_sin_cos.0, z_sin_cos.0),
-                Vec3A::new(x_sin_cos.1, y_sin_cos.1, z_sin_cos.1),
-            )
+            let out_of_range: Mask3A<f32> = Mask(sin_cos_out_of_range(vector.0));
+            if cfg!(feature = "deterministic") || out_of_range.any() {
+                let x_sin_cos = PrimitiveFloatUtils::sin_cos(vector.x);
+                let y_sin_cos = PrimitiveFloatUtils::sin_cos(vector.y);
+                let z_sin_cos = PrimitiveFloatUtils::sin_cos(vector.z);
+                (
+                    Vec3A::new(x_sin_cos.0, y_sin_cos.0, z_sin_cos.0),
+                    Vec3A::new(x_sin_cos.1, y_sin_cos.1, z_sin_cos.1),
+                )
+            } else {
+                let (sin, cos) = sin_cos(vector.0);
+                (Vector(sin), Vector(cos))
+            }
         }
     }
 }
@@ -806,7 +1066,12 @@ impl FloatVectorBackend<4, Aligned> for f32 {
 
         #[inline(always)]
         fn vector_powf(vector: Vec4A<f32>, n: f32) -> Vec4A<f32> {
-            Vec4A::new(vector.x.powf(n), vector.y.powf(n), vector.z.powf(n), vector.w.powf(n))
+            Vec4A::new(
+                PrimitiveFloatUtils::powf(vector.x, n),
+                PrimitiveFloatUtils::powf(vector.y, n),
+                PrimitiveFloatUtils::powf(vector.z, n),
+                PrimitiveFloatUtils::powf(vector.w, n),
+            )
         }
 
         #[inline(always)]
@@ -814,71 +1079,361 @@ impl FloatVectorBackend<4, Aligned> for f32 {
             Vector(_mm_sqrt_ps(vector.0))
         }
 
-        #[inline(always)]
+        #[inline]
+        fn vector_rsqrt(vector: Vec4A<f32>) -> Vec4A<f32> {
+            Vector(rsqrt(vector.0))
+        }
+
+        #[inline]
+        fn vector_recip_fast(vector: Vec4A<f32>) -> Vec4A<f32> {
+            Vector(recip_fast(vector.0))
+        }
+
+        #[inline]
+        fn vector_length_recip_fast(vector: Vec4A<f32>) -> f32 {
+            _mm_cvtss_f32(rsqrt(_mm_set_ss(vector.dot(vector))))
+        }
+
+        #[inline]
+        fn vector_normalize_fast(vector: Vec4A<f32>) -> Vec4A<f32> {
+            Vector(_mm_mul_ps(vector.0, rsqrt(_mm_set1_ps(vector.dot(vector)))))
+        }
+
+        #[inline]
         fn vector_exp(vector: Vec4A<f32>) -> Vec4A<f32> {
-            Vec4A::new(vector.x.exp(), vector.y.exp(), vector.z.exp(), vector.w.exp())
+            if cfg!(feature = "deterministic") {
+                Vec4A::new(
+                    PrimitiveFloatUtils::exp(vector.x),
+                    PrimitiveFloatUtils::exp(vector.y),
+                    PrimitiveFloatUtils::exp(vector.z),
+                    PrimitiveFloatUtils::exp(vector.w),
+                )
+            } else {
+                Vector(exp(vector.0))
+            }
         }
 
-        #[inline(always)]
+        #[inline]
         fn vector_exp2(vector: Vec4A<f32>) -> Vec4A<f32> {
-            Vec4A::new(vector.x.exp2(), vector.y.exp2(), vector.z.exp2(), vector.w.exp2())
+            if cfg!(feature = "deterministic") {
+                Vec4A::new(
+                    PrimitiveFloatUtils::exp2(vector.x),
+                    PrimitiveFloatUtils::exp2(vector.y),
+                    PrimitiveFloatUtils::exp2(vector.z),
+                    PrimitiveFloatUtils::exp2(vector.w),
+                )
+            } else {
+                Vector(exp2(vector.0))
+            }
         }
 
-        #[inline(always)]
+        #[inline]
         fn vector_ln(vector: Vec4A<f32>) -> Vec4A<f32> {
-            Vec4A::new(vector.x.ln(), vector.y.ln(), vector.z.ln(), vector.w.ln())
+            if cfg!(feature = "deterministic") {
+                Vec4A::new(
+                    PrimitiveFloatUtils::ln(vector.x),
+                    PrimitiveFloatUtils::ln(vector.y),
+                    PrimitiveFloatUtils::ln(vector.z),
+                    PrimitiveFloatUtils::ln(vector.w),
+                )
+            } else {
+                Vector(ln(vector.0))
+            }
         }
 
-        #[inline(always)]
+        #[inline]
         fn vector_log2(vector: Vec4A<f32>) -> Vec4A<f32> {
-            Vec4A::new(vector.x.log2(), vector.y.log2(), vector.z.log2(), vector.w.log2())
+            if cfg!(feature = "deterministic") {
+                Vec4A::new(
+                    PrimitiveFloatUtils::log2(vector.x),
+                    PrimitiveFloatUtils::log2(vector.y),
+                    PrimitiveFloatUtils::log2(vector.z),
+                    PrimitiveFloatUtils::log2(vector.w),
+                )
+            } else {
+                Vector(log2(vector.0))
+            }
         }
 
-        #[inline(always)]
+        #[inline]
         fn vector_sin(vector: Vec4A<f32>) -> Vec4A<f32> {
-            Vec4A::new(vector.x.sin(), vector.y.sin(), vector.z.sin(), vector.w.sin())
+            let out_of_range: Mask4A<f32> = Mask(sin_cos_out_of_range(vector.0));
+            if cfg!(feature = "deterministic") || out_of_range.any() {
+                Vec4A::new(
+                    PrimitiveFloatUtils::sin(vector.x),
+                    PrimitiveFloatUtils::sin(vector.y),
+                    PrimitiveFloatUtils::sin(vector.z),
+                    PrimitiveFloatUtils::sin(vector.w),
+                )
+            } else {
+                Vector(sin_cos(vector.0).0)
+            }
         }
 
-        #[inline(always)]
+        #[inline]
         fn vector_cos(vector: Vec4A<f32>) -> Vec4A<f32> {
-            Vec4A::new(vector.x.cos(), vector.y.cos(), vector.z.cos(), vector.w.cos())
+            let out_of_range: Mask4A<f32> = Mask(sin_cos_out_of_range(vector.0));
+            if cfg!(feature = "deterministic") || out_of_range.any() {
+                Vec4A::new(
+                    PrimitiveFloatUtils::cos(vector.x),
+                    PrimitiveFloatUtils::cos(vector.y),
+                    PrimitiveFloatUtils::cos(vector.z),
+                    PrimitiveFloatUtils::cos(vector.w),
+                )
+            } else {
+                Vector(sin_cos(vector.0).1)
+            }
         }
 
         #[inline(always)]
         fn vector_tan(vector: Vec4A<f32>) -> Vec4A<f32> {
-            Vec4A::new(vector.x.tan(), vector.y.tan(), vector.z.tan(), vector.w.tan())
+            Vec4A::new(
+                PrimitiveFloatUtils::tan(vector.x),
+                PrimitiveFloatUtils::tan(vector.y),
+                PrimitiveFloatUtils::tan(vector.z),
+                PrimitiveFloatUtils::tan(vector.w),
+            )
         }
 
         #[inline(always)]
         fn vector_asin(vector: Vec4A<f32>) -> Vec4A<f32> {
-            Vec4A::new(vector.x.asin(), vector.y.asin(), vector.z.asin(), vector.w.asin())
+            Vec4A::new(
+                PrimitiveFloatUtils::asin(vector.x),
+                PrimitiveFloatUtils::asin(vector.y),
+                PrimitiveFloatUtils::asin(vector.z),
+                PrimitiveFloatUtils::asin(vector.w),
+            )
         }
 
-        #[inline(always)]
+        #[inline]
         fn vector_acos(vector: Vec4A<f32>) -> Vec4A<f32> {
-            Vec4A::new(vector.x.acos(), vector.y.acos(), vector.z.acos(), vector.w.acos())
+            if cfg!(feature = "deterministic") {
+                Vec4A::new(
+                    PrimitiveFloatUtils::acos(vector.x),
+                    PrimitiveFloatUtils::acos(vector.y),
+                    PrimitiveFloatUtils::acos(vector.z),
+                    PrimitiveFloatUtils::acos(vector.w),
+                )
+            } else {
+                Vector(acos(vector.0))
+            }
         }
 
-        #[inline(always)]
+        #[inline]
         fn vector_atan(vector: Vec4A<f32>) -> Vec4A<f32> {
-            Vec4A::new(vector.x.atan(), vector.y.atan(), vector.z.atan(), vector.w.atan())
+            if cfg!(feature = "deterministic") {
+                Vec4A::new(
+                    PrimitiveFloatUtils::atan(vector.x),
+                    PrimitiveFloatUtils::atan(vector.y),
+                    PrimitiveFloatUtils::atan(vector.z),
+                    PrimitiveFloatUtils::atan(vector.w),
+                )
+            } else {
+                Vector(atan(vector.0))
+            }
         }
 
-        #[inline(always)]
+        #[inline]
         fn vector_sin_cos(vector: Vec4A<f32>) -> (Vec4A<f32>, Vec4A<f32>) {
-            let x_sin_cos = vector.x.sin_cos();
-            let y_sin_cos = vector.y.sin_cos();
-            let z_sin_cos = vector.z.sin_cos();
-            let w_sin_cos = vector.w.sin_cos();
-            (
-                Vec4A::new(x_sin_cos.0, y_sin_cos.0, z_sin_cos.0, w_sin_cos.0),
-                Vec4A::new(x_sin_cos.1, y_sin_cos.1, z_sin_cos.1, w_sin_cos.1),
-            )
+            let out_of_range: Mask4A<f32> = Mask(sin_cos_out_of_range(vector.0));
+            if cfg!(feature = "deterministic") || out_of_range.any() {
+                let x_sin_cos = PrimitiveFloatUtils::sin_cos(vector.x);
+                let y_sin_cos = PrimitiveFloatUtils::sin_cos(vector.y);
+                let z_sin_cos = PrimitiveFloatUtils::sin_cos(vector.z);
+                let w_sin_cos = PrimitiveFloatUtils::sin_cos(vector.w);
+                (
+                    Vec4A::new(x_sin_cos.0, y_sin_cos.0, z_sin_cos.0, w_sin_cos.0),
+                    Vec4A::new(x_sin_cos.1, y_sin_cos.1, z_sin_cos.1, w_sin_cos.1),
+                )
+            } else {
+                let (sin, cos) = sin_cos(vector.0);
+                (Vector(sin), Vector(cos))
+            }
+        }
+    }
+}
+
+impl FloatMatrixBackend<3, Aligned> for f32 {
+    #[inline]
+    fn matrix_inverse_and_determinant(matrix: &Mat3A<f32>) -> (Mat3A<f32>, f32) {
+        matrix.inverse_and_determinant_generic()
+    }
+}
+
+impl FloatMatrixBackend<4, Aligned> for f32 {
+    safe_target_feature! {
+        #[inline]
+        fn matrix_inverse_and_determinant(matrix: &Mat4A<f32>) -> (Mat4A<f32>, f32) {
+            // Cofactor expansion in the same order as the generic
+            // implementation, using shuffles instead of scalar elements.
+            let (x, y, z, w) = (matrix.x_axis.0, matrix.y_axis.0, matrix.z_axis.0, matrix.w_axis.0);
+
+            // `[z[i], z[i], y[i], y[i]]`
+            let zzyy0 = _mm_shuffle_ps(z, y, 0b00_00_00_00);
+            let zzyy1 = _mm_shuffle_ps(z, y, 0b01_01_01_01);
+            let zzyy2 = _mm_shuffle_ps(z, y, 0b10_10_10_10);
+            let zzyy3 = _mm_shuffle_ps(z, y, 0b11_11_11_11);
+
+            // `[w[i], w[i], w[i], z[i]]`
+            let wwwz0 = _mm_shuffle_ps(w, z, 0b00_00_00_00);
+            let wwwz0 = _mm_shuffle_ps(wwwz0, wwwz0, 0b10_00_00_00);
+            let wwwz1 = _mm_shuffle_ps(w, z, 0b01_01_01_01);
+            let wwwz1 = _mm_shuffle_ps(wwwz1, wwwz1, 0b10_00_00_00);
+            let wwwz2 = _mm_shuffle_ps(w, z, 0b10_10_10_10);
+            let wwwz2 = _mm_shuffle_ps(wwwz2, wwwz2, 0b10_00_00_00);
+            let wwwz3 = _mm_shuffle_ps(w, z, 0b11_11_11_11);
+            let wwwz3 = _mm_shuffle_ps(wwwz3, wwwz3, 0b10_00_00_00);
+
+            let fac0 = _mm_sub_ps(_mm_mul_ps(zzyy2, wwwz3), _mm_mul_ps(wwwz2, zzyy3));
+            let fac1 = _mm_sub_ps(_mm_mul_ps(zzyy1, wwwz3), _mm_mul_ps(wwwz1, zzyy3));
+            let fac2 = _mm_sub_ps(_mm_mul_ps(zzyy1, wwwz2), _mm_mul_ps(wwwz1, zzyy2));
+            let fac3 = _mm_sub_ps(_mm_mul_ps(zzyy0, wwwz3), _mm_mul_ps(wwwz0, zzyy3));
+            let fac4 = _mm_sub_ps(_mm_mul_ps(zzyy0, wwwz2), _mm_mul_ps(wwwz0, zzyy2));
+            let fac5 = _mm_sub_ps(_mm_mul_ps(zzyy0, wwwz1), _mm_mul_ps(wwwz0, zzyy1));
+
+            // `[y[i], x[i], x[i], x[i]]`
+            let vec0 = _mm_shuffle_ps(y, x, 0b00_00_00_00);
+            let vec0 = _mm_shuffle_ps(vec0, vec0, 0b10_10_10_00);
+            let vec1 = _mm_shuffle_ps(y, x, 0b01_01_01_01);
+            let vec1 = _mm_shuffle_ps(vec1, vec1, 0b10_10_10_00);
+            let vec2 = _mm_shuffle_ps(y, x, 0b10_10_10_10);
+            let vec2 = _mm_shuffle_ps(vec2, vec2, 0b10_10_10_00);
+            let vec3 = _mm_shuffle_ps(y, x, 0b11_11_11_11);
+            let vec3 = _mm_shuffle_ps(vec3, vec3, 0b10_10_10_00);
+
+            let inv0 = _mm_add_ps(
+                _mm_sub_ps(_mm_mul_ps(vec1, fac0), _mm_mul_ps(vec2, fac1)),
+                _mm_mul_ps(vec3, fac2),
+            );
+            let inv1 = _mm_add_ps(
+                _mm_sub_ps(_mm_mul_ps(vec0, fac0), _mm_mul_ps(vec2, fac3)),
+                _mm_mul_ps(vec3, fac4),
+            );
+            let inv2 = _mm_add_ps(
+                _mm_sub_ps(_mm_mul_ps(vec0, fac1), _mm_mul_ps(vec1, fac3)),
+                _mm_mul_ps(vec3, fac5),
+            );
+            let inv3 = _mm_add_ps(
+                _mm_sub_ps(_mm_mul_ps(vec0, fac2), _mm_mul_ps(vec1, fac4)),
+                _mm_mul_ps(vec2, fac5),
+            );
+
+            const SIGN_A: Vec4A<f32> = Vec4A::new(1.0, -1.0, 1.0, -1.0);
+            const SIGN_B: Vec4A<f32> = Vec4A::new(-1.0, 1.0, -1.0, 1.0);
+
+            let inv0 = _mm_mul_ps(inv0, SIGN_A.0);
+            let inv1 = _mm_mul_ps(inv1, SIGN_B.0);
+            let inv2 = _mm_mul_ps(inv2, SIGN_A.0);
+            let inv3 = _mm_mul_ps(inv3, SIGN_B.0);
+
+            let inverse_column_0 = _mm_shuffle_ps(
+                _mm_shuffle_ps(inv0, inv1, 0b00_00_00_00),
+                _mm_shuffle_ps(inv2, inv3, 0b00_00_00_00),
+                0b10_00_10_00,
+            );
+
+            let determinant = matrix.x_axis.dot(Vector(inverse_column_0));
+            let determinant_splat = _mm_set1_ps(determinant);
+
+            let inverse = Matrix::from_rows(&[
+                Vector(_mm_div_ps(inv0, determinant_splat)),
+                Vector(_mm_div_ps(inv1, determinant_splat)),
+                Vector(_mm_div_ps(inv2, determinant_splat)),
+                Vector(_mm_div_ps(inv3, determinant_splat)),
+            ]);
+
+            (inverse, determinant)
+        }
+    }
+}
+
+impl CastBackend<3, Aligned> for f32 {
+    safe_target_feature! {
+        #[inline]
+        fn vector_as_f32(vector: Vec3A<f32>) -> Vec3A<f32> {
+            vector
+        }
+
+        #[inline]
+        #[cfg(not(target_feature = "avx"))]
+        fn vector_as_f64(vector: Vec3A<f32>) -> Vec3A<f64> {
+            Vector(M128dPair(
+                _mm_cvtps_pd(vector.0),
+                _mm_cvtps_pd(_mm_movehl_ps(vector.0, vector.0)),
+            ))
+        }
+
+        #[inline]
+        #[cfg(target_feature = "avx")]
+        fn vector_as_f64(vector: Vec3A<f32>) -> Vec3A<f64> {
+            Vector(_mm256_cvtps_pd(vector.0))
+        }
+
+        #[inline]
+        fn vector_as_i32(vector: Vec3A<f32>) -> Vec3A<i32> {
+            Vector(cvt_i32(vector.0))
+        }
+
+        #[inline]
+        fn vector_as_u32(vector: Vec3A<f32>) -> Vec3A<u32> {
+            Vector(cvt_u32(vector.0))
+        }
+    }
+}
+
+impl CastBackend<4, Aligned> for f32 {
+    safe_target_feature! {
+        #[inline]
+        fn vector_as_f32(vector: Vec4A<f32>) -> Vec4A<f32> {
+            vector
+        }
+
+        #[inline]
+        #[cfg(not(target_feature = "avx"))]
+        fn vector_as_f64(vector: Vec4A<f32>) -> Vec4A<f64> {
+            Vector(M128dPair(
+                _mm_cvtps_pd(vector.0),
+                _mm_cvtps_pd(_mm_movehl_ps(vector.0, vector.0)),
+            ))
+        }
+
+        #[inline]
+        #[cfg(target_feature = "avx")]
+        fn vector_as_f64(vector: Vec4A<f32>) -> Vec4A<f64> {
+            Vector(_mm256_cvtps_pd(vector.0))
+        }
+
+        #[inline]
+        fn vector_as_i32(vector: Vec4A<f32>) -> Vec4A<i32> {
+            Vector(cvt_i32(vector.0))
+        }
+
+        #[inline]
+        fn vector_as_u32(vector: Vec4A<f32>) -> Vec4A<u32> {
+            Vector(cvt_u32(vector.0))
         }
     }
 }
 
 safe_target_feature! {
+    #[inline]
+    fn vector_mul_matrix(vector: __m128, matrix: &Mat4A<f32>) -> __m128 {
+        // Same operation order as `Vector * Matrix`.
+        let result = _mm_mul_ps(matrix.x_axis.0, _mm_shuffle_ps(vector, vector, 0b00_00_00_00));
+        let result = _mm_add_ps(
+            _mm_mul_ps(matrix.y_axis.0, _mm_shuffle_ps(vector, vector, 0b01_01_01_01)),
+            result,
+        );
+        let result = _mm_add_ps(
+            _mm_mul_ps(matrix.z_axis.0, _mm_shuffle_ps(vector, vector, 0b10_10_10_10)),
+            result,
+        );
+        _mm_add_ps(
+            _mm_mul_ps(matrix.w_axis.0, _mm_shuffle_ps(vector, vector, 0b11_11_11_11)),
+            result,
+        )
+    }
+
     #[inline]
     fn neg(vector: __m128) -> __m128 {
         _mm_xor_ps(vector, _mm_set1_ps(-0.0))
@@ -939,6 +1494,348 @@ safe_target_feature! {
         select(_mm_set1_ps(-0.0), sign, vector)
     }
 
+    #[inline]
+    fn rsqrt(vector: __m128) -> __m128 {
+        // Refine the 12-bit estimate with one Newton-Raphson step,
+        // `y * (1.5 - 0.5 * x * y * y)`. Multiplying `x * y` first keeps the
+        // intermediate values in range.
+        let estimate = _mm_rsqrt_ps(vector);
+        let half_product = _mm_mul_ps(_mm_mul_ps(_mm_set1_ps(0.5), vector), estimate);
+        let refined = _mm_mul_ps(
+            estimate,
+            _mm_sub_ps(_mm_set1_ps(1.5), _mm_mul_ps(half_product, estimate)),
+        );
+
+        select(zero_or_infinite_mask(estimate), estimate, refined)
+    }
+
+    #[inline]
+    fn recip_fast(vector: __m128) -> __m128 {
+        // Refine the 12-bit estimate with one Newton-Raphson step,
+        // `y * (2 - x * y)`.
+        let estimate = _mm_rcp_ps(vector);
+        let refined = _mm_mul_ps(
+            estimate,
+            _mm_sub_ps(_mm_set1_ps(2.0), _mm_mul_ps(vector, estimate)),
+        );
+
+        select(zero_or_infinite_mask(estimate), estimate, refined)
+    }
+
+    // Zero and infinite estimates are already exact, and a Newton-Raphson step
+    // would turn them into NaN.
+    #[inline]
+    fn zero_or_infinite_mask(vector: __m128) -> __m128 {
+        let abs = abs(vector);
+        _mm_or_ps(
+            _mm_cmpeq_ps(abs, _mm_setzero_ps()),
+            _mm_cmpeq_ps(abs, _mm_set1_ps(f32::INFINITY)),
+        )
+    }
+
+    // The transcendental functions below are vectorized versions of the
+    // Cephes single precision implementations. Their maximum errors are
+    // documented on the public functions, and are checked by the tests in
+    // `vector/float.rs`.
+
+    /// Returns `(sin(vector), cos(vector))`. Only accurate when
+    /// `|vector| <= SIN_COS_MAX`.
+    #[inline]
+    fn sin_cos(vector: __m128) -> (__m128, __m128) {
+        let x = abs(vector);
+
+        // Reduce `x` to `[-pi / 4, pi / 4]` around an even multiple `j` of
+        // `pi / 4`, with `pi / 4` split into four parts to keep the
+        // subtraction exact.
+        let j = _mm_cvttps_epi32(_mm_mul_ps(x, _mm_set1_ps(4.0 / core::f32::consts::PI)));
+        let j = _mm_and_si128(_mm_add_epi32(j, _mm_set1_epi32(1)), _mm_set1_epi32(!1));
+        let y = _mm_cvtepi32_ps(j);
+        let x = _mm_add_ps(x, _mm_mul_ps(y, _mm_set1_ps(-0.78515625)));
+        let x = _mm_add_ps(x, _mm_mul_ps(y, _mm_set1_ps(-2.4175644e-4)));
+        let x = _mm_add_ps(x, _mm_mul_ps(y, _mm_set1_ps(-1.5692785e-7)));
+        let x = _mm_add_ps(x, _mm_mul_ps(y, _mm_set1_ps(-3.0385503e-11)));
+
+        // Octants 2 and 6 swap the sine and cosine polynomials, octants 4 and
+        // 6 negate the sine, and octants 2 and 4 negate the cosine.
+        let poly_mask = _mm_castsi128_ps(_mm_cmpeq_epi32(
+            _mm_and_si128(j, _mm_set1_epi32(2)),
+            _mm_setzero_si128(),
+        ));
+        let sin_sign = _mm_xor_ps(
+            _mm_and_ps(vector, _mm_set1_ps(-0.0)),
+            _mm_castsi128_ps(_mm_slli_epi32::<29>(_mm_and_si128(j, _mm_set1_epi32(4)))),
+        );
+        let cos_sign = _mm_castsi128_ps(_mm_slli_epi32::<29>(_mm_andnot_si128(
+            _mm_sub_epi32(j, _mm_set1_epi32(2)),
+            _mm_set1_epi32(4),
+        )));
+
+        let z = _mm_mul_ps(x, x);
+
+        let cos_poly = _mm_set1_ps(2.4433157e-5);
+        let cos_poly = _mm_add_ps(_mm_mul_ps(cos_poly, z), _mm_set1_ps(-1.3887316e-3));
+        let cos_poly = _mm_add_ps(_mm_mul_ps(cos_poly, z), _mm_set1_ps(4.1666646e-2));
+        let cos_poly = _mm_mul_ps(_mm_mul_ps(cos_poly, z), z);
+        let cos_poly = _mm_sub_ps(cos_poly, _mm_mul_ps(z, _mm_set1_ps(0.5)));
+        let cos_poly = _mm_add_ps(cos_poly, _mm_set1_ps(1.0));
+
+        let sin_poly = _mm_set1_ps(-1.9515296e-4);
+        let sin_poly = _mm_add_ps(_mm_mul_ps(sin_poly, z), _mm_set1_ps(8.332161e-3));
+        let sin_poly = _mm_add_ps(_mm_mul_ps(sin_poly, z), _mm_set1_ps(-1.6666655e-1));
+        let sin_poly = _mm_add_ps(_mm_mul_ps(_mm_mul_ps(sin_poly, z), x), x);
+
+        (
+            _mm_xor_ps(select(poly_mask, sin_poly, cos_poly), sin_sign),
+            _mm_xor_ps(select(poly_mask, cos_poly, sin_poly), cos_sign),
+        )
+    }
+
+    /// Returns a mask of the elements for which [`sin_cos`] is not accurate.
+    #[inline]
+    fn sin_cos_out_of_range(vector: __m128) -> __m128 {
+        _mm_cmpgt_ps(abs(vector), _mm_set1_ps(SIN_COS_MAX))
+    }
+
+    #[inline]
+    fn exp(vector: __m128) -> __m128 {
+        // Clamping keeps `n` in the range supported by `scale_by_exp2`. The
+        // operand order propagates NaN.
+        let x = _mm_max_ps(_mm_set1_ps(-110.0), _mm_min_ps(_mm_set1_ps(110.0), vector));
+
+        // `e^x = 2^n * e^r` where `r = x - n * ln(2)`, with `ln(2)` split into
+        // two parts to keep `r` accurate.
+        let n = _mm_cvtps_epi32(_mm_mul_ps(x, _mm_set1_ps(core::f32::consts::LOG2_E)));
+        let n_float = _mm_cvtepi32_ps(n);
+        let r = _mm_sub_ps(x, _mm_mul_ps(n_float, _mm_set1_ps(0.6933594)));
+        let r = _mm_sub_ps(r, _mm_mul_ps(n_float, _mm_set1_ps(-2.1219444e-4)));
+
+        let poly = _mm_set1_ps(1.9875691e-4);
+        let poly = _mm_add_ps(_mm_mul_ps(poly, r), _mm_set1_ps(1.3981999e-3));
+        let poly = _mm_add_ps(_mm_mul_ps(poly, r), _mm_set1_ps(8.333452e-3));
+        let poly = _mm_add_ps(_mm_mul_ps(poly, r), _mm_set1_ps(4.1665796e-2));
+        let poly = _mm_add_ps(_mm_mul_ps(poly, r), _mm_set1_ps(1.6666666e-1));
+        let poly = _mm_add_ps(_mm_mul_ps(poly, r), _mm_set1_ps(0.5));
+        let poly = _mm_add_ps(_mm_mul_ps(poly, _mm_mul_ps(r, r)), r);
+        let poly = _mm_add_ps(poly, _mm_set1_ps(1.0));
+
+        scale_by_exp2(poly, n)
+    }
+
+    #[inline]
+    fn exp2(vector: __m128) -> __m128 {
+        // Clamping keeps `n` in the range supported by `scale_by_exp2`. The
+        // operand order propagates NaN.
+        let x = _mm_max_ps(_mm_set1_ps(-160.0), _mm_min_ps(_mm_set1_ps(160.0), vector));
+
+        // `2^x = 2^n * 2^r` where `r = x - n` is in `[-0.5, 0.5]`.
+        let n = _mm_cvtps_epi32(x);
+        let r = _mm_sub_ps(x, _mm_cvtepi32_ps(n));
+
+        let poly = _mm_set1_ps(1.5353362e-4);
+        let poly = _mm_add_ps(_mm_mul_ps(poly, r), _mm_set1_ps(1.3398874e-3));
+        let poly = _mm_add_ps(_mm_mul_ps(poly, r), _mm_set1_ps(9.618437e-3));
+        let poly = _mm_add_ps(_mm_mul_ps(poly, r), _mm_set1_ps(5.5503324e-2));
+        let poly = _mm_add_ps(_mm_mul_ps(poly, r), _mm_set1_ps(2.4022648e-1));
+        let poly = _mm_add_ps(_mm_mul_ps(poly, r), _mm_set1_ps(6.931472e-1));
+        let poly = _mm_add_ps(_mm_mul_ps(poly, r), _mm_set1_ps(1.0));
+
+        scale_by_exp2(poly, n)
+    }
+
+    /// Computes `vector * 2^n` for `n` in `-160..=160`.
+    #[inline]
+    fn scale_by_exp2(vector: __m128, n: __m128i) -> __m128 {
+        // Scaling in two steps keeps both factors normal. Only the second
+        // multiplication rounds, so subnormal results are rounded once.
+        let half = _mm_srai_epi32::<1>(n);
+        let first = _mm_slli_epi32::<23>(_mm_add_epi32(half, _mm_set1_epi32(127)));
+        let second = _mm_slli_epi32::<23>(_mm_add_epi32(
+            _mm_sub_epi32(n, half),
+            _mm_set1_epi32(127),
+        ));
+
+        _mm_mul_ps(
+            _mm_mul_ps(vector, _mm_castsi128_ps(first)),
+            _mm_castsi128_ps(second),
+        )
+    }
+
+    #[inline]
+    fn ln(vector: __m128) -> __m128 {
+        let (m, e, poly) = log_reduce(vector);
+
+        let z = _mm_mul_ps(m, m);
+        let result = _mm_add_ps(poly, _mm_mul_ps(e, _mm_set1_ps(-2.1219444e-4)));
+        let result = _mm_sub_ps(result, _mm_mul_ps(z, _mm_set1_ps(0.5)));
+        let result = _mm_add_ps(m, result);
+        let result = _mm_add_ps(result, _mm_mul_ps(e, _mm_set1_ps(0.6933594)));
+
+        log_special_values(vector, result)
+    }
+
+    #[inline]
+    fn log2(vector: __m128) -> __m128 {
+        let (m, e, poly) = log_reduce(vector);
+
+        // Multiplying by `log2(e) - 1` and adding the product separately is
+        // more accurate than multiplying by `log2(e)`.
+        let log2_e_minus_one = _mm_set1_ps(0.44269505);
+
+        let z = _mm_mul_ps(m, m);
+        let y = _mm_sub_ps(poly, _mm_mul_ps(z, _mm_set1_ps(0.5)));
+        let result = _mm_add_ps(
+            _mm_mul_ps(m, log2_e_minus_one),
+            _mm_mul_ps(y, log2_e_minus_one),
+        );
+        let result = _mm_add_ps(result, y);
+        let result = _mm_add_ps(result, m);
+        let result = _mm_add_ps(result, e);
+
+        log_special_values(vector, result)
+    }
+
+    /// Splits `vector` into `2^e * (1 + m)` with `1 + m` in
+    /// `[sqrt(0.5), sqrt(2))`, and returns `(m, e, m^3 * P(m))`, which is
+    /// shared by [`ln`] and [`log2`].
+    #[inline]
+    fn log_reduce(vector: __m128) -> (__m128, __m128, __m128) {
+        // Subnormal inputs are normalized first.
+        let subnormal_mask = _mm_cmplt_ps(vector, _mm_set1_ps(f32::MIN_POSITIVE));
+        let x = select(
+            subnormal_mask,
+            _mm_mul_ps(vector, _mm_set1_ps(8388608.0)),
+            vector,
+        );
+
+        let bits = _mm_castps_si128(x);
+        let e = _mm_sub_epi32(_mm_srli_epi32::<23>(bits), _mm_set1_epi32(126));
+        let e = _mm_sub_epi32(
+            e,
+            _mm_and_si128(_mm_castps_si128(subnormal_mask), _mm_set1_epi32(23)),
+        );
+        let e = _mm_cvtepi32_ps(e);
+
+        // `m` is in `[0.5, 1)`.
+        let m = _mm_or_ps(
+            _mm_and_ps(x, _mm_castsi128_ps(_mm_set1_epi32(0x007fffff))),
+            _mm_set1_ps(0.5),
+        );
+
+        let small_mask = _mm_cmplt_ps(m, _mm_set1_ps(core::f32::consts::FRAC_1_SQRT_2));
+        let e = _mm_sub_ps(e, _mm_and_ps(small_mask, _mm_set1_ps(1.0)));
+        let m = _mm_add_ps(_mm_sub_ps(m, _mm_set1_ps(1.0)), _mm_and_ps(small_mask, m));
+
+        let poly = _mm_set1_ps(7.0376836e-2);
+        let poly = _mm_add_ps(_mm_mul_ps(poly, m), _mm_set1_ps(-1.151461e-1));
+        let poly = _mm_add_ps(_mm_mul_ps(poly, m), _mm_set1_ps(1.16769984e-1));
+        let poly = _mm_add_ps(_mm_mul_ps(poly, m), _mm_set1_ps(-1.2420141e-1));
+        let poly = _mm_add_ps(_mm_mul_ps(poly, m), _mm_set1_ps(1.4249323e-1));
+        let poly = _mm_add_ps(_mm_mul_ps(poly, m), _mm_set1_ps(-1.6668057e-1));
+        let poly = _mm_add_ps(_mm_mul_ps(poly, m), _mm_set1_ps(2.0000714e-1));
+        let poly = _mm_add_ps(_mm_mul_ps(poly, m), _mm_set1_ps(-2.4999994e-1));
+        let poly = _mm_add_ps(_mm_mul_ps(poly, m), _mm_set1_ps(3.333333e-1));
+        let poly = _mm_mul_ps(_mm_mul_ps(poly, m), _mm_mul_ps(m, m));
+
+        (m, e, poly)
+    }
+
+    #[inline]
+    fn log_special_values(vector: __m128, result: __m128) -> __m128 {
+        let result = select(
+            _mm_cmpeq_ps(vector, _mm_set1_ps(f32::INFINITY)),
+            vector,
+            result,
+        );
+        let result = select(
+            _mm_cmpeq_ps(vector, _mm_setzero_ps()),
+            _mm_set1_ps(f32::NEG_INFINITY),
+            result,
+        );
+
+        // Negative values and NaN.
+        select(
+            _mm_cmpnge_ps(vector, _mm_setzero_ps()),
+            _mm_set1_ps(f32::NAN),
+            result,
+        )
+    }
+
+    #[inline]
+    fn atan(vector: __m128) -> __m128 {
+        let x = abs(vector);
+
+        // Reduce `x` to `[0, tan(pi / 8)]` with `atan(x) = pi / 2 - atan(1 / x)`
+        // and `atan(x) = pi / 4 + atan((x - 1) / (x + 1))`.
+        let large_mask = _mm_cmpgt_ps(x, _mm_set1_ps(2.4142137));
+        let medium_mask = _mm_andnot_ps(
+            large_mask,
+            _mm_cmpgt_ps(x, _mm_set1_ps(0.41421357)),
+        );
+        let offset = select(
+            large_mask,
+            _mm_set1_ps(core::f32::consts::FRAC_PI_2),
+            _mm_and_ps(medium_mask, _mm_set1_ps(core::f32::consts::FRAC_PI_4)),
+        );
+        let x = select(
+            large_mask,
+            neg(_mm_div_ps(_mm_set1_ps(1.0), x)),
+            select(
+                medium_mask,
+                _mm_div_ps(_mm_sub_ps(x, _mm_set1_ps(1.0)), _mm_add_ps(x, _mm_set1_ps(1.0))),
+                x,
+            ),
+        );
+
+        let z = _mm_mul_ps(x, x);
+        let poly = _mm_set1_ps(8.0537446e-2);
+        let poly = _mm_add_ps(_mm_mul_ps(poly, z), _mm_set1_ps(-1.3877685e-1));
+        let poly = _mm_add_ps(_mm_mul_ps(poly, z), _mm_set1_ps(1.9977711e-1));
+        let poly = _mm_add_ps(_mm_mul_ps(poly, z), _mm_set1_ps(-3.333295e-1));
+        let poly = _mm_add_ps(_mm_mul_ps(_mm_mul_ps(poly, z), x), x);
+
+        _mm_xor_ps(
+            _mm_add_ps(offset, poly),
+            _mm_and_ps(vector, _mm_set1_ps(-0.0)),
+        )
+    }
+
+    #[inline]
+    fn acos(vector: __m128) -> __m128 {
+        let x = abs(vector);
+
+        // Compute `asin(s)` where `s = sqrt((1 - |x|) / 2)` for `|x| > 0.5`,
+        // and `s = |x|` otherwise.
+        let large_mask = _mm_cmpgt_ps(x, _mm_set1_ps(0.5));
+        let z = select(
+            large_mask,
+            _mm_mul_ps(_mm_sub_ps(_mm_set1_ps(1.0), x), _mm_set1_ps(0.5)),
+            _mm_mul_ps(x, x),
+        );
+        let s = select(large_mask, _mm_sqrt_ps(z), x);
+
+        let poly = _mm_set1_ps(4.21632e-2);
+        let poly = _mm_add_ps(_mm_mul_ps(poly, z), _mm_set1_ps(2.4181312e-2));
+        let poly = _mm_add_ps(_mm_mul_ps(poly, z), _mm_set1_ps(4.5470025e-2));
+        let poly = _mm_add_ps(_mm_mul_ps(poly, z), _mm_set1_ps(7.4953005e-2));
+        let poly = _mm_add_ps(_mm_mul_ps(poly, z), _mm_set1_ps(1.6666752e-1));
+        let asin = _mm_add_ps(_mm_mul_ps(_mm_mul_ps(poly, z), s), s);
+
+        // `acos(x) = 2 * asin(s)` for `x > 0.5`, `pi - 2 * asin(s)` for
+        // `x < -0.5`, and `pi / 2 - asin(x)` otherwise.
+        let large = _mm_add_ps(asin, asin);
+        let large = select(
+            _mm_cmplt_ps(vector, _mm_setzero_ps()),
+            _mm_sub_ps(_mm_set1_ps(core::f32::consts::PI), large),
+            large,
+        );
+        let small = _mm_sub_ps(
+            _mm_set1_ps(core::f32::consts::FRAC_PI_2),
+            copysign(asin, vector),
+        );
+
+        select(large_mask, large, small)
+    }
+
     #[inline]
     fn select(mask: __m128, if_true: __m128, if_false: __m128) -> __m128 {
         _mm_or_ps(_mm_and_ps(mask, if_true), _mm_andnot_ps(mask, if_false))
@@ -1057,4 +1954,36 @@ safe_target_feature! {
     fn trunc(vector: __m128) -> __m128 {
         _mm_round_ps::<_MM_FROUND_TO_ZERO>(vector)
     }
+
+    #[inline]
+    fn cvt_i32(vector: __m128) -> __m128i {
+        // `_mm_cvttps_epi32` returns `i32::MIN` for NaN and out of range
+        // values, while `as` saturates and converts NaN to `0`.
+        let result = _mm_cvttps_epi32(vector);
+        let overflow_mask = _mm_cmpge_ps(vector, _mm_set1_ps(2147483648.0));
+        let not_nan_mask = _mm_cmpord_ps(vector, vector);
+
+        _mm_and_si128(
+            _mm_xor_si128(result, _mm_castps_si128(overflow_mask)),
+            _mm_castps_si128(not_nan_mask),
+        )
+    }
+
+    #[inline]
+    fn cvt_u32(vector: __m128) -> __m128i {
+        // `_mm_max_ps` returns the second operand for NaN.
+        let vector = _mm_max_ps(vector, _mm_setzero_ps());
+        let high_mask = _mm_cmpge_ps(vector, _mm_set1_ps(2147483648.0));
+        let overflow_mask = _mm_cmpge_ps(vector, _mm_set1_ps(4294967296.0));
+
+        // Values in `2^31..2^32` are converted with an offset of `2^31`, which
+        // is exact because they are multiples of `256`.
+        let low = _mm_sub_ps(vector, _mm_and_ps(high_mask, _mm_set1_ps(2147483648.0)));
+        let result = _mm_xor_si128(
+            _mm_cvttps_epi32(low),
+            _mm_slli_epi32::<31>(_mm_castps_si128(high_mask)),
+        );
+
+        _mm_or_si128(result, _mm_castps_si128(overflow_mask))
+    }
 }
@@ -0,0 +1,1835 @@
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+use crate::utils::PrimitiveFloatUtils;
+use crate::{
+    Aligned, Mask, Mask2A, Mat2A, Vec2A, Vector,
+    backend::{
+        CastBackend, FloatMatrixBackend, FloatVectorBackend, MaskBackend, MatrixBackend,
+        VectorBackend,
+    },
+    utils::safe_target_feature,
+};
+#[cfg(not(target_feature = "avx"))]
+use crate::{
+    Mask3A, Mask4A, Mat3A, Mat4A, QuatA, Quaternion, Vec3A, Vec4A, backend::QuaternionBackend,
+};
+
+/// Two consecutive `__m128d` registers with the alignment of `__m256d`.
+///
+/// This is the representation of [`Vec3A<f64>`] and [`Vec4A<f64>`] when AVX is
+/// not enabled, which keeps their layout identical to `__m256d`.
+#[cfg(not(target_feature = "avx"))]
+#[repr(C, align(32))]
+#[derive(Clone, Copy)]
+pub(crate) struct M128dPair(pub(crate) __m128d, pub(crate) __m128d);
+
+// `Self::Inner` follows its requirements.
+unsafe impl VectorBackend<2, Aligned> for f64 {
+    type Inner = __m128d;
+
+    safe_target_feature! {
+        #[inline]
+        fn vector_eq(vector: &Vec2A<f64>, other: &Vec2A<f64>) -> bool {
+            _mm_movemask_pd(_mm_cmpeq_pd(vector.0, other.0)) == 0b11
+        }
+
+        #[inline]
+        fn vector_ne(vector: &Vec2A<f64>, other: &Vec2A<f64>) -> bool {
+            _mm_movemask_pd(_mm_cmpneq_pd(vector.0, other.0)) != 0
+        }
+
+        #[inline]
+        fn vector_neg(vector: Vec2A<f64>) -> Vec2A<f64> {
+            Vector(neg(vector.0))
+        }
+
+        #[inline]
+        fn vector_not(vector: Vec2A<f64>) -> Vec2A<f64> {
+            Vector(not(vector.0))
+        }
+
+        #[inline]
+        fn vector_add(vector: Vec2A<f64>, rhs: Vec2A<f64>) -> Vec2A<f64> {
+            Vector(_mm_add_pd(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_sub(vector: Vec2A<f64>, rhs: Vec2A<f64>) -> Vec2A<f64> {
+            Vector(_mm_sub_pd(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_mul(vector: Vec2A<f64>, rhs: Vec2A<f64>) -> Vec2A<f64> {
+            Vector(_mm_mul_pd(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_div(vector: Vec2A<f64>, rhs: Vec2A<f64>) -> Vec2A<f64> {
+            Vector(_mm_div_pd(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_rem(vector: Vec2A<f64>, rhs: Vec2A<f64>) -> Vec2A<f64> {
+            Vec2A::new(vector.x % rhs.x, vector.y % rhs.y)
+        }
+
+        #[inline]
+        fn vector_shl(vector: Vec2A<f64>, rhs: Vec2A<f64>) -> Vec2A<f64> {
+            Vec2A::<f64>::from_bits(vector.to_bits() << rhs.to_bits())
+        }
+
+        #[inline]
+        fn vector_shr(vector: Vec2A<f64>, rhs: Vec2A<f64>) -> Vec2A<f64> {
+            Vec2A::<f64>::from_bits(vector.to_bits() >> rhs.to_bits())
+        }
+
+        #[inline]
+        fn vector_bitand(vector: Vec2A<f64>, rhs: Vec2A<f64>) -> Vec2A<f64> {
+            Vector(_mm_and_pd(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_bitor(vector: Vec2A<f64>, rhs: Vec2A<f64>) -> Vec2A<f64> {
+            Vector(_mm_or_pd(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_bitxor(vector: Vec2A<f64>, rhs: Vec2A<f64>) -> Vec2A<f64> {
+            Vector(_mm_xor_pd(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_element_sum(vector: Vec2A<f64>) -> f64 {
+            let vector = vector.0;
+            _mm_cvtsd_f64(_mm_add_sd(vector, _mm_unpackhi_pd(vector, vector)))
+        }
+
+        #[inline]
+        fn vector_element_product(vector: Vec2A<f64>) -> f64 {
+            let vector = vector.0;
+            _mm_cvtsd_f64(_mm_mul_sd(vector, _mm_unpackhi_pd(vector, vector)))
+        }
+
+        #[inline]
+        fn vector_dot(vector: Vec2A<f64>, rhs: Vec2A<f64>) -> f64 {
+            dot(vector.0, rhs.0)
+        }
+
+        #[inline]
+        fn vector_mul_add_contracted(vector: Vec2A<f64>, a: Vec2A<f64>, b: Vec2A<f64>) -> Vec2A<f64> {
+            Vector(mul_add_contracted(vector.0, a.0, b.0))
+        }
+
+        #[inline]
+        fn vector_eq_mask(vector: Vec2A<f64>, other: Vec2A<f64>) -> Mask2A<f64> {
+            Mask(_mm_cmpeq_pd(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_ne_mask(vector: Vec2A<f64>, other: Vec2A<f64>) -> Mask2A<f64> {
+            Mask(_mm_cmpneq_pd(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_lt_mask(vector: Vec2A<f64>, other: Vec2A<f64>) -> Mask2A<f64> {
+            Mask(_mm_cmplt_pd(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_gt_mask(vector: Vec2A<f64>, other: Vec2A<f64>) -> Mask2A<f64> {
+            Mask(_mm_cmpgt_pd(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_le_mask(vector: Vec2A<f64>, other: Vec2A<f64>) -> Mask2A<f64> {
+            Mask(_mm_cmple_pd(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_ge_mask(vector: Vec2A<f64>, other: Vec2A<f64>) -> Mask2A<f64> {
+            Mask(_mm_cmpge_pd(vector.0, other.0))
+        }
+    }
+}
+
+// `Self::Inner` follows its requirements. The second register contains `z`
+// followed by one padding element.
+#[cfg(not(target_feature = "avx"))]
+unsafe impl VectorBackend<3, Aligned> for f64 {
+    type Inner = M128dPair;
+
+    safe_target_feature! {
+        #[inline]
+        fn vector_eq(vector: &Vec3A<f64>, other: &Vec3A<f64>) -> bool {
+            movemask(cmpeq(vector.0, other.0)) & 0b111 == 0b111
+        }
+
+        #[inline]
+        fn vector_ne(vector: &Vec3A<f64>, other: &Vec3A<f64>) -> bool {
+            movemask(cmpneq(vector.0, other.0)) & 0b111 != 0
+        }
+
+        #[inline]
+        fn vector_neg(vector: Vec3A<f64>) -> Vec3A<f64> {
+            Vector(M128dPair(neg(vector.0.0), neg(vector.0.1)))
+        }
+
+        #[inline]
+        fn vector_not(vector: Vec3A<f64>) -> Vec3A<f64> {
+            Vector(M128dPair(not(vector.0.0), not(vector.0.1)))
+        }
+
+        #[inline]
+        fn vector_add(vector: Vec3A<f64>, rhs: Vec3A<f64>) -> Vec3A<f64> {
+            Vector(M128dPair(_mm_add_pd(vector.0.0, rhs.0.0), _mm_add_pd(vector.0.1, rhs.0.1)))
+        }
+
+        #[inline]
+        fn vector_sub(vector: Vec3A<f64>, rhs: Vec3A<f64>) -> Vec3A<f64> {
+            Vector(M128dPair(_mm_sub_pd(vector.0.0, rhs.0.0), _mm_sub_pd(vector.0.1, rhs.0.1)))
+        }
+
+        #[inline]
+        fn vector_mul(vector: Vec3A<f64>, rhs: Vec3A<f64>) -> Vec3A<f64> {
+            Vector(M128dPair(_mm_mul_pd(vector.0.0, rhs.0.0), _mm_mul_pd(vector.0.1, rhs.0.1)))
+        }
+
+        #[inline]
+        fn vector_div(vector: Vec3A<f64>, rhs: Vec3A<f64>) -> Vec3A<f64> {
+            Vector(M128dPair(_mm_div_pd(vector.0.0, rhs.0.0), _mm_div_pd(vector.0.1, rhs.0.1)))
+        }
+
+        #[inline]
+        fn vector_rem(vector: Vec3A<f64>, rhs: Vec3A<f64>) -> Vec3A<f64> {
+            Vec3A::new(vector.x % rhs.x, vector.y % rhs.y, vector.z % rhs.z)
+        }
+
+        #[inline]
+        fn vector_shl(vector: Vec3A<f64>, rhs: Vec3A<f64>) -> Vec3A<f64> {
+            Vec3A::<f64>::from_bits(vector.to_bits() << rhs.to_bits())
+        }
+
+        #[inline]
+        fn vector_shr(vector: Vec3A<f64>, rhs: Vec3A<f64>) -> Vec3A<f64> {
+            Vec3A::<f64>::from_bits(vector.to_bits() >> rhs.to_bits())
+        }
+
+        #[inline]
+        fn vector_bitand(vector: Vec3A<f64>, rhs: Vec3A<f64>) -> Vec3A<f64> {
+            Vector(M128dPair(_mm_and_pd(vector.0.0, rhs.0.0), _mm_and_pd(vector.0.1, rhs.0.1)))
+        }
+
+        #[inline]
+        fn vector_bitor(vector: Vec3A<f64>, rhs: Vec3A<f64>) -> Vec3A<f64> {
+            Vector(M128dPair(_mm_or_pd(vector.0.0, rhs.0.0), _mm_or_pd(vector.0.1, rhs.0.1)))
+        }
+
+        #[inline]
+        fn vector_bitxor(vector: Vec3A<f64>, rhs: Vec3A<f64>) -> Vec3A<f64> {
+            Vector(M128dPair(_mm_xor_pd(vector.0.0, rhs.0.0), _mm_xor_pd(vector.0.1, rhs.0.1)))
+        }
+
+        #[inline]
+        fn vector_element_sum(vector: Vec3A<f64>) -> f64 {
+            let M128dPair(xy, z) = vector.0;
+            let sum = _mm_add_sd(xy, _mm_unpackhi_pd(xy, xy));
+            _mm_cvtsd_f64(_mm_add_sd(sum, z))
+        }
+
+        #[inline]
+        fn vector_element_product(vector: Vec3A<f64>) -> f64 {
+            let M128dPair(xy, z) = vector.0;
+            let product = _mm_mul_sd(xy, _mm_unpackhi_pd(xy, xy));
+            _mm_cvtsd_f64(_mm_mul_sd(product, z))
+        }
+
+        #[inline]
+        fn vector_dot(vector: Vec3A<f64>, rhs: Vec3A<f64>) -> f64 {
+            (vector * rhs).element_sum()
+        }
+
+        #[inline]
+        fn vector_mul_add_contracted(vector: Vec3A<f64>, a: Vec3A<f64>, b: Vec3A<f64>) -> Vec3A<f64> {
+            vector * a + b
+        }
+
+        #[inline]
+        fn vector_eq_mask(vector: Vec3A<f64>, other: Vec3A<f64>) -> Mask3A<f64> {
+            Mask(cmpeq(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_ne_mask(vector: Vec3A<f64>, other: Vec3A<f64>) -> Mask3A<f64> {
+            Mask(cmpneq(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_lt_mask(vector: Vec3A<f64>, other: Vec3A<f64>) -> Mask3A<f64> {
+            Mask(M128dPair(_mm_cmplt_pd(vector.0.0, other.0.0), _mm_cmplt_pd(vector.0.1, other.0.1)))
+        }
+
+        #[inline]
+        fn vector_gt_mask(vector: Vec3A<f64>, other: Vec3A<f64>) -> Mask3A<f64> {
+            Mask(M128dPair(_mm_cmpgt_pd(vector.0.0, other.0.0), _mm_cmpgt_pd(vector.0.1, other.0.1)))
+        }
+
+        #[inline]
+        fn vector_le_mask(vector: Vec3A<f64>, other: Vec3A<f64>) -> Mask3A<f64> {
+            Mask(M128dPair(_mm_cmple_pd(vector.0.0, other.0.0), _mm_cmple_pd(vector.0.1, other.0.1)))
+        }
+
+        #[inline]
+        fn vector_ge_mask(vector: Vec3A<f64>, other: Vec3A<f64>) -> Mask3A<f64> {
+            Mask(M128dPair(_mm_cmpge_pd(vector.0.0, other.0.0), _mm_cmpge_pd(vector.0.1, other.0.1)))
+        }
+    }
+}
+
+// `Self::Inner` follows its requirements.
+#[cfg(not(target_feature = "avx"))]
+unsafe impl VectorBackend<4, Aligned> for f64 {
+    type Inner = M128dPair;
+
+    safe_target_feature! {
+        #[inline]
+        fn vector_eq(vector: &Vec4A<f64>, other: &Vec4A<f64>) -> bool {
+            movemask(cmpeq(vector.0, other.0)) == 0xf
+        }
+
+        #[inline]
+        fn vector_ne(vector: &Vec4A<f64>, other: &Vec4A<f64>) -> bool {
+            movemask(cmpneq(vector.0, other.0)) != 0
+        }
+
+        #[inline]
+        fn vector_neg(vector: Vec4A<f64>) -> Vec4A<f64> {
+            Vector(M128dPair(neg(vector.0.0), neg(vector.0.1)))
+        }
+
+        #[inline]
+        fn vector_not(vector: Vec4A<f64>) -> Vec4A<f64> {
+            Vector(M128dPair(not(vector.0.0), not(vector.0.1)))
+        }
+
+        #[inline]
+        fn vector_add(vector: Vec4A<f64>, rhs: Vec4A<f64>) -> Vec4A<f64> {
+            Vector(M128dPair(_mm_add_pd(vector.0.0, rhs.0.0), _mm_add_pd(vector.0.1, rhs.0.1)))
+        }
+
+        #[inline]
+        fn vector_sub(vector: Vec4A<f64>, rhs: Vec4A<f64>) -> Vec4A<f64> {
+            Vector(M128dPair(_mm_sub_pd(vector.0.0, rhs.0.0), _mm_sub_pd(vector.0.1, rhs.0.1)))
+        }
+
+        #[inline]
+        fn vector_mul(vector: Vec4A<f64>, rhs: Vec4A<f64>) -> Vec4A<f64> {
+            Vector(M128dPair(_mm_mul_pd(vector.0.0, rhs.0.0), _mm_mul_pd(vector.0.1, rhs.0.1)))
+        }
+
+        #[inline]
+        fn vector_div(vector: Vec4A<f64>, rhs: Vec4A<f64>) -> Vec4A<f64> {
+            Vector(M128dPair(_mm_div_pd(vector.0.0, rhs.0.0), _mm_div_pd(vector.0.1, rhs.0.1)))
+        }
+
+        #[inline]
+        fn vector_rem(vector: Vec4A<f64>, rhs: Vec4A<f64>) -> Vec4A<f64> {
+            Vec4A::new(
+                vector.x % rhs.x,
+                vector.y % rhs.y,
+                vector.z % rhs.z,
+                vector.w % rhs.w,
+            )
+        }
+
+        #[inline]
+        fn vector_shl(vector: Vec4A<f64>, rhs: Vec4A<f64>) -> Vec4A<f64> {
+            Vec4A::<f64>::from_bits(vector.to_bits() << rhs.to_bits())
+        }
+
+        #[inline]
+        fn vector_shr(vector: Vec4A<f64>, rhs: Vec4A<f64>) -> Vec4A<f64> {
+            Vec4A::<f64>::from_bits(vector.to_bits() >> rhs.to_bits())
+        }
+
+        #[inline]
+        fn vector_bitand(vector: Vec4A<f64>, rhs: Vec4A<f64>) -> Vec4A<f64> {
+            Vector(M128dPair(_mm_and_pd(vector.0.0, rhs.0.0), _mm_and_pd(vector.0.1, rhs.0.1)))
+        }
+
+        #[inline]
+        fn vector_bitor(vector: Vec4A<f64>, rhs: Vec4A<f64>) -> Vec4A<f64> {
+            Vector(M128dPair(_mm_or_pd(vector.0.0, rhs.0.0), _mm_or_pd(vector.0.1, rhs.0.1)))
+        }
+
+        #[inline]
+        fn vector_bitxor(vector: Vec4A<f64>, rhs: Vec4A<f64>) -> Vec4A<f64> {
+            Vector(M128dPair(_mm_xor_pd(vector.0.0, rhs.0.0), _mm_xor_pd(vector.0.1, rhs.0.1)))
+        }
+
+        #[inline]
+        fn vector_element_sum(vector: Vec4A<f64>) -> f64 {
+            let M128dPair(xy, zw) = vector.0;
+            // `[x + y, z + w]`
+            let sums = _mm_add_pd(_mm_unpacklo_pd(xy, zw), _mm_unpackhi_pd(xy, zw));
+            _mm_cvtsd_f64(_mm_add_sd(sums, _mm_unpackhi_pd(sums, sums)))
+        }
+
+        #[inline]
+        fn vector_element_product(vector: Vec4A<f64>) -> f64 {
+            let M128dPair(xy, zw) = vector.0;
+            // `[x * y, z * w]`
+            let products = _mm_mul_pd(_mm_unpacklo_pd(xy, zw), _mm_unpackhi_pd(xy, zw));
+            _mm_cvtsd_f64(_mm_mul_sd(products, _mm_unpackhi_pd(products, products)))
+        }
+
+        #[inline]
+        fn vector_dot(vector: Vec4A<f64>, rhs: Vec4A<f64>) -> f64 {
+            (vector * rhs).element_sum()
+        }
+
+        #[inline]
+        fn vector_mul_add_contracted(vector: Vec4A<f64>, a: Vec4A<f64>, b: Vec4A<f64>) -> Vec4A<f64> {
+            vector * a + b
+        }
+
+        #[inline]
+        fn vector_eq_mask(vector: Vec4A<f64>, other: Vec4A<f64>) -> Mask4A<f64> {
+            Mask(cmpeq(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_ne_mask(vector: Vec4A<f64>, other: Vec4A<f64>) -> Mask4A<f64> {
+            Mask(cmpneq(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_lt_mask(vector: Vec4A<f64>, other: Vec4A<f64>) -> Mask4A<f64> {
+            Mask(M128dPair(_mm_cmplt_pd(vector.0.0, other.0.0), _mm_cmplt_pd(vector.0.1, other.0.1)))
+        }
+
+        #[inline]
+        fn vector_gt_mask(vector: Vec4A<f64>, other: Vec4A<f64>) -> Mask4A<f64> {
+            Mask(M128dPair(_mm_cmpgt_pd(vector.0.0, other.0.0), _mm_cmpgt_pd(vector.0.1, other.0.1)))
+        }
+
+        #[inline]
+        fn vector_le_mask(vector: Vec4A<f64>, other: Vec4A<f64>) -> Mask4A<f64> {
+            Mask(M128dPair(_mm_cmple_pd(vector.0.0, other.0.0), _mm_cmple_pd(vector.0.1, other.0.1)))
+        }
+
+        #[inline]
+        fn vector_ge_mask(vector: Vec4A<f64>, other: Vec4A<f64>) -> Mask4A<f64> {
+            Mask(M128dPair(_mm_cmpge_pd(vector.0.0, other.0.0), _mm_cmpge_pd(vector.0.1, other.0.1)))
+        }
+    }
+}
+
+#[cfg(not(target_feature = "avx"))]
+impl QuaternionBackend<Aligned> for f64 {
+    #[inline]
+    fn quat_mul(quat: QuatA<f64>, rhs: QuatA<f64>) -> QuatA<f64> {
+        const PNPN: Vec4A<f64> = Vec4A::new(0.0, -0.0, 0.0, -0.0);
+        const PPNN: Vec4A<f64> = Vec4A::new(0.0, 0.0, -0.0, -0.0);
+        const NPPN: Vec4A<f64> = Vec4A::new(-0.0, 0.0, 0.0, -0.0);
+
+        Quaternion(
+            quat.0 * rhs.0.wwww()
+                + Vec4A::<f64>::from_bits(
+                    PNPN.to_bits() ^ (quat.0.wzyx() * rhs.0.xxxx()).to_bits(),
+                )
+                + Vec4A::<f64>::from_bits(
+                    PPNN.to_bits() ^ (quat.0.zwxy() * rhs.0.yyyy()).to_bits(),
+                )
+                + Vec4A::<f64>::from_bits(
+                    NPPN.to_bits() ^ (quat.0.yxwz() * rhs.0.zzzz()).to_bits(),
+                ),
+        )
+    }
+}
+
+impl MatrixBackend<2, Aligned> for f64 {
+    #[inline]
+    fn matrix_transpose(matrix: &Mat2A<f64>) -> Mat2A<f64> {
+        matrix.transpose_generic()
+    }
+
+    #[inline]
+    fn matrix_mul(matrix: &Mat2A<f64>, rhs: &Mat2A<f64>) -> Mat2A<f64> {
+        matrix.mul_generic(rhs)
+    }
+
+    #[inline]
+    fn matrix_determinant(matrix: &Mat2A<f64>) -> f64 {
+        matrix.determinant_generic()
+    }
+}
+
+#[cfg(not(target_feature = "avx"))]
+impl MatrixBackend<3, Aligned> for f64 {
+    #[inline]
+    fn matrix_transpose(matrix: &Mat3A<f64>) -> Mat3A<f64> {
+        matrix.transpose_generic()
+    }
+
+    #[inline]
+    fn matrix_mul(matrix: &Mat3A<f64>, rhs: &Mat3A<f64>) -> Mat3A<f64> {
+        matrix.mul_generic(rhs)
+    }
+
+    #[inline]
+    fn matrix_determinant(matrix: &Mat3A<f64>) -> f64 {
+        matrix.determinant_generic()
+    }
+}
+
+#[cfg(not(target_feature = "avx"))]
+impl MatrixBackend<4, Aligned> for f64 {
+    #[inline]
+    fn matrix_transpose(matrix: &Mat4A<f64>) -> Mat4A<f64> {
+        matrix.transpose_generic()
+    }
+
+    #[inline]
+    fn matrix_mul(matrix: &Mat4A<f64>, rhs: &Mat4A<f64>) -> Mat4A<f64> {
+        matrix.mul_generic(rhs)
+    }
+
+    #[inline]
+    fn matrix_determinant(matrix: &Mat4A<f64>) -> f64 {
+        matrix.determinant_generic()
+    }
+}
+
+// `Self::Inner` follows its requirements.
+unsafe impl MaskBackend<2, Aligned> for f64 {
+    type Inner = __m128d;
+
+    safe_target_feature! {
+        #[inline]
+        fn mask_from_array(array: [bool; 2]) -> Mask2A<f64> {
+            Mask(_mm_castsi128_pd(_mm_set_epi64x(-(array[1] as i64), -(array[0] as i64))))
+        }
+
+        #[inline]
+        fn mask_splat(value: bool) -> Mask2A<f64> {
+            Mask(_mm_castsi128_pd(_mm_set1_epi64x(-(value as i64))))
+        }
+
+        #[inline]
+        fn mask_to_array(mask: Mask2A<f64>) -> [bool; 2] {
+            let bits = _mm_movemask_pd(mask.0);
+            [bits & 0x1 != 0, bits & 0x2 != 0]
+        }
+
+        #[inline]
+        fn mask_all(mask: Mask2A<f64>) -> bool {
+            _mm_movemask_pd(mask.0) == 0x3
+        }
+
+        #[inline]
+        fn mask_any(mask: Mask2A<f64>) -> bool {
+            _mm_movemask_pd(mask.0) != 0
+        }
+
+        #[inline]
+        fn mask_select(mask: Mask2A<f64>, if_true: Vec2A<f64>, if_false: Vec2A<f64>) -> Vec2A<f64> {
+            Vector(select(mask.0, if_true.0, if_false.0))
+        }
+
+        #[inline]
+        fn mask_get(mask: Mask2A<f64>, index: usize) -> bool {
+            match index {
+                0 => _mm_movemask_pd(mask.0) & 0x1 != 0,
+                1 => _mm_movemask_pd(mask.0) & 0x2 != 0,
+                _ => panic!("index out of bounds"),
+            }
+        }
+
+        #[inline]
+        fn mask_set(mask: &mut Mask2A<f64>, index: usize, value: bool) {
+            if index < 2 {
+                // SAFETY: `*mut __m128d` is valid as `*mut i64` for 2 values.
+                // Adding `index` is valid because it was just checked to be
+                // less then 2, and the result is a pointer to a valid `i64`.
+                let slot = unsafe {
+                    core::ptr::from_mut::<__m128d>(&mut mask.0)
+                        .cast::<i64>()
+                        .add(index)
+                        .as_mut()
+                        .unwrap_unchecked()
+                };
+
+                *slot = -(value as i64);
+            } else {
+                panic!("index out of bounds")
+            }
+        }
+
+        #[inline]
+        fn mask_eq(mask: &Mask2A<f64>, other: &Mask2A<f64>) -> bool {
+            _mm_movemask_pd(mask.0) == _mm_movemask_pd(other.0)
+        }
+
+        #[inline]
+        fn mask_ne(mask: &Mask2A<f64>, other: &Mask2A<f64>) -> bool {
+            !(mask == other)
+        }
+
+        #[inline]
+        fn mask_not(mask: Mask2A<f64>) -> Mask2A<f64> {
+            Mask(not(mask.0))
+        }
+
+        #[inline]
+        fn mask_bitand(mask: Mask2A<f64>, rhs: Mask2A<f64>) -> Mask2A<f64> {
+            Mask(_mm_and_pd(mask.0, rhs.0))
+        }
+
+        #[inline]
+        fn mask_bitor(mask: Mask2A<f64>, rhs: Mask2A<f64>) -> Mask2A<f64> {
+            Mask(_mm_or_pd(mask.0, rhs.0))
+        }
+
+        #[inline]
+        fn mask_bitxor(mask: Mask2A<f64>, rhs: Mask2A<f64>) -> Mask2A<f64> {
+            Mask(_mm_xor_pd(mask.0, rhs.0))
+        }
+    }
+}
+
+// `Self::Inner` follows its requirements.
+#[cfg(not(target_feature = "avx"))]
+unsafe impl MaskBackend<3, Aligned> for f64 {
+    type Inner = M128dPair;
+
+    safe_target_feature! {
+        #[inline]
+        fn mask_from_array(array: [bool; 3]) -> Mask3A<f64> {
+            Mask(M128dPair(
+                _mm_castsi128_pd(_mm_set_epi64x(-(array[1] as i64), -(array[0] as i64))),
+                _mm_castsi128_pd(_mm_set1_epi64x(-(array[2] as i64))),
+            ))
+        }
+
+        #[inline]
+        fn mask_splat(value: bool) -> Mask3A<f64> {
+            let value = _mm_castsi128_pd(_mm_set1_epi64x(-(value as i64)));
+            Mask(M128dPair(value, value))
+        }
+
+        #[inline]
+        fn mask_to_array(mask: Mask3A<f64>) -> [bool; 3] {
+            let bits = movemask(mask.0);
+            [bits & 0x1 != 0, bits & 0x2 != 0, bits & 0x4 != 0]
+        }
+
+        #[inline]
+        fn mask_all(mask: Mask3A<f64>) -> bool {
+            movemask(mask.0) & 0x7 == 0x7
+        }
+
+        #[inline]
+        fn mask_any(mask: Mask3A<f64>) -> bool {
+            movemask(mask.0) & 0x7 != 0
+        }
+
+        #[inline]
+        fn mask_select(mask: Mask3A<f64>, if_true: Vec3A<f64>, if_false: Vec3A<f64>) -> Vec3A<f64> {
+            Vector(M128dPair(
+                select(mask.0.0, if_true.0.0, if_false.0.0),
+                select(mask.0.1, if_true.0.1, if_false.0.1),
+            ))
+        }
+
+        #[inline]
+        fn mask_get(mask: Mask3A<f64>, index: usize) -> bool {
+            match index {
+                0 => movemask(mask.0) & 0x1 != 0,
+                1 => movemask(mask.0) & 0x2 != 0,
+                2 => movemask(mask.0) & 0x4 != 0,
+                _ => panic!("index out of bounds"),
+            }
+        }
+
+        #[inline]
+        fn mask_set(mask: &mut Mask3A<f64>, index: usize, value: bool) {
+            if index < 3 {
+                // SAFETY: `*mut M128dPair` is valid as `*mut i64` for 4
+                // values. Adding `index` is valid because it was just checked
+                // to be less then 3, and the result is a pointer to a valid
+                // `i64`.
+                let slot = unsafe {
+                    core::ptr::from_mut::<M128dPair>(&mut mask.0)
+                        .cast::<i64>()
+                        .add(index)
+                        .as_mut()
+                        .unwrap_unchecked()
+                };
+
+                *slot = -(value as i64);
+            } else {
+                panic!("index out of bounds")
+            }
+        }
+
+        #[inline]
+        fn mask_eq(mask: &Mask3A<f64>, other: &Mask3A<f64>) -> bool {
+            movemask(mask.0) & 0x7 == movemask(other.0) & 0x7
+        }
+
+        #[inline]
+        fn mask_ne(mask: &Mask3A<f64>, other: &Mask3A<f64>) -> bool {
+            !(mask == other)
+        }
+
+        #[inline]
+        fn mask_not(mask: Mask3A<f64>) -> Mask3A<f64> {
+            Mask(M128dPair(not(mask.0.0), not(mask.0.1)))
+        }
+
+        #[inline]
+        fn mask_bitand(mask: Mask3A<f64>, rhs: Mask3A<f64>) -> Mask3A<f64> {
+            Mask(M128dPair(_mm_and_pd(mask.0.0, rhs.0.0), _mm_and_pd(mask.0.1, rhs.0.1)))
+        }
+
+        #[inline]
+        fn mask_bitor(mask: Mask3A<f64>, rhs: Mask3A<f64>) -> Mask3A<f64> {
+            Mask(M128dPair(_mm_or_pd(mask.0.0, rhs.0.0), _mm_or_pd(mask.0.1, rhs.0.1)))
+        }
+
+        #[inline]
+        fn mask_bitxor(mask: Mask3A<f64>, rhs: Mask3A<f64>) -> Mask3A<f64> {
+            Mask(M128dPair(_mm_xor_pd(mask.0.0, rhs.0.0), _mm_xor_pd(mask.0.1, rhs.0.1)))
+        }
+    }
+}
+
+// `Self::Inner` follows its requirements.
+#[cfg(not(target_feature = "avx"))]
+unsafe impl MaskBackend<4, Aligned> for f64 {
+    type Inner = M128dPair;
+
+    safe_target_feature! {
+        #[inline]
+        fn mask_from_array(array: [bool; 4]) -> Mask4A<f64> {
+            Mask(M128dPair(
+                _mm_castsi128_pd(_mm_set_epi64x(-(array[1] as i64), -(array[0] as i64))),
+                _mm_castsi128_pd(_mm_set_epi64x(-(array[3] as i64), -(array[2] as i64))),
+            ))
+        }
+
+        #[inline]
+        fn mask_splat(value: bool) -> Mask4A<f64> {
+            let value = _mm_castsi128_pd(_mm_set1_epi64x(-(value as i64)));
+            Mask(M128dPair(value, value))
+        }
+
+        #[inline]
+        fn mask_to_array(mask: Mask4A<f64>) -> [bool; 4] {
+            let bits = movemask(mask.0);
+            [
+                bits & 0x1 != 0,
+                bits & 0x2 != 0,
+                bits & 0x4 != 0,
+                bits & 0x8 != 0,
+            ]
+        }
+
+        #[inline]
+        fn mask_all(mask: Mask4A<f64>) -> bool {
+            movemask(mask.0) == 0xf
+        }
+
+        #[inline]
+        fn mask_any(mask: Mask4A<f64>) -> bool {
+            movemask(mask.0) != 0
+        }
+
+        #[inline]
+        fn mask_select(mask: Mask4A<f64>, if_true: Vec4A<f64>, if_false: Vec4A<f64>) -> Vec4A<f64> {
+            Vector(M128dPair(
+                select(mask.0.0, if_true.0.0, if_false.0.0),
+                select(mask.0.1, if_true.0.1, if_false.0.1),
+            ))
+        }
+
+        #[inline]
+        fn mask_get(mask: Mask4A<f64>, index: usize) -> bool {
+            match index {
+                0 => movemask(mask.0) & 0x1 != 0,
+                1 => movemask(mask.0) & 0x2 != 0,
+                2 => movemask(mask.0) & 0x4 != 0,
+                3 => movemask(mask.0) & 0x8 != 0,
+                _ => panic!("index out of bounds"),
+            }
+        }
+
+        #[inline]
+        fn mask_set(mask: &mut Mask4A<f64>, index: usize, value: bool) {
+            if index < 4 {
+                // SAFETY: `*mut M128dPair` is valid as `*mut i64` for 4
+                // values. Adding `index` is valid because it was just checked
+                // to be less then 4, and the result is a pointer to a valid
+                // `i64`.
+                let slot = unsafe {
+                    core::ptr::from_mut::<M128dPair>(&mut mask.0)
+                        .cast::<i64>()
+                        .add(index)
+                        .as_mut()
+                        .unwrap_unchecked()
+                };
+
+                *slot = -(value as i64);
+            } else {
+                panic!("index out of bounds")
+            }
+        }
+
+        #[inline]
+        fn mask_eq(mask: &Mask4A<f64>, other: &Mask4A<f64>) -> bool {
+            movemask(mask.0) == movemask(other.0)
+        }
+
+        #[inline]
+        fn mask_ne(mask: &Mask4A<f64>, other: &Mask4A<f64>) -> bool {
+            !(mask == other)
+        }
+
+        #[inline]
+        fn mask_not(mask: Mask4A<f64>) -> Mask4A<f64> {
+            Mask(M128dPair(not(mask.0.0), not(mask.0.1)))
+        }
+
+        #[inline]
+        fn mask_bitand(mask: Mask4A<f64>, rhs: Mask4A<f64>) -> Mask4A<f64> {
+            Mask(M128dPair(_mm_and_pd(mask.0.0, rhs.0.0), _mm_and_pd(mask.0.1, rhs.0.1)))
+        }
+
+        #[inline]
+        fn mask_bitor(mask: Mask4A<f64>, rhs: Mask4A<f64>) -> Mask4A<f64> {
+            Mask(M128dPair(_mm_or_pd(mask.0.0, rhs.0.0), _mm_or_pd(mask.0.1, rhs.0.1)))
+        }
+
+        #[inline]
+        fn mask_bitxor(mask: Mask4A<f64>, rhs: Mask4A<f64>) -> Mask4A<f64> {
+            Mask(M128dPair(_mm_xor_pd(mask.0.0, rhs.0.0), _mm_xor_pd(mask.0.1, rhs.0.1)))
+        }
+    }
+}
+
+impl FloatVectorBackend<2, Aligned> for f64 {
+    safe_target_feature! {
+        #[inline]
+        fn vector_nan_mask(vector: Vec2A<f64>) -> Mask2A<f64> {
+            Mask(nan_mask(vector.0))
+        }
+
+        #[inline]
+        fn vector_finite_mask(vector: Vec2A<f64>) -> Mask2A<f64> {
+            Mask(finite_mask(vector.0))
+        }
+
+        #[inline]
+        fn vector_sign_positive_mask(vector: Vec2A<f64>) -> Mask2A<f64> {
+            Mask(not(sign_negative_mask(vector.0)))
+        }
+
+        #[inline]
+        fn vector_sign_negative_mask(vector: Vec2A<f64>) -> Mask2A<f64> {
+            Mask(sign_negative_mask(vector.0))
+        }
+
+        #[inline]
+        fn vector_max(vector: Vec2A<f64>, other: Vec2A<f64>) -> Vec2A<f64> {
+            Vector(_mm_max_pd(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_min(vector: Vec2A<f64>, other: Vec2A<f64>) -> Vec2A<f64> {
+            Vector(_mm_min_pd(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_abs(vector: Vec2A<f64>) -> Vec2A<f64> {
+            Vector(abs(vector.0))
+        }
+
+        #[inline]
+        fn vector_signum(vector: Vec2A<f64>) -> Vec2A<f64> {
+            Vector(signum(vector.0))
+        }
+
+        #[inline]
+        fn vector_copysign(vector: Vec2A<f64>, sign: Vec2A<f64>) -> Vec2A<f64> {
+            Vector(copysign(vector.0, sign.0))
+        }
+
+        #[inline]
+        fn vector_max_element(vector: Vec2A<f64>) -> f64 {
+            let vector = vector.0;
+            _mm_cvtsd_f64(_mm_max_sd(vector, _mm_unpackhi_pd(vector, vector)))
+        }
+
+        #[inline]
+        fn vector_min_element(vector: Vec2A<f64>) -> f64 {
+            let vector = vector.0;
+            _mm_cvtsd_f64(_mm_min_sd(vector, _mm_unpackhi_pd(vector, vector)))
+        }
+
+        #[inline(always)]
+        fn vector_floor(vector: Vec2A<f64>) -> Vec2A<f64> {
+            Vector(floor(vector.0))
+        }
+
+        #[inline(always)]
+        fn vector_ceil(vector: Vec2A<f64>) -> Vec2A<f64> {
+            Vector(ceil(vector.0))
+        }
+
+        #[inline(always)]
+        fn vector_round(vector: Vec2A<f64>) -> Vec2A<f64> {
+            Vector(round(vector.0))
+        }
+
+        #[inline(always)]
+        fn vector_trunc(vector: Vec2A<f64>) -> Vec2A<f64> {
+            Vector(trunc(vector.0))
+        }
+
+        #[cfg(target_feature = "fma")]
+        #[inline(always)]
+        fn vector_mul_add(vector: Vec2A<f64>, a: Vec2A<f64>, b: Vec2A<f64>) -> Vec2A<f64> {
+            Vector(_mm_fmadd_pd(vector.0, a.0, b.0))
+        }
+
+        #[cfg(not(target_feature = "fma"))]
+        #[inline(always)]
+        fn vector_mul_add(vector: Vec2A<f64>, a: Vec2A<f64>, b: Vec2A<f64>) -> Vec2A<f64> {
+            Vec2A::new(vector.x.mul_add(a.x, b.x), vector.y.mul_add(a.y, b.y))
+        }
+
+        #[inline(always)]
+        fn vector_div_euclid(vector: Vec2A<f64>, rhs: Vec2A<f64>) -> Vec2A<f64> {
+            Vec2A::new(vector.x.div_euclid(rhs.x), vector.y.div_euclid(rhs.y))
+        }
+
+        #[inline(always)]
+        fn vector_rem_euclid(vector: Vec2A<f64>, rhs: Vec2A<f64>) -> Vec2A<f64> {
+            Vec2A::new(vector.x.rem_euclid(rhs.x), vector.y.rem_euclid(rhs.y))
+        }
+
+        #[inline(always)]
+        fn vector_powf(vector: Vec2A<f64>, n: f64) -> Vec2A<f64> {
+            Vec2A::new(
+                PrimitiveFloatUtils::powf(vector.x, n),
+                PrimitiveFloatUtils::powf(vector.y, n),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_sqrt(vector: Vec2A<f64>) -> Vec2A<f64> {
+            Vector(_mm_sqrt_pd(vector.0))
+        }
+
+        #[inline]
+        fn vector_rsqrt(vector: Vec2A<f64>) -> Vec2A<f64> {
+            Vec2A::ONE / vector.sqrt()
+        }
+
+        #[inline]
+        fn vector_recip_fast(vector: Vec2A<f64>) -> Vec2A<f64> {
+            vector.recip()
+        }
+
+        #[inline]
+        fn vector_length_recip_fast(vector: Vec2A<f64>) -> f64 {
+            vector.length().recip()
+        }
+
+        #[inline]
+        fn vector_normalize_fast(vector: Vec2A<f64>) -> Vec2A<f64> {
+            vector / vector.length()
+        }
+
+        #[inline(always)]
+        fn vector_exp(vector: Vec2A<f64>) -> Vec2A<f64> {
+            Vec2A::new(PrimitiveFloatUtils::exp(vector.x), PrimitiveFloatUtils::exp(vector.y))
+        }
+
+        #[inline(always)]
+        fn vector_exp2(vector: Vec2A<f64>) -> Vec2A<f64> {
+            Vec2A::new(PrimitiveFloatUtils::exp2(vector.x), PrimitiveFloatUtils::exp2(vector.y))
+        }
+
+        #[inline(always)]
+        fn vector_ln(vector: Vec2A<f64>) -> Vec2A<f64> {
+            Vec2A::new(PrimitiveFloatUtils::ln(vector.x), PrimitiveFloatUtils::ln(vector.y))
+        }
+
+        #[inline(always)]
+        fn vector_log2(vector: Vec2A<f64>) -> Vec2A<f64> {
+            Vec2A::new(PrimitiveFloatUtils::log2(vector.x), PrimitiveFloatUtils::log2(vector.y))
+        }
+
+        #[inline(always)]
+        fn vector_sin(vector: Vec2A<f64>) -> Vec2A<f64> {
+            Vec2A::new(PrimitiveFloatUtils::sin(vector.x), PrimitiveFloatUtils::sin(vector.y))
+        }
+
+        #[inline(always)]
+        fn vector_cos(vector: Vec2A<f64>) -> Vec2A<f64> {
+            Vec2A::new(PrimitiveFloatUtils::cos(vector.x), PrimitiveFloatUtils::cos(vector.y))
+        }
+
+        #[inline(always)]
+        fn vector_tan(vector: Vec2A<f64>) -> Vec2A<f64> {
+            Vec2A::new(PrimitiveFloatUtils::tan(vector.x), PrimitiveFloatUtils::tan(vector.y))
+        }
+
+        #[inline(always)]
+        fn vector_asin(vector: Vec2A<f64>) -> Vec2A<f64> {
+            Vec2A::new(PrimitiveFloatUtils::asin(vector.x), PrimitiveFloatUtils::asin(vector.y))
+        }
+
+        #[inline(always)]
+        fn vector_acos(vector: Vec2A<f64>) -> Vec2A<f64> {
+            Vec2A::new(PrimitiveFloatUtils::acos(vector.x), PrimitiveFloatUtils::acos(vector.y))
+        }
+
+        #[inline(always)]
+        fn vector_atan(vector: Vec2A<f64>) -> Vec2A<f64> {
+            Vec2A::new(PrimitiveFloatUtils::atan(vector.x), PrimitiveFloatUtils::atan(vector.y))
+        }
+
+        #[inline(always)]
+        fn vector_sin_cos(vector: Vec2A<f64>) -> (Vec2A<f64>, Vec2A<f64>) {
+            let x_sin_cos = PrimitiveFloatUtils::sin_cos(vector.x);
+            let y_sin_cos = PrimitiveFloatUtils::sin_cos(vector.y);
+            (
+                Vec2A::new(x_sin_cos.0, y_sin_cos.0),
+                Vec2A::new(x_sin_cos.1, y_sin_cos.1),
+            )
+        }
+    }
+}
+
+#[cfg(not(target_feature = "avx"))]
+impl FloatVectorBackend<3, Aligned> for f64 {
+    safe_target_feature! {
+        #[inline]
+        fn vector_nan_mask(vector: Vec3A<f64>) -> Mask3A<f64> {
+            Mask(M128dPair(nan_mask(vector.0.0), nan_mask(vector.0.1)))
+        }
+
+        #[inline]
+        fn vector_finite_mask(vector: Vec3A<f64>) -> Mask3A<f64> {
+            Mask(M128dPair(finite_mask(vector.0.0), finite_mask(vector.0.1)))
+        }
+
+        #[inline]
+        fn vector_sign_positive_mask(vector: Vec3A<f64>) -> Mask3A<f64> {
+            Mask(M128dPair(
+                not(sign_negative_mask(vector.0.0)),
+                not(sign_negative_mask(vector.0.1)),
+            ))
+        }
+
+        #[inline]
+        fn vector_sign_negative_mask(vector: Vec3A<f64>) -> Mask3A<f64> {
+            Mask(M128dPair(sign_negative_mask(vector.0.0), sign_negative_mask(vector.0.1)))
+        }
+
+        #[inline]
+        fn vector_max(vector: Vec3A<f64>, other: Vec3A<f64>) -> Vec3A<f64> {
+            Vector(M128dPair(_mm_max_pd(vector.0.0, other.0.0), _mm_max_pd(vector.0.1, other.0.1)))
+        }
+
+        #[inline]
+        fn vector_min(vector: Vec3A<f64>, other: Vec3A<f64>) -> Vec3A<f64> {
+            Vector(M128dPair(_mm_min_pd(vector.0.0, other.0.0), _mm_min_pd(vector.0.1, other.0.1)))
+        }
+
+        #[inline]
+        fn vector_abs(vector: Vec3A<f64>) -> Vec3A<f64> {
+            Vector(M128dPair(abs(vector.0.0), abs(vector.0.1)))
+        }
+
+        #[inline]
+        fn vector_signum(vector: Vec3A<f64>) -> Vec3A<f64> {
+            Vector(M128dPair(signum(vector.0.0), signum(vector.0.1)))
+        }
+
+        #[inline]
+        fn vector_copysign(vector: Vec3A<f64>, sign: Vec3A<f64>) -> Vec3A<f64> {
+            Vector(M128dPair(copysign(vector.0.0, sign.0.0), copysign(vector.0.1, sign.0.1)))
+        }
+
+        #[inline]
+        fn vector_max_element(vector: Vec3A<f64>) -> f64 {
+            let M128dPair(xy, z) = vector.0;
+            let max = _mm_max_sd(xy, _mm_unpackhi_pd(xy, xy));
+            _mm_cvtsd_f64(_mm_max_sd(max, z))
+        }
+
+        #[inline]
+        fn vector_min_element(vector: Vec3A<f64>) -> f64 {
+            let M128dPair(xy, z) = vector.0;
+            let min = _mm_min_sd(xy, _mm_unpackhi_pd(xy, xy));
+            _mm_cvtsd_f64(_mm_min_sd(min, z))
+        }
+
+        #[inline(always)]
+        fn vector_floor(vector: Vec3A<f64>) -> Vec3A<f64> {
+            Vector(M128dPair(floor(vector.0.0), floor(vector.0.1)))
+        }
+
+        #[inline(always)]
+        fn vector_ceil(vector: Vec3A<f64>) -> Vec3A<f64> {
+            Vector(M128dPair(ceil(vector.0.0), ceil(vector.0.1)))
+        }
+
+        #[inline(always)]
+        fn vector_round(vector: Vec3A<f64>) -> Vec3A<f64> {
+            Vector(M128dPair(round(vector.0.0), round(vector.0.1)))
+        }
+
+        #[inline(always)]
+        fn vector_trunc(vector: Vec3A<f64>) -> Vec3A<f64> {
+            Vector(M128dPair(trunc(vector.0.0), trunc(vector.0.1)))
+        }
+
+        #[inline(always)]
+        fn vector_mul_add(vector: Vec3A<f64>, a: Vec3A<f64>, b: Vec3A<f64>) -> Vec3A<f64> {
+            Vec3A::new(
+                vector.x.mul_add(a.x, b.x),
+                vector.y.mul_add(a.y, b.y),
+                vector.z.mul_add(a.z, b.z),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_div_euclid(vector: Vec3A<f64>, rhs: Vec3A<f64>) -> Vec3A<f64> {
+            Vec3A::new(
+                vector.x.div_euclid(rhs.x),
+                vector.y.div_euclid(rhs.y),
+                vector.z.div_euclid(rhs.z),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_rem_euclid(vector: Vec3A<f64>, rhs: Vec3A<f64>) -> Vec3A<f64> {
+            Vec3A::new(
+                vector.x.rem_euclid(rhs.x),
+                vector.y.rem_euclid(rhs.y),
+                vector.z.rem_euclid(rhs.z),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_powf(vector: Vec3A<f64>, n: f64) -> Vec3A<f64> {
+            Vec3A::new(
+                PrimitiveFloatUtils::powf(vector.x, n),
+                PrimitiveFloatUtils::powf(vector.y, n),
+                PrimitiveFloatUtils::powf(vector.z, n),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_sqrt(vector: Vec3A<f64>) -> Vec3A<f64> {
+            Vector(M128dPair(_mm_sqrt_pd(vector.0.0), _mm_sqrt_pd(vector.0.1)))
+        }
+
+        #[inline]
+        fn vector_rsqrt(vector: Vec3A<f64>) -> Vec3A<f64> {
+            Vec3A::ONE / vector.sqrt()
+        }
+
+        #[inline]
+        fn vector_recip_fast(vector: Vec3A<f64>) -> Vec3A<f64> {
+            vector.recip()
+        }
+
+        #[inline]
+        fn vector_length_recip_fast(vector: Vec3A<f64>) -> f64 {
+            vector.length().recip()
+        }
+
+        #[inline]
+        fn vector_normalize_fast(vector: Vec3A<f64>) -> Vec3A<f64> {
+            vector / vector.length()
+        }
+
+        #[inline(always)]
+        fn vector_exp(vector: Vec3A<f64>) -> Vec3A<f64> {
+            Vec3A::new(
+                PrimitiveFloatUtils::exp(vector.x),
+                PrimitiveFloatUtils::exp(vector.y),
+                PrimitiveFloatUtils::exp(vector.z),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_exp2(vector: Vec3A<f64>) -> Vec3A<f64> {
+            Vec3A::new(
+                PrimitiveFloatUtils::exp2(vector.x),
+                PrimitiveFloatUtils::exp2(vector.y),
+                PrimitiveFloatUtils::exp2(vector.z),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_ln(vector: Vec3A<f64>) -> Vec3A<f64> {
+            Vec3A::new(
+                PrimitiveFloatUtils::ln(vector.x),
+                PrimitiveFloatUtils::ln(vector.y),
+                PrimitiveFloatUtils::ln(vector.z),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_log2(vector: Vec3A<f64>) -> Vec3A<f64> {
+            Vec3A::new(
+                PrimitiveFloatUtils::log2(vector.x),
+                PrimitiveFloatUtils::log2(vector.y),
+                PrimitiveFloatUtils::log2(vector.z),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_sin(vector: Vec3A<f64>) -> Vec3A<f64> {
+            Vec3A::new(
+                PrimitiveFloatUtils::sin(vector.x),
+                PrimitiveFloatUtils::sin(vector.y),
+                PrimitiveFloatUtils::sin(vector.z),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_cos(vector: Vec3A<f64>) -> Vec3A<f64> {
+            Vec3A::new(
+                PrimitiveFloatUtils::cos(vector.x),
+                PrimitiveFloatUtils::cos(vector.y),
+                PrimitiveFloatUtils::cos(vector.z),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_tan(vector: Vec3A<f64>) -> Vec3A<f64> {
+            Vec3A::new(
+                PrimitiveFloatUtils::tan(vector.x),
+                PrimitiveFloatUtils::tan(vector.y),
+                PrimitiveFloatUtils::tan(vector.z),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_asin(vector: Vec3A<f64>) -> Vec3A<f64> {
+            Vec3A::new(
+                PrimitiveFloatUtils::asin(vector.x),
+                PrimitiveFloatUtils::asin(vector.y),
+                PrimitiveFloatUtils::asin(vector.z),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_acos(vector: Vec3A<f64>) -> Vec3A<f64> {
+            Vec3A::new(
+                PrimitiveFloatUtils::acos(vector.x),
+                PrimitiveFloatUtils::acos(vector.y),
+                PrimitiveFloatUtils::acos(vector.z),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_atan(vector: Vec3A<f64>) -> Vec3A<f64> {
+            Vec3A::new(
+                PrimitiveFloatUtils::atan(vector.x),
+                PrimitiveFloatUtils::atan(vector.y),
+                PrimitiveFloatUtils::atan(vector.z),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_sin_cos(vector: Vec3A<f64>) -> (Vec3A<f64>, Vec3A<f64>) {
+            let x_sin_cos = PrimitiveFloatUtils::sin_cos(vector.x);
+            let y_sin_cos = PrimitiveFloatUtils::sin_cos(vector.y);
+            let z_sin_cos = PrimitiveFloatUtils::sin_cos(vector.z);
+            (
+                Vec3A::new(x_sin_cos.0, y_sin_cos.0, z_sin_cos.0),
+                Vec3A::new(x_sin_cos.1, y_sin_cos.1, z_sin_cos.1),
+            )
+        }
+    }
+}
+
+#[cfg(not(target_feature = "avx"))]
+impl FloatVectorBackend<4, Aligned> for f64 {
+    safe_target_feature! {
+        #[inline]
+        fn vector_nan_mask(vector: Vec4A<f64>) -> Mask4A<f64> {
+            Mask(M128dPair(nan_mask(vector.0.0), nan_mask(vector.0.1)))
+        }
+
+        #[inline]
+        fn vector_finite_mask(vector: Vec4A<f64>) -> Mask4A<f64> {
+            Mask(M128dPair(finite_mask(vector.0.0), finite_mask(vector.0.1)))
+        }
+
+        #[inline]
+        fn vector_sign_positive_mask(vector: Vec4A<f64>) -> Mask4A<f64> {
+            Mask(M128dPair(
+                not(sign_negative_mask(vector.0.0)),
+                not(sign_negative_mask(vector.0.1)),
+            ))
+        }
+
+        #[inline]
+        fn vector_sign_negative_mask(vector: Vec4A<f64>) -> Mask4A<f64> {
+            Mask(M128dPair(sign_negative_mask(vector.0.0), sign_negative_mask(vector.0.1)))
+        }
+
+        #[inline]
+        fn vector_max(vector: Vec4A<f64>, other: Vec4A<f64>) -> Vec4A<f64> {
+            Vector(M128dPair(_mm_max_pd(vector.0.0, other.0.0), _mm_max_pd(vector.0.1, other.0.1)))
+        }
+
+        #[inline]
+        fn vector_min(vector: Vec4A<f64>, other: Vec4A<f64>) -> Vec4A<f64> {
+            Vector(M128dPair(_mm_min_pd(vector.0.0, other.0.0), _mm_min_pd(vector.0.1, other.0.1)))
+        }
+
+        #[inline]
+        fn vector_abs(vector: Vec4A<f64>) -> Vec4A<f64> {
+            Vector(M128dPair(abs(vector.0.0), abs(vector.0.1)))
+        }
+
+        #[inline]
+        fn vector_signum(vector: Vec4A<f64>) -> Vec4A<f64> {
+            Vector(M128dPair(signum(vector.0.0), signum(vector.0.1)))
+        }
+
+        #[inline]
+        fn vector_copysign(vector: Vec4A<f64>, sign: Vec4A<f64>) -> Vec4A<f64> {
+            Vector(M128dPair(copysign(vector.0.0, sign.0.0), copysign(vector.0.1, sign.0.1)))
+        }
+
+        #[inline]
+        fn vector_max_element(vector: Vec4A<f64>) -> f64 {
+            let vector = _mm_max_pd(vector.0.0, vector.0.1);
+            _mm_cvtsd_f64(_mm_max_sd(vector, _mm_unpackhi_pd(vector, vector)))
+        }
+
+        #[inline]
+        fn vector_min_element(vector: Vec4A<f64>) -> f64 {
+            let vector = _mm_min_pd(vector.0.0, vector.0.1);
+            _mm_cvtsd_f64(_mm_min_sd(vector, _mm_unpackhi_pd(vector, vector)))
+        }
+
+        #[inline(always)]
+        fn vector_floor(vector: Vec4A<f64>) -> Vec4A<f64> {
+            Vector(M128dPair(floor(vector.0.0), floor(vector.0.1)))
+        }
+
+        #[inline(always)]
+        fn vector_ceil(vector: Vec4A<f64>) -> Vec4A<f64> {
+            Vector(M128dPair(ceil(vector.0.0), ceil(vector.0.1)))
+        }
+
+        #[inline(always)]
+        fn vector_round(vector: Vec4A<f64>) -> Vec4A<f64> {
+            Vector(M128dPair(round(vector.0.0), round(vector.0.1)))
+        }
+
+        #[inline(always)]
+        fn vector_trunc(vector: Vec4A<f64>) -> Vec4A<f64> {
+            Vector(M128dPair(trunc(vector.0.0), trunc(vector.0.1)))
+        }
+
+        #[inline(always)]
+        fn vector_mul_add(vector: Vec4A<f64>, a: Vec4A<f64>, b: Vec4A<f64>) -> Vec4A<f64> {
+            Vec4A::new(
+                vector.x.mul_add(a.x, b.x),
+                vector.y.mul_add(a.y, b.y),
+                vector.z.mul_add(a.z, b.z),
+                vector.w.mul_add(a.w, b.w),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_div_euclid(vector: Vec4A<f64>, rhs: Vec4A<f64>) -> Vec4A<f64> {
+            Vec4A::new(
+                vector.x.div_euclid(rhs.x),
+                vector.y.div_euclid(rhs.y),
+                vector.z.div_euclid(rhs.z),
+                vector.w.div_euclid(rhs.w),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_rem_euclid(vector: Vec4A<f64>, rhs: Vec4A<f64>) -> Vec4A<f64> {
+            Vec4A::new(
+                vector.x.rem_euclid(rhs.x),
+                vector.y.rem_euclid(rhs.y),
+                vector.z.rem_euclid(rhs.z),
+                vector.w.rem_euclid(rhs.w),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_powf(vector: Vec4A<f64>, n: f64) -> Vec4A<f64> {
+            Vec4A::new(
+                PrimitiveFloatUtils::powf(vector.x, n),
+                PrimitiveFloatUtils::powf(vector.y, n),
+                PrimitiveFloatUtils::powf(vector.z, n),
+                PrimitiveFloatUtils::powf(vector.w, n),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_sqrt(vector: Vec4A<f64>) -> Vec4A<f64> {
+            Vector(M128dPair(_mm_sqrt_pd(vector.0.0), _mm_sqrt_pd(vector.0.1)))
+        }
+
+        #[inline]
+        fn vector_rsqrt(vector: Vec4A<f64>) -> Vec4A<f64> {
+            Vec4A::ONE / vector.sqrt()
+        }
+
+        #[inline]
+        fn vector_recip_fast(vector: Vec4A<f64>) -> Vec4A<f64> {
+            vector.recip()
+        }
+
+        #[inline]
+        fn vector_length_recip_fast(vector: Vec4A<f64>) -> f64 {
+            vector.length().recip()
+        }
+
+        #[inline]
+        fn vector_normalize_fast(vector: Vec4A<f64>) -> Vec4A<f64> {
+            vector / vector.length()
+        }
+
+        #[inline(always)]
+        fn vector_exp(vector: Vec4A<f64>) -> Vec4A<f64> {
+            Vec4A::new(
+                PrimitiveFloatUtils::exp(vector.x),
+                PrimitiveFloatUtils::exp(vector.y),
+                PrimitiveFloatUtils::exp(vector.z),
+                PrimitiveFloatUtils::exp(vector.w),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_exp2(vector: Vec4A<f64>) -> Vec4A<f64> {
+            Vec4A::new(
+                PrimitiveFloatUtils::exp2(vector.x),
+                PrimitiveFloatUtils::exp2(vector.y),
+                PrimitiveFloatUtils::exp2(vector.z),
+                PrimitiveFloatUtils::exp2(vector.w),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_ln(vector: Vec4A<f64>) -> Vec4A<f64> {
+            Vec4A::new(
+                PrimitiveFloatUtils::ln(vector.x),
+                PrimitiveFloatUtils::ln(vector.y),
+                PrimitiveFloatUtils::ln(vector.z),
+                PrimitiveFloatUtils::ln(vector.w),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_log2(vector: Vec4A<f64>) -> Vec4A<f64> {
+            Vec4A::new(
+                PrimitiveFloatUtils::log2(vector.x),
+                PrimitiveFloatUtils::log2(vector.y),
+                PrimitiveFloatUtils::log2(vector.z),
+                PrimitiveFloatUtils::log2(vector.w),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_sin(vector: Vec4A<f64>) -> Vec4A<f64> {
+            Vec4A::new(
+                PrimitiveFloatUtils::sin(vector.x),
+                PrimitiveFloatUtils::sin(vector.y),
+                PrimitiveFloatUtils::sin(vector.z),
+                PrimitiveFloatUtils::sin(vector.w),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_cos(vector: Vec4A<f64>) -> Vec4A<f64> {
+            Vec4A::new(
+                PrimitiveFloatUtils::cos(vector.x),
+                PrimitiveFloatUtils::cos(vector.y),
+                PrimitiveFloatUtils::cos(vector.z),
+                PrimitiveFloatUtils::cos(vector.w),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_tan(vector: Vec4A<f64>) -> Vec4A<f64> {
+            Vec4A::new(
+                PrimitiveFloatUtils::tan(vector.x),
+                PrimitiveFloatUtils::tan(vector.y),
+                PrimitiveFloatUtils::tan(vector.z),
+                PrimitiveFloatUtils::tan(vector.w),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_asin(vector: Vec4A<f64>) -> Vec4A<f64> {
+            Vec4A::new(
+                PrimitiveFloatUtils::asin(vector.x),
+                PrimitiveFloatUtils::asin(vector.y),
+                PrimitiveFloatUtils::asin(vector.z),
+                PrimitiveFloatUtils::asin(vector.w),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_acos(vector: Vec4A<f64>) -> Vec4A<f64> {
+            Vec4A::new(
+                PrimitiveFloatUtils::acos(vector.x),
+                PrimitiveFloatUtils::acos(vector.y),
+                PrimitiveFloatUtils::acos(vector.z),
+                PrimitiveFloatUtils::acos(vector.w),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_atan(vector: Vec4A<f64>) -> Vec4A<f64> {
+            Vec4A::new(
+                PrimitiveFloatUtils::atan(vector.x),
+                PrimitiveFloatUtils::atan(vector.y),
+                PrimitiveFloatUtils::atan(vector.z),
+                PrimitiveFloatUtils::atan(vector.w),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_sin_cos(vector: Vec4A<f64>) -> (Vec4A<f64>, Vec4A<f64>) {
+            let x_sin_cos = PrimitiveFloatUtils::sin_cos(vector.x);
+            let y_sin_cos = PrimitiveFloatUtils::sin_cos(vector.y);
+            let z_sin_cos = PrimitiveFloatUtils::sin_cos(vector.z);
+            let w_sin_cos = PrimitiveFloatUtils::sin_cos(vector.w);
+            (
+                Vec4A::new(x_sin_cos.0, y_sin_cos.0, z_sin_cos.0, w_sin_cos.0),
+                Vec4A::new(x_sin_cos.1, y_sin_cos.1, z_sin_cos.1, w_sin_cos.1),
+            )
+        }
+    }
+}
+
+impl FloatMatrixBackend<2, Aligned> for f64 {
+    #[inline]
+    fn matrix_inverse_and_determinant(matrix: &Mat2A<f64>) -> (Mat2A<f64>, f64) {
+        matrix.inverse_and_determinant_generic()
+    }
+}
+
+#[cfg(not(target_feature = "avx"))]
+impl FloatMatrixBackend<3, Aligned> for f64 {
+    #[inline]
+    fn matrix_inverse_and_determinant(matrix: &Mat3A<f64>) -> (Mat3A<f64>, f64) {
+        matrix.inverse_and_determinant_generic()
+    }
+}
+
+#[cfg(not(target_feature = "avx"))]
+impl FloatMatrixBackend<4, Aligned> for f64 {
+    #[inline]
+    fn matrix_inverse_and_determinant(matrix: &Mat4A<f64>) -> (Mat4A<f64>, f64) {
+        matrix.inverse_and_determinant_generic()
+    }
+}
+
+impl CastBackend<2, Aligned> for f64 {
+    safe_target_feature! {
+        #[inline]
+        fn vector_as_f32(vector: Vec2A<f64>) -> Vec2A<f32> {
+            vector.map(|x| x as f32)
+        }
+
+        #[inline]
+        fn vector_as_f64(vector: Vec2A<f64>) -> Vec2A<f64> {
+            vector
+        }
+
+        #[inline]
+        fn vector_as_i32(vector: Vec2A<f64>) -> Vec2A<i32> {
+            vector.map(|x| x as i32)
+        }
+
+        #[inline]
+        fn vector_as_u32(vector: Vec2A<f64>) -> Vec2A<u32> {
+            vector.map(|x| x as u32)
+        }
+    }
+}
+
+#[cfg(not(target_feature = "avx"))]
+impl CastBackend<3, Aligned> for f64 {
+    safe_target_feature! {
+        #[inline]
+        fn vector_as_f32(vector: Vec3A<f64>) -> Vec3A<f32> {
+            Vector(_mm_movelh_ps(
+                _mm_cvtpd_ps(vector.0.0),
+                _mm_cvtpd_ps(vector.0.1),
+            ))
+        }
+
+        #[inline]
+        fn vector_as_f64(vector: Vec3A<f64>) -> Vec3A<f64> {
+            vector
+        }
+
+        #[inline]
+        fn vector_as_i32(vector: Vec3A<f64>) -> Vec3A<i32> {
+            Vector(_mm_unpacklo_epi64(
+                cvt_i32(vector.0.0),
+                cvt_i32(vector.0.1),
+            ))
+        }
+
+        #[inline]
+        fn vector_as_u32(vector: Vec3A<f64>) -> Vec3A<u32> {
+            Vector(_mm_unpacklo_epi64(
+                cvt_u32(vector.0.0),
+                cvt_u32(vector.0.1),
+            ))
+        }
+    }
+}
+
+#[cfg(not(target_feature = "avx"))]
+impl CastBackend<4, Aligned> for f64 {
+    safe_target_feature! {
+        #[inline]
+        fn vector_as_f32(vector: Vec4A<f64>) -> Vec4A<f32> {
+            Vector(_mm_movelh_ps(
+                _mm_cvtpd_ps(vector.0.0),
+                _mm_cvtpd_ps(vector.0.1),
+            ))
+        }
+
+        #[inline]
+        fn vector_as_f64(vector: Vec4A<f64>) -> Vec4A<f64> {
+            vector
+        }
+
+        #[inline]
+        fn vector_as_i32(vector: Vec4A<f64>) -> Vec4A<i32> {
+            Vector(_mm_unpacklo_epi64(
+                cvt_i32(vector.0.0),
+                cvt_i32(vector.0.1),
+            ))
+        }
+
+        #[inline]
+        fn vector_as_u32(vector: Vec4A<f64>) -> Vec4A<u32> {
+            Vector(_mm_unpacklo_epi64(
+                cvt_u32(vector.0.0),
+                cvt_u32(vector.0.1),
+            ))
+        }
+    }
+}
+
+safe_target_feature! {
+    #[cfg(not(target_feature = "avx"))]
+    #[inline]
+    fn movemask(vector: M128dPair) -> i32 {
+        _mm_movemask_pd(vector.0) | (_mm_movemask_pd(vector.1) << 2)
+    }
+
+    #[cfg(not(target_feature = "avx"))]
+    #[inline]
+    fn cmpeq(vector: M128dPair, other: M128dPair) -> M128dPair {
+        M128dPair(_mm_cmpeq_pd(vector.0, other.0), _mm_cmpeq_pd(vector.1, other.1))
+    }
+
+    #[cfg(not(target_feature = "avx"))]
+    #[inline]
+    fn cmpneq(vector: M128dPair, other: M128dPair) -> M128dPair {
+        M128dPair(_mm_cmpneq_pd(vector.0, other.0), _mm_cmpneq_pd(vector.1, other.1))
+    }
+
+    #[inline]
+    fn neg(vector: __m128d) -> __m128d {
+        _mm_xor_pd(vector, _mm_set1_pd(-0.0))
+    }
+
+    #[inline]
+    fn not(vector: __m128d) -> __m128d {
+        _mm_xor_pd(vector, _mm_castsi128_pd(_mm_set1_epi32(-1)))
+    }
+
+    #[cfg(any(not(target_feature = "fma"), feature = "deterministic"))]
+    #[inline]
+    fn mul_add_contracted(vector: __m128d, a: __m128d, b: __m128d) -> __m128d {
+        _mm_add_pd(_mm_mul_pd(vector, a), b)
+    }
+
+    #[cfg(all(target_feature = "fma", not(feature = "deterministic")))]
+    #[inline]
+    fn mul_add_contracted(vector: __m128d, a: __m128d, b: __m128d) -> __m128d {
+        _mm_fmadd_pd(vector, a, b)
+    }
+
+    #[cfg(any(not(target_feature = "fma"), feature = "deterministic"))]
+    #[inline]
+    fn dot(vector: __m128d, rhs: __m128d) -> f64 {
+        let products = _mm_mul_pd(vector, rhs);
+        _mm_cvtsd_f64(_mm_add_sd(products, _mm_unpackhi_pd(products, products)))
+    }
+
+    #[cfg(all(target_feature = "fma", not(feature = "deterministic")))]
+    #[inline]
+    fn dot(vector: __m128d, rhs: __m128d) -> f64 {
+        let x_product = _mm_mul_sd(vector, rhs);
+        _mm_cvtsd_f64(_mm_fmadd_sd(
+            _mm_unpackhi_pd(vector, vector),
+            _mm_unpackhi_pd(rhs, rhs),
+            x_product,
+        ))
+    }
+
+    #[inline]
+    fn nan_mask(vector: __m128d) -> __m128d {
+        _mm_cmpneq_pd(vector, vector)
+    }
+
+    #[inline]
+    fn finite_mask(vector: __m128d) -> __m128d {
+        _mm_cmplt_pd(abs(vector), _mm_set1_pd(f64::INFINITY))
+    }
+
+    #[inline]
+    fn sign_negative_mask(vector: __m128d) -> __m128d {
+        // SSE2 has no 64-bit arithmetic shift, so the sign is broadcast from
+        // the upper 32 bits of each element.
+        let high_signs = _mm_srai_epi32::<31>(_mm_castpd_si128(vector));
+        _mm_castsi128_pd(_mm_shuffle_epi32::<0b11_11_01_01>(high_signs))
+    }
+
+    #[inline]
+    fn abs(vector: __m128d) -> __m128d {
+        _mm_andnot_pd(_mm_set1_pd(-0.0), vector)
+    }
+
+    #[inline]
+    fn signum(vector: __m128d) -> __m128d {
+        let result = _mm_or_pd(_mm_set1_pd(1.0), _mm_and_pd(vector, _mm_set1_pd(-0.0)));
+        let nan_mask = _mm_cmpneq_pd(vector, vector);
+
+        select(nan_mask, vector, result)
+    }
+
+    #[inline]
+    fn copysign(vector: __m128d, sign: __m128d) -> __m128d {
+        select(_mm_set1_pd(-0.0), sign, vector)
+    }
+
+    #[inline]
+    fn select(mask: __m128d, if_true: __m128d, if_false: __m128d) -> __m128d {
+        _mm_or_pd(_mm_and_pd(mask, if_true), _mm_andnot_pd(mask, if_false))
+    }
+
+    #[inline]
+    fn trunc(vector: __m128d) -> __m128d {
+        // SSE2 has no 64-bit float to integer conversion, so rounding uses
+        // the `2^52` trick instead. Adding `2^52` to a value below `2^52`
+        // rounds it to an integer, and subtracting it back is exact.
+        const MAGIC: f64 = 4503599627370496.0;
+
+        let vector_abs = abs(vector);
+        let rounded = _mm_sub_pd(_mm_add_pd(vector_abs, _mm_set1_pd(MAGIC)), _mm_set1_pd(MAGIC));
+        // Rounding may round up, which needs to be undone.
+        let result_abs = _mm_sub_pd(
+            rounded,
+            _mm_and_pd(_mm_cmpgt_pd(rounded, vector_abs), _mm_set1_pd(1.0)),
+        );
+        let result = _mm_or_pd(result_abs, _mm_and_pd(vector, _mm_set1_pd(-0.0)));
+
+        // Large values, infinity and NaN are already integers or NaN.
+        let bounds_mask = _mm_cmplt_pd(vector_abs, _mm_set1_pd(MAGIC));
+
+        select(bounds_mask, result, vector)
+    }
+
+    #[inline]
+    fn floor(vector: __m128d) -> __m128d {
+        let trunc = trunc(vector);
+        let greater_mask = _mm_cmpgt_pd(trunc, vector);
+
+        _mm_sub_pd(trunc, _mm_and_pd(greater_mask, _mm_set1_pd(1.0)))
+    }
+
+    #[inline]
+    fn ceil(vector: __m128d) -> __m128d {
+        let trunc = trunc(vector);
+        let less_mask = _mm_cmplt_pd(trunc, vector);
+
+        // Subtracting `-1.0` instead of adding `1.0` retains the sign of
+        // `-0.0` when no offset is applied.
+        _mm_sub_pd(trunc, _mm_and_pd(less_mask, _mm_set1_pd(-1.0)))
+    }
+
+    #[inline]
+    fn round(vector: __m128d) -> __m128d {
+        let vector_abs = abs(vector);
+        let trunc_abs = trunc(vector_abs);
+        // The fraction is computed exactly, so there is no need to handle
+        // `0.5.next_down()` like the `f32` implementation.
+        let round_up_mask = _mm_cmpge_pd(_mm_sub_pd(vector_abs, trunc_abs), _mm_set1_pd(0.5));
+        let result_abs = _mm_add_pd(trunc_abs, _mm_and_pd(round_up_mask, _mm_set1_pd(1.0)));
+        let result = _mm_or_pd(result_abs, _mm_and_pd(vector, _mm_set1_pd(-0.0)));
+
+        // Large values, infinity and NaN need special handling.
+        let bounds_mask = _mm_cmplt_pd(vector_abs, _mm_set1_pd(4503599627370496.0));
+
+        select(bounds_mask, result, vector)
+    }
+
+    #[cfg(not(target_feature = "avx"))]
+    #[inline]
+    fn cvt_i32(vector: __m128d) -> __m128i {
+        // Every `i32` is exactly representable, so clamping makes
+        // `_mm_cvttpd_epi32` saturate like `as`. NaN is cleared to `0` first.
+        let vector = _mm_and_pd(vector, _mm_cmpord_pd(vector, vector));
+        let vector = _mm_min_pd(
+            _mm_max_pd(vector, _mm_set1_pd(-2147483648.0)),
+            _mm_set1_pd(2147483647.0),
+        );
+
+        _mm_cvttpd_epi32(vector)
+    }
+
+    #[cfg(not(target_feature = "avx"))]
+    #[inline]
+    fn cvt_u32(vector: __m128d) -> __m128i {
+        // `_mm_max_pd` returns the second operand for NaN.
+        let vector = _mm_min_pd(
+            _mm_max_pd(vector, _mm_setzero_pd()),
+            _mm_set1_pd(4294967295.0),
+        );
+        let high = _mm_and_pd(
+            _mm_cmpge_pd(vector, _mm_set1_pd(2147483648.0)),
+            _mm_set1_pd(2147483648.0),
+        );
+
+        // Values in `2^31..2^32` are converted with an offset of `2^31`, which
+        // is then restored by flipping the sign bit.
+        _mm_xor_si128(
+            _mm_cvttpd_epi32(_mm_sub_pd(vector, high)),
+            _mm_cvttpd_epi32(_mm_xor_pd(high, _mm_set1_pd(-0.0))),
+        )
+    }
+}
@@ -0,0 +1,1048 @@
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+#[cfg(not(target_feature = "avx"))]
+use super::f64::M128dPair;
+use crate::{
+    Aligned, Mask, Mask3A, Mask4A, Mat3A, Mat4A, QuatA, Quaternion, Vec3A, Vec4A, Vector,
+    backend::{
+        CastBackend, IntegerVectorBackend, MaskBackend, MatrixBackend, QuaternionBackend,
+        SignedVectorBackend, VectorBackend, overflow_check,
+    },
+    utils::safe_target_feature,
+};
+
+// `Self::Inner` follows its requirements.
+unsafe impl VectorBackend<3, Aligned> for i32 {
+    type Inner = __m128i;
+
+    safe_target_feature! {
+        #[inline]
+        fn vector_eq(vector: &Vec3A<i32>, other: &Vec3A<i32>) -> bool {
+            movemask(_mm_cmpeq_epi32(vector.0, other.0)) & 0b111 == 0b111
+        }
+
+        #[inline]
+        fn vector_ne(vector: &Vec3A<i32>, other: &Vec3A<i32>) -> bool {
+            movemask(_mm_cmpeq_epi32(vector.0, other.0)) & 0b111 != 0b111
+        }
+
+        #[inline]
+        fn vector_neg(vector: Vec3A<i32>) -> Vec3A<i32> {
+            overflow_check::<3, _>(|i| -vector[i]);
+            Vector(_mm_sub_epi32(_mm_setzero_si128(), vector.0))
+        }
+
+        #[inline]
+        fn vector_not(vector: Vec3A<i32>) -> Vec3A<i32> {
+            Vector(not(vector.0))
+        }
+
+        #[inline]
+        fn vector_add(vector: Vec3A<i32>, rhs: Vec3A<i32>) -> Vec3A<i32> {
+            overflow_check::<3, _>(|i| vector[i] + rhs[i]);
+            Vector(_mm_add_epi32(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_sub(vector: Vec3A<i32>, rhs: Vec3A<i32>) -> Vec3A<i32> {
+            overflow_check::<3, _>(|i| vector[i] - rhs[i]);
+            Vector(_mm_sub_epi32(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_mul(vector: Vec3A<i32>, rhs: Vec3A<i32>) -> Vec3A<i32> {
+            overflow_check::<3, _>(|i| vector[i] * rhs[i]);
+            Vector(mul(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_div(vector: Vec3A<i32>, rhs: Vec3A<i32>) -> Vec3A<i32> {
+            Vec3A::<i32>::new(vector.x / rhs.x, vector.y / rhs.y, vector.z / rhs.z)
+        }
+
+        #[inline]
+        fn vector_rem(vector: Vec3A<i32>, rhs: Vec3A<i32>) -> Vec3A<i32> {
+            Vec3A::<i32>::new(vector.x % rhs.x, vector.y % rhs.y, vector.z % rhs.z)
+        }
+
+        #[cfg(not(target_feature = "avx2"))]
+        #[inline]
+        fn vector_shl(vector: Vec3A<i32>, rhs: Vec3A<i32>) -> Vec3A<i32> {
+            Vec3A::<i32>::new(vector.x << rhs.x, vector.y << rhs.y, vector.z << rhs.z)
+        }
+
+        #[cfg(target_feature = "avx2")]
+        #[inline]
+        fn vector_shl(vector: Vec3A<i32>, rhs: Vec3A<i32>) -> Vec3A<i32> {
+            overflow_check::<3, _>(|i| vector[i] << rhs[i]);
+            Vector(_mm_sllv_epi32(vector.0, shift_amount(rhs.0)))
+        }
+
+        #[cfg(not(target_feature = "avx2"))]
+        #[inline]
+        fn vector_shr(vector: Vec3A<i32>, rhs: Vec3A<i32>) -> Vec3A<i32> {
+            Vec3A::<i32>::new(vector.x >> rhs.x, vector.y >> rhs.y, vector.z >> rhs.z)
+        }
+
+        #[cfg(target_feature = "avx2")]
+        #[inline]
+        fn vector_shr(vector: Vec3A<i32>, rhs: Vec3A<i32>) -> Vec3A<i32> {
+            overflow_check::<3, _>(|i| vector[i] >> rhs[i]);
+            Vector(_mm_srav_epi32(vector.0, shift_amount(rhs.0)))
+        }
+
+        #[inline]
+        fn vector_bitand(vector: Vec3A<i32>, rhs: Vec3A<i32>) -> Vec3A<i32> {
+            Vector(_mm_and_si128(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_bitor(vector: Vec3A<i32>, rhs: Vec3A<i32>) -> Vec3A<i32> {
+            Vector(_mm_or_si128(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_bitxor(vector: Vec3A<i32>, rhs: Vec3A<i32>) -> Vec3A<i32> {
+            Vector(_mm_xor_si128(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_element_sum(vector: Vec3A<i32>) -> i32 {
+            vector.x + vector.y + vector.z
+        }
+
+        #[inline]
+        fn vector_element_product(vector: Vec3A<i32>) -> i32 {
+            vector.x * vector.y * vector.z
+        }
+
+        #[inline]
+        fn vector_dot(vector: Vec3A<i32>, rhs: Vec3A<i32>) -> i32 {
+            (vector * rhs).element_sum()
+        }
+
+        #[inline]
+        fn vector_mul_add_contracted(vector: Vec3A<i32>, a: Vec3A<i32>, b: Vec3A<i32>) -> Vec3A<i32> {
+            vector * a + b
+        }
+
+        #[inline]
+        fn vector_eq_mask(vector: Vec3A<i32>, other: Vec3A<i32>) -> Mask3A<i32> {
+            Mask(_mm_cmpeq_epi32(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_ne_mask(vector: Vec3A<i32>, other: Vec3A<i32>) -> Mask3A<i32> {
+            Mask(not(_mm_cmpeq_epi32(vector.0, other.0)))
+        }
+
+        #[inline]
+        fn vector_lt_mask(vector: Vec3A<i32>, other: Vec3A<i32>) -> Mask3A<i32> {
+            Mask(_mm_cmplt_epi32(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_gt_mask(vector: Vec3A<i32>, other: Vec3A<i32>) -> Mask3A<i32> {
+            Mask(_mm_cmpgt_epi32(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_le_mask(vector: Vec3A<i32>, other: Vec3A<i32>) -> Mask3A<i32> {
+            Mask(not(_mm_cmpgt_epi32(vector.0, other.0)))
+        }
+
+        #[inline]
+        fn vector_ge_mask(vector: Vec3A<i32>, other: Vec3A<i32>) -> Mask3A<i32> {
+            Mask(not(_mm_cmplt_epi32(vector.0, other.0)))
+        }
+    }
+}
+
+// `Self::Inner` follows its requirements.
+unsafe impl VectorBackend<4, Aligned> for i32 {
+    type Inner = __m128i;
+
+    safe_target_feature! {
+        #[inline]
+        fn vector_eq(vector: &Vec4A<i32>, other: &Vec4A<i32>) -> bool {
+            movemask(_mm_cmpeq_epi32(vector.0, other.0)) & 0b1111 == 0b1111
+        }
+
+        #[inline]
+        fn vector_ne(vector: &Vec4A<i32>, other: &Vec4A<i32>) -> bool {
+            movemask(_mm_cmpeq_epi32(vector.0, other.0)) & 0b1111 != 0b1111
+        }
+
+        #[inline]
+        fn vector_neg(vector: Vec4A<i32>) -> Vec4A<i32> {
+            overflow_check::<4, _>(|i| -vector[i]);
+            Vector(_mm_sub_epi32(_mm_setzero_si128(), vector.0))
+        }
+
+        #[inline]
+        fn vector_not(vector: Vec4A<i32>) -> Vec4A<i32> {
+            Vector(not(vector.0))
+        }
+
+        #[inline]
+        fn vector_add(vector: Vec4A<i32>, rhs: Vec4A<i32>) -> Vec4A<i32> {
+            overflow_check::<4, _>(|i| vector[i] + rhs[i]);
+            Vector(_mm_add_epi32(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_sub(vector: Vec4A<i32>, rhs: Vec4A<i32>) -> Vec4A<i32> {
+            overflow_check::<4, _>(|i| vector[i] - rhs[i]);
+            Vector(_mm_sub_epi32(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_mul(vector: Vec4A<i32>, rhs: Vec4A<i32>) -> Vec4A<i32> {
+            overflow_check::<4, _>(|i| vector[i] * rhs[i]);
+            Vector(mul(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_div(vector: Vec4A<i32>, rhs: Vec4A<i32>) -> Vec4A<i32> {
+            Vec4A::<i32>::new(vector.x / rhs.x, vector.y / rhs.y, vector.z / rhs.z, vector.w / rhs.w)
+        }
+
+        #[inline]
+        fn vector_rem(vector: Vec4A<i32>, rhs: Vec4A<i32>) -> Vec4A<i32> {
+            Vec4A::<i32>::new(vector.x % rhs.x, vector.y % rhs.y, vector.z % rhs.z, vector.w % rhs.w)
+        }
+
+        #[cfg(not(target_feature = "avx2"))]
+        #[inline]
+        fn vector_shl(vector: Vec4A<i32>, rhs: Vec4A<i32>) -> Vec4A<i32> {
+            Vec4A::<i32>::new(vector.x << rhs.x, vector.y << rhs.y, vector.z << rhs.z, vector.w << rhs.w)
+        }
+
+        #[cfg(target_feature = "avx2")]
+        #[inline]
+        fn vector_shl(vector: Vec4A<i32>, rhs: Vec4A<i32>) -> Vec4A<i32> {
+            overflow_check::<4, _>(|i| vector[i] << rhs[i]);
+            Vector(_mm_sllv_epi32(vector.0, shift_amount(rhs.0)))
+        }
+
+        #[cfg(not(target_feature = "avx2"))]
+        #[inline]
+        fn vector_shr(vector: Vec4A<i32>, rhs: Vec4A<i32>) -> Vec4A<i32> {
+            Vec4A::<i32>::new(vector.x >> rhs.x, vector.y >> rhs.y, vector.z >> rhs.z, vector.w >> rhs.w)
+        }
+
+        #[cfg(target_feature = "avx2")]
+        #[inline]
+        fn vector_shr(vector: Vec4A<i32>, rhs: Vec4A<i32>) -> Vec4A<i32> {
+            overflow_check::<4, _>(|i| vector[i] >> rhs[i]);
+            Vector(_mm_srav_epi32(vector.0, shift_amount(rhs.0)))
+        }
+
+        #[inline]
+        fn vector_bitand(vector: Vec4A<i32>, rhs: Vec4A<i32>) -> Vec4A<i32> {
+            Vector(_mm_and_si128(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_bitor(vector: Vec4A<i32>, rhs: Vec4A<i32>) -> Vec4A<i32> {
+            Vector(_mm_or_si128(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_bitxor(vector: Vec4A<i32>, rhs: Vec4A<i32>) -> Vec4A<i32> {
+            Vector(_mm_xor_si128(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_element_sum(vector: Vec4A<i32>) -> i32 {
+            vector.x + vector.y + (vector.z + vector.w)
+        }
+
+        #[inline]
+        fn vector_element_product(vector: Vec4A<i32>) -> i32 {
+            vector.x * vector.y * (vector.z * vector.w)
+        }
+
+        #[inline]
+        fn vector_dot(vector: Vec4A<i32>, rhs: Vec4A<i32>) -> i32 {
+            (vector * rhs).element_sum()
+        }
+
+        #[inline]
+        fn vector_mul_add_contracted(vector: Vec4A<i32>, a: Vec4A<i32>, b: Vec4A<i32>) -> Vec4A<i32> {
+            vector * a + b
+        }
+
+        #[inline]
+        fn vector_eq_mask(vector: Vec4A<i32>, other: Vec4A<i32>) -> Mask4A<i32> {
+            Mask(_mm_cmpeq_epi32(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_ne_mask(vector: Vec4A<i32>, other: Vec4A<i32>) -> Mask4A<i32> {
+            Mask(not(_mm_cmpeq_epi32(vector.0, other.0)))
+        }
+
+        #[inline]
+        fn vector_lt_mask(vector: Vec4A<i32>, other: Vec4A<i32>) -> Mask4A<i32> {
+            Mask(_mm_cmplt_epi32(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_gt_mask(vector: Vec4A<i32>, other: Vec4A<i32>) -> Mask4A<i32> {
+            Mask(_mm_cmpgt_epi32(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_le_mask(vector: Vec4A<i32>, other: Vec4A<i32>) -> Mask4A<i32> {
+            Mask(not(_mm_cmpgt_epi32(vector.0, other.0)))
+        }
+
+        #[inline]
+        fn vector_ge_mask(vector: Vec4A<i32>, other: Vec4A<i32>) -> Mask4A<i32> {
+            Mask(not(_mm_cmplt_epi32(vector.0, other.0)))
+        }
+    }
+}
+
+impl QuaternionBackend<Aligned> for i32 {
+    #[inline]
+    fn quat_mul(quat: QuatA<i32>, rhs: QuatA<i32>) -> QuatA<i32> {
+        let [x0, y0, z0, w0] = quat.to_array();
+        let [x1, y1, z1, w1] = rhs.to_array();
+
+        Quaternion::from_xyzw(
+            x0 * w1 + w0 * x1 + z0 * y1 - y0 * z1,
+            y0 * w1 - z0 * x1 + w0 * y1 + x0 * z1,
+            z0 * w1 + y0 * x1 - x0 * y1 + w0 * z1,
+            w0 * w1 - x0 * x1 - y0 * y1 - z0 * z1,
+        )
+    }
+}
+
+impl MatrixBackend<3, Aligned> for i32 {
+    #[inline]
+    fn matrix_transpose(matrix: &Mat3A<i32>) -> Mat3A<i32> {
+        matrix.transpose_generic()
+    }
+
+    #[inline]
+    fn matrix_mul(matrix: &Mat3A<i32>, rhs: &Mat3A<i32>) -> Mat3A<i32> {
+        matrix.mul_generic(rhs)
+    }
+
+    #[inline]
+    fn matrix_determinant(matrix: &Mat3A<i32>) -> i32 {
+        matrix.determinant_generic()
+    }
+}
+
+impl MatrixBackend<4, Aligned> for i32 {
+    #[inline]
+    fn matrix_transpose(matrix: &Mat4A<i32>) -> Mat4A<i32> {
+        matrix.transpose_generic()
+    }
+
+    #[inline]
+    fn matrix_mul(matrix: &Mat4A<i32>, rhs: &Mat4A<i32>) -> Mat4A<i32> {
+        matrix.mul_generic(rhs)
+    }
+
+    #[inline]
+    fn matrix_determinant(matrix: &Mat4A<i32>) -> i32 {
+        matrix.determinant_generic()
+    }
+}
+
+// `Self::Inner` follows its requirements.
+unsafe impl MaskBackend<3, Aligned> for i32 {
+    type Inner = __m128i;
+
+    safe_target_feature! {
+        #[inline]
+        fn mask_from_array(array: [bool; 3]) -> Mask3A<i32> {
+            Mask(_mm_set_epi32(-(array[2] as i32), -(array[2] as i32), -(array[1] as i32), -(array[0] as i32)))
+        }
+
+        #[inline]
+        fn mask_splat(value: bool) -> Mask3A<i32> {
+            Mask(_mm_set1_epi32(-(value as i32)))
+        }
+
+        #[inline]
+        fn mask_to_array(mask: Mask3A<i32>) -> [bool; 3] {
+            let bits = movemask(mask.0);
+            [bits & 0x1 != 0, bits & 0x2 != 0, bits & 0x4 != 0]
+        }
+
+        #[inline]
+        fn mask_all(mask: Mask3A<i32>) -> bool {
+            movemask(mask.0) & 0b111 == 0b111
+        }
+
+        #[inline]
+        fn mask_any(mask: Mask3A<i32>) -> bool {
+            movemask(mask.0) & 0b111 != 0
+        }
+
+        #[inline]
+        fn mask_select(mask: Mask3A<i32>, if_true: Vec3A<i32>, if_false: Vec3A<i32>) -> Vec3A<i32> {
+            Vector(select(mask.0, if_true.0, if_false.0))
+        }
+
+        #[inline]
+        fn mask_get(mask: Mask3A<i32>, index: usize) -> bool {
+            match index {
+                0 => movemask(mask.0) & 0x1 != 0,
+                1 => movemask(mask.0) & 0x2 != 0,
+                2 => movemask(mask.0) & 0x4 != 0,
+                _ => panic!("index out of bounds"),
+            }
+        }
+
+        #[inline]
+        fn mask_set(mask: &mut Mask3A<i32>, index: usize, value: bool) {
+            if index < 3 {
+                // SAFETY: `*mut __m128i` is valid as `*mut i32` for 4 values. Adding
+                // `index` is valid because it was just checked to be less then 3,
+                // and the result is a pointer to a valid `i32`.
+                let slot = unsafe {
+                    core::ptr::from_mut::<__m128i>(&mut mask.0)
+                        .cast::<i32>()
+                        .add(index)
+                        .as_mut()
+                        .unwrap_unchecked()
+                };
+
+                *slot = -(value as i32);
+            } else {
+                panic!("index out of bounds")
+            }
+        }
+
+        #[inline]
+        fn mask_eq(mask: &Mask3A<i32>, other: &Mask3A<i32>) -> bool {
+            movemask(mask.0) & 0b111 == movemask(other.0) & 0b111
+        }
+
+        #[inline]
+        fn mask_ne(mask: &Mask3A<i32>, other: &Mask3A<i32>) -> bool {
+            !(mask == other)
+        }
+
+        #[inline]
+        fn mask_not(mask: Mask3A<i32>) -> Mask3A<i32> {
+            Mask(not(mask.0))
+        }
+
+        #[inline]
+        fn mask_bitand(mask: Mask3A<i32>, rhs: Mask3A<i32>) -> Mask3A<i32> {
+            Mask(_mm_and_si128(mask.0, rhs.0))
+        }
+
+        #[inline]
+        fn mask_bitor(mask: Mask3A<i32>, rhs: Mask3A<i32>) -> Mask3A<i32> {
+            Mask(_mm_or_si128(mask.0, rhs.0))
+        }
+
+        #[inline]
+        fn mask_bitxor(mask: Mask3A<i32>, rhs: Mask3A<i32>) -> Mask3A<i32> {
+            Mask(_mm_xor_si128(mask.0, rhs.0))
+        }
+    }
+}
+
+// `Self::Inner` follows its requirements.
+unsafe impl MaskBackend<4, Aligned> for i32 {
+    type Inner = __m128i;
+
+    safe_target_feature! {
+        #[inline]
+        fn mask_from_array(array: [bool; 4]) -> Mask4A<i32> {
+            Mask(_mm_set_epi32(-(array[3] as i32), -(array[2] as i32), -(array[1] as i32), -(array[0] as i32)))
+        }
+
+        #[inline]
+        fn mask_splat(value: bool) -> Mask4A<i32> {
+            Mask(_mm_set1_epi32(-(value as i32)))
+        }
+
+        #[inline]
+        fn mask_to_array(mask: Mask4A<i32>) -> [bool; 4] {
+            let bits = movemask(mask.0);
+            [bits & 0x1 != 0, bits & 0x2 != 0, bits & 0x4 != 0, bits & 0x8 != 0]
+        }
+
+        #[inline]
+        fn mask_all(mask: Mask4A<i32>) -> bool {
+            movemask(mask.0) & 0b1111 == 0b1111
+        }
+
+        #[inline]
+        fn mask_any(mask: Mask4A<i32>) -> bool {
+            movemask(mask.0) & 0b1111 != 0
+        }
+
+        #[inline]
+        fn mask_select(mask: Mask4A<i32>, if_true: Vec4A<i32>, if_false: Vec4A<i32>) -> Vec4A<i32> {
+            Vector(select(mask.0, if_true.0, if_false.0))
+        }
+
+        #[inline]
+        fn mask_get(mask: Mask4A<i32>, index: usize) -> bool {
+            match index {
+                0 => movemask(mask.0) & 0x1 != 0,
+                1 => movemask(mask.0) & 0x2 != 0,
+                2 => movemask(mask.0) & 0x4 != 0,
+                3 => movemask(mask.0) & 0x8 != 0,
+                _ => panic!("index out of bounds"),
+            }
+        }
+
+        #[inline]
+        fn mask_set(mask: &mut Mask4A<i32>, index: usize, value: bool) {
+            if index < 4 {
+                // SAFETY: `*mut __m128i` is valid as `*mut i32` for 4 values. Adding
+                // `index` is valid because it was just checked to be less then 4,
+                // and the result is a pointer to a valid `i32`.
+                let slot = unsafe {
+                    core::ptr::from_mut::<__m128i>(&mut mask.0)
+                        .cast::<i32>()
+                        .add(index)
+                        .as_mut()
+                        .unwrap_unchecked()
+                };
+
+                *slot = -(value as i32);
+            } else {
+                panic!("index out of bounds")
+            }
+        }
+
+        #[inline]
+        fn mask_eq(mask: &Mask4A<i32>, other: &Mask4A<i32>) -> bool {
+            movemask(mask.0) & 0b1111 == movemask(other.0) & 0b1111
+        }
+
+        #[inline]
+        fn mask_ne(mask: &Mask4A<i32>, other: &Mask4A<i32>) -> bool {
+            !(mask == other)
+        }
+
+        #[inline]
+        fn mask_not(mask: Mask4A<i32>) -> Mask4A<i32> {
+            Mask(not(mask.0))
+        }
+
+        #[inline]
+        fn mask_bitand(mask: Mask4A<i32>, rhs: Mask4A<i32>) -> Mask4A<i32> {
+            Mask(_mm_and_si128(mask.0, rhs.0))
+        }
+
+        #[inline]
+        fn mask_bitor(mask: Mask4A<i32>, rhs: Mask4A<i32>) -> Mask4A<i32> {
+            Mask(_mm_or_si128(mask.0, rhs.0))
+        }
+
+        #[inline]
+        fn mask_bitxor(mask: Mask4A<i32>, rhs: Mask4A<i32>) -> Mask4A<i32> {
+            Mask(_mm_xor_si128(mask.0, rhs.0))
+        }
+    }
+}
+
+impl IntegerVectorBackend<3, Aligned> for i32 {
+    safe_target_feature! {
+        #[inline]
+        fn vector_max(vector: Vec3A<i32>, other: Vec3A<i32>) -> Vec3A<i32> {
+            Vector(max(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_min(vector: Vec3A<i32>, other: Vec3A<i32>) -> Vec3A<i32> {
+            Vector(min(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_max_element(vector: Vec3A<i32>) -> i32 {
+            max_element(_mm_shuffle_epi32::<0b10_10_01_00>(vector.0))
+        }
+
+        #[inline]
+        fn vector_min_element(vector: Vec3A<i32>) -> i32 {
+            min_element(_mm_shuffle_epi32::<0b10_10_01_00>(vector.0))
+        }
+
+        #[inline]
+        fn vector_checked_add(vector: Vec3A<i32>, rhs: Vec3A<i32>) -> Option<Vec3A<i32>> {
+            let result = _mm_add_epi32(vector.0, rhs.0);
+            let overflow = _mm_and_si128(
+                _mm_xor_si128(vector.0, result),
+                _mm_xor_si128(rhs.0, result),
+            );
+
+            if movemask(overflow) & 0b111 == 0 {
+                Some(Vector(result))
+            } else {
+                None
+            }
+        }
+
+        #[inline]
+        fn vector_checked_sub(vector: Vec3A<i32>, rhs: Vec3A<i32>) -> Option<Vec3A<i32>> {
+            let result = _mm_sub_epi32(vector.0, rhs.0);
+            let overflow = _mm_and_si128(
+                _mm_xor_si128(vector.0, rhs.0),
+                _mm_xor_si128(vector.0, result),
+            );
+
+            if movemask(overflow) & 0b111 == 0 {
+                Some(Vector(result))
+            } else {
+                None
+            }
+        }
+
+        #[inline]
+        fn vector_checked_mul(vector: Vec3A<i32>, rhs: Vec3A<i32>) -> Option<Vec3A<i32>> {
+            Some(Vec3A::<i32>::new(vector.x.checked_mul(rhs.x)?, vector.y.checked_mul(rhs.y)?, vector.z.checked_mul(rhs.z)?))
+        }
+
+        #[inline]
+        fn vector_checked_div(vector: Vec3A<i32>, rhs: Vec3A<i32>) -> Option<Vec3A<i32>> {
+            Some(Vec3A::<i32>::new(vector.x.checked_div(rhs.x)?, vector.y.checked_div(rhs.y)?, vector.z.checked_div(rhs.z)?))
+        }
+
+        #[inline]
+        fn vector_checked_rem(vector: Vec3A<i32>, rhs: Vec3A<i32>) -> Option<Vec3A<i32>> {
+            Some(Vec3A::<i32>::new(vector.x.checked_rem(rhs.x)?, vector.y.checked_rem(rhs.y)?, vector.z.checked_rem(rhs.z)?))
+        }
+
+        #[inline]
+        fn vector_saturating_add(vector: Vec3A<i32>, rhs: Vec3A<i32>) -> Vec3A<i32> {
+            Vector(saturating_add(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_saturating_sub(vector: Vec3A<i32>, rhs: Vec3A<i32>) -> Vec3A<i32> {
+            Vector(saturating_sub(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_saturating_mul(vector: Vec3A<i32>, rhs: Vec3A<i32>) -> Vec3A<i32> {
+            Vec3A::<i32>::new(vector.x.saturating_mul(rhs.x), vector.y.saturating_mul(rhs.y), vector.z.saturating_mul(rhs.z))
+        }
+
+        #[inline]
+        fn vector_saturating_div(vector: Vec3A<i32>, rhs: Vec3A<i32>) -> Vec3A<i32> {
+            Vec3A::<i32>::new(vector.x.saturating_div(rhs.x), vector.y.saturating_div(rhs.y), vector.z.saturating_div(rhs.z))
+        }
+
+        #[inline]
+        fn vector_wrapping_add(vector: Vec3A<i32>, rhs: Vec3A<i32>) -> Vec3A<i32> {
+            Vector(_mm_add_epi32(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_wrapping_sub(vector: Vec3A<i32>, rhs: Vec3A<i32>) -> Vec3A<i32> {
+            Vector(_mm_sub_epi32(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_wrapping_mul(vector: Vec3A<i32>, rhs: Vec3A<i32>) -> Vec3A<i32> {
+            Vector(mul(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_wrapping_div(vector: Vec3A<i32>, rhs: Vec3A<i32>) -> Vec3A<i32> {
+            Vec3A::<i32>::new(vector.x.wrapping_div(rhs.x), vector.y.wrapping_div(rhs.y), vector.z.wrapping_div(rhs.z))
+        }
+
+        #[inline]
+        fn vector_wrapping_rem(vector: Vec3A<i32>, rhs: Vec3A<i32>) -> Vec3A<i32> {
+            Vec3A::<i32>::new(vector.x.wrapping_rem(rhs.x), vector.y.wrapping_rem(rhs.y), vector.z.wrapping_rem(rhs.z))
+        }
+    }
+}
+
+impl IntegerVectorBackend<4, Aligned> for i32 {
+    safe_target_feature! {
+        #[inline]
+        fn vector_max(vector: Vec4A<i32>, other: Vec4A<i32>) -> Vec4A<i32> {
+            Vector(max(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_min(vector: Vec4A<i32>, other: Vec4A<i32>) -> Vec4A<i32> {
+            Vector(min(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_max_element(vector: Vec4A<i32>) -> i32 {
+            max_element(vector.0)
+        }
+
+        #[inline]
+        fn vector_min_element(vector: Vec4A<i32>) -> i32 {
+            min_element(vector.0)
+        }
+
+        #[inline]
+        fn vector_checked_add(vector: Vec4A<i32>, rhs: Vec4A<i32>) -> Option<Vec4A<i32>> {
+            let result = _mm_add_epi32(vector.0, rhs.0);
+            let overflow = _mm_and_si128(
+                _mm_xor_si128(vector.0, result),
+                _mm_xor_si128(rhs.0, result),
+            );
+
+            if movemask(overflow) & 0b1111 == 0 {
+                Some(Vector(result))
+            } else {
+                None
+            }
+        }
+
+        #[inline]
+        fn vector_checked_sub(vector: Vec4A<i32>, rhs: Vec4A<i32>) -> Option<Vec4A<i32>> {
+            let result = _mm_sub_epi32(vector.0, rhs.0);
+            let overflow = _mm_and_si128(
+                _mm_xor_si128(vector.0, rhs.0),
+                _mm_xor_si128(vector.0, result),
+            );
+
+            if movemask(overflow) & 0b1111 == 0 {
+                Some(Vector(result))
+            } else {
+                None
+            }
+        }
+
+        #[inline]
+        fn vector_checked_mul(vector: Vec4A<i32>, rhs: Vec4A<i32>) -> Option<Vec4A<i32>> {
+            Some(Vec4A::<i32>::new(vector.x.checked_mul(rhs.x)?, vector.y.checked_mul(rhs.y)?, vector.z.checked_mul(rhs.z)?, vector.w.checked_mul(rhs.w)?))
+        }
+
+        #[inline]
+        fn vector_checked_div(vector: Vec4A<i32>, rhs: Vec4A<i32>) -> Option<Vec4A<i32>> {
+            Some(Vec4A::<i32>::new(vector.x.checked_div(rhs.x)?, vector.y.checked_div(rhs.y)?, vector.z.checked_div(rhs.z)?, vector.w.checked_div(rhs.w)?))
+        }
+
+        #[inline]
+        fn vector_checked_rem(vector: Vec4A<i32>, rhs: Vec4A<i32>) -> Option<Vec4A<i32>> {
+            Some(Vec4A::<i32>::new(vector.x.checked_rem(rhs.x)?, vector.y.checked_rem(rhs.y)?, vector.z.checked_rem(rhs.z)?, vector.w.checked_rem(rhs.w)?))
+        }
+
+        #[inline]
+        fn vector_saturating_add(vector: Vec4A<i32>, rhs: Vec4A<i32>) -> Vec4A<i32> {
+            Vector(saturating_add(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_saturating_sub(vector: Vec4A<i32>, rhs: Vec4A<i32>) -> Vec4A<i32> {
+            Vector(saturating_sub(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_saturating_mul(vector: Vec4A<i32>, rhs: Vec4A<i32>) -> Vec4A<i32> {
+            Vec4A::<i32>::new(vector.x.saturating_mul(rhs.x), vector.y.saturating_mul(rhs.y), vector.z.saturating_mul(rhs.z), vector.w.saturating_mul(rhs.w))
+        }
+
+        #[inline]
+        fn vector_saturating_div(vector: Vec4A<i32>, rhs: Vec4A<i32>) -> Vec4A<i32> {
+            Vec4A::<i32>::new(vector.x.saturating_div(rhs.x), vector.y.saturating_div(rhs.y), vector.z.saturating_div(rhs.z), vector.w.saturating_div(rhs.w))
+        }
+
+        #[inline]
+        fn vector_wrapping_add(vector: Vec4A<i32>, rhs: Vec4A<i32>) -> Vec4A<i32> {
+            Vector(_mm_add_epi32(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_wrapping_sub(vector: Vec4A<i32>, rhs: Vec4A<i32>) -> Vec4A<i32> {
+            Vector(_mm_sub_epi32(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_wrapping_mul(vector: Vec4A<i32>, rhs: Vec4A<i32>) -> Vec4A<i32> {
+            Vector(mul(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_wrapping_div(vector: Vec4A<i32>, rhs: Vec4A<i32>) -> Vec4A<i32> {
+            Vec4A::<i32>::new(vector.x.wrapping_div(rhs.x), vector.y.wrapping_div(rhs.y), vector.z.wrapping_div(rhs.z), vector.w.wrapping_div(rhs.w))
+        }
+
+        #[inline]
+        fn vector_wrapping_rem(vector: Vec4A<i32>, rhs: Vec4A<i32>) -> Vec4A<i32> {
+            Vec4A::<i32>::new(vector.x.wrapping_rem(rhs.x), vector.y.wrapping_rem(rhs.y), vector.z.wrapping_rem(rhs.z), vector.w.wrapping_rem(rhs.w))
+        }
+    }
+}
+
+impl SignedVectorBackend<3, Aligned> for i32 {
+    safe_target_feature! {
+        #[inline]
+        fn vector_wrapping_abs(vector: Vec3A<i32>) -> Vec3A<i32> {
+            Vector(wrapping_abs(vector.0))
+        }
+
+        #[inline]
+        fn vector_signum(vector: Vec3A<i32>) -> Vec3A<i32> {
+            Vector(signum(vector.0))
+        }
+
+        #[inline]
+        fn vector_positive_mask(vector: Vec3A<i32>) -> Mask3A<i32> {
+            Mask(_mm_cmpgt_epi32(vector.0, _mm_setzero_si128()))
+        }
+
+        #[inline]
+        fn vector_negative_mask(vector: Vec3A<i32>) -> Mask3A<i32> {
+            Mask(_mm_cmplt_epi32(vector.0, _mm_setzero_si128()))
+        }
+    }
+}
+
+impl SignedVectorBackend<4, Aligned> for i32 {
+    safe_target_feature! {
+        #[inline]
+        fn vector_wrapping_abs(vector: Vec4A<i32>) -> Vec4A<i32> {
+            Vector(wrapping_abs(vector.0))
+        }
+
+        #[inline]
+        fn vector_signum(vector: Vec4A<i32>) -> Vec4A<i32> {
+            Vector(signum(vector.0))
+        }
+
+        #[inline]
+        fn vector_positive_mask(vector: Vec4A<i32>) -> Mask4A<i32> {
+            Mask(_mm_cmpgt_epi32(vector.0, _mm_setzero_si128()))
+        }
+
+        #[inline]
+        fn vector_negative_mask(vector: Vec4A<i32>) -> Mask4A<i32> {
+            Mask(_mm_cmplt_epi32(vector.0, _mm_setzero_si128()))
+        }
+    }
+}
+
+impl CastBackend<3, Aligned> for i32 {
+    safe_target_feature! {
+        #[inline]
+        fn vector_as_f32(vector: Vec3A<i32>) -> Vec3A<f32> {
+            Vector(_mm_cvtepi32_ps(vector.0))
+        }
+
+        #[inline]
+        #[cfg(not(target_feature = "avx"))]
+        fn vector_as_f64(vector: Vec3A<i32>) -> Vec3A<f64> {
+            Vector(M128dPair(
+                _mm_cvtepi32_pd(vector.0),
+                _mm_cvtepi32_pd(_mm_unpackhi_epi64(vector.0, vector.0)),
+            ))
+        }
+
+        #[inline]
+        #[cfg(target_feature = "avx")]
+        fn vector_as_f64(vector: Vec3A<i32>) -> Vec3A<f64> {
+            Vector(_mm256_cvtepi32_pd(vector.0))
+        }
+
+        #[inline]
+        fn vector_as_i32(vector: Vec3A<i32>) -> Vec3A<i32> {
+            vector
+        }
+
+        #[inline]
+        fn vector_as_u32(vector: Vec3A<i32>) -> Vec3A<u32> {
+            Vector(vector.0)
+        }
+    }
+}
+
+impl CastBackend<4, Aligned> for i32 {
+    safe_target_feature! {
+        #[inline]
+        fn vector_as_f32(vector: Vec4A<i32>) -> Vec4A<f32> {
+            Vector(_mm_cvtepi32_ps(vector.0))
+        }
+
+        #[inline]
+        #[cfg(not(target_feature = "avx"))]
+        fn vector_as_f64(vector: Vec4A<i32>) -> Vec4A<f64> {
+            Vector(M128dPair(
+                _mm_cvtepi32_pd(vector.0),
+                _mm_cvtepi32_pd(_mm_unpackhi_epi64(vector.0, vector.0)),
+            ))
+        }
+
+        #[inline]
+        #[cfg(target_feature = "avx")]
+        fn vector_as_f64(vector: Vec4A<i32>) -> Vec4A<f64> {
+            Vector(_mm256_cvtepi32_pd(vector.0))
+        }
+
+        #[inline]
+        fn vector_as_i32(vector: Vec4A<i32>) -> Vec4A<i32> {
+            vector
+        }
+
+        #[inline]
+        fn vector_as_u32(vector: Vec4A<i32>) -> Vec4A<u32> {
+            Vector(vector.0)
+        }
+    }
+}
+
+safe_target_feature! {
+    #[inline]
+    fn movemask(vector: __m128i) -> i32 {
+        _mm_movemask_ps(_mm_castsi128_ps(vector))
+    }
+
+    #[inline]
+    fn not(vector: __m128i) -> __m128i {
+        _mm_xor_si128(vector, _mm_set1_epi32(-1))
+    }
+
+    #[cfg(not(target_feature = "sse4.1"))]
+    #[inline]
+    fn mul(vector: __m128i, rhs: __m128i) -> __m128i {
+        // `_mm_mul_epu32` multiplies the even elements into 64-bit results.
+        // The low 32 bits are the same for signed and unsigned multiplication.
+        let even = _mm_mul_epu32(vector, rhs);
+        let odd = _mm_mul_epu32(_mm_srli_epi64::<32>(vector), _mm_srli_epi64::<32>(rhs));
+        _mm_unpacklo_epi32(
+            _mm_shuffle_epi32::<0b00_00_10_00>(even),
+            _mm_shuffle_epi32::<0b00_00_10_00>(odd),
+        )
+    }
+
+    #[cfg(target_feature = "sse4.1")]
+    #[inline]
+    fn mul(vector: __m128i, rhs: __m128i) -> __m128i {
+        _mm_mullo_epi32(vector, rhs)
+    }
+
+    #[cfg(target_feature = "avx2")]
+    #[inline]
+    fn shift_amount(rhs: __m128i) -> __m128i {
+        // Matches the scalar shift operators without overflow checks, which
+        // only use the low 5 bits of the shift amount.
+        _mm_and_si128(rhs, _mm_set1_epi32(31))
+    }
+
+    #[cfg(not(target_feature = "sse4.1"))]
+    #[inline]
+    fn select(mask: __m128i, if_true: __m128i, if_false: __m128i) -> __m128i {
+        _mm_or_si128(_mm_and_si128(mask, if_true), _mm_andnot_si128(mask, if_false))
+    }
+
+    #[cfg(target_feature = "sse4.1")]
+    #[inline]
+    fn select(mask: __m128i, if_true: __m128i, if_false: __m128i) -> __m128i {
+        _mm_blendv_epi8(if_false, if_true, mask)
+    }
+
+    #[cfg(not(target_feature = "sse4.1"))]
+    #[inline]
+    fn max(vector: __m128i, other: __m128i) -> __m128i {
+        select(_mm_cmpgt_epi32(vector, other), vector, other)
+    }
+
+    #[cfg(target_feature = "sse4.1")]
+    #[inline]
+    fn max(vector: __m128i, other: __m128i) -> __m128i {
+        _mm_max_epi32(vector, other)
+    }
+
+    #[cfg(not(target_feature = "sse4.1"))]
+    #[inline]
+    fn min(vector: __m128i, other: __m128i) -> __m128i {
+        select(_mm_cmplt_epi32(vector, other), vector, other)
+    }
+
+    #[cfg(target_feature = "sse4.1")]
+    #[inline]
+    fn min(vector: __m128i, other: __m128i) -> __m128i {
+        _mm_min_epi32(vector, other)
+    }
+
+    #[inline]
+    fn max_element(vector: __m128i) -> i32 {
+        let vector = max(vector, _mm_shuffle_epi32::<0b01_00_11_10>(vector));
+        let vector = max(vector, _mm_shuffle_epi32::<0b10_11_00_01>(vector));
+        _mm_cvtsi128_si32(vector) as i32
+    }
+
+    #[inline]
+    fn min_element(vector: __m128i) -> i32 {
+        let vector = min(vector, _mm_shuffle_epi32::<0b01_00_11_10>(vector));
+        let vector = min(vector, _mm_shuffle_epi32::<0b10_11_00_01>(vector));
+        _mm_cvtsi128_si32(vector) as i32
+    }
+
+    #[inline]
+    fn saturating_add(vector: __m128i, rhs: __m128i) -> __m128i {
+        let result = _mm_add_epi32(vector, rhs);
+        // Overflow happened if both operands have a different sign than the
+        // result.
+        let overflow = _mm_srai_epi32::<31>(_mm_and_si128(
+            _mm_xor_si128(vector, result),
+            _mm_xor_si128(rhs, result),
+        ));
+        // `MIN` for negative operands and `MAX` for positive operands.
+        let saturated = _mm_xor_si128(_mm_srai_epi32::<31>(vector), _mm_set1_epi32(i32::MAX));
+
+        select(overflow, saturated, result)
+    }
+
+    #[inline]
+    fn saturating_sub(vector: __m128i, rhs: __m128i) -> __m128i {
+        let result = _mm_sub_epi32(vector, rhs);
+        // Overflow happened if the operands have different signs and the
+        // result has a different sign than `vector`.
+        let overflow = _mm_srai_epi32::<31>(_mm_and_si128(
+            _mm_xor_si128(vector, rhs),
+            _mm_xor_si128(vector, result),
+        ));
+        // `MIN` for negative operands and `MAX` for positive operands.
+        let saturated = _mm_xor_si128(_mm_srai_epi32::<31>(vector), _mm_set1_epi32(i32::MAX));
+
+        select(overflow, saturated, result)
+    }
+
+    #[cfg(not(target_feature = "ssse3"))]
+    #[inline]
+    fn wrapping_abs(vector: __m128i) -> __m128i {
+        let sign = _mm_srai_epi32::<31>(vector);
+        _mm_sub_epi32(_mm_xor_si128(vector, sign), sign)
+    }
+
+    #[cfg(target_feature = "ssse3")]
+    #[inline]
+    fn wrapping_abs(vector: __m128i) -> __m128i {
+        _mm_abs_epi32(vector)
+    }
+
+    #[cfg(not(target_feature = "ssse3"))]
+    #[inline]
+    fn signum(vector: __m128i) -> __m128i {
+        // `-1` for negative values, and `1` for positive values because their
+        // negation is negative. `MIN` is handled by the first operand.
+        _mm_or_si128(
+            _mm_srai_epi32::<31>(vector),
+            _mm_srli_epi32::<31>(_mm_sub_epi32(_mm_setzero_si128(), vector)),
+        )
+    }
+
+    #[cfg(target_feature = "ssse3")]
+    #[inline]
+    fn signum(vector: __m128i) -> __m128i {
+        _mm_sign_epi32(_mm_set1_epi32(1), vector)
+    }
+}
@@ -0,0 +1,983 @@
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+#[cfg(not(target_feature = "avx"))]
+use super::f64::M128dPair;
+use crate::{
+    Aligned, Mask, Mask3A, Mask4A, Mat3A, Mat4A, QuatA, Vec3A, Vec4A, Vector,
+    backend::{
+        CastBackend, IntegerVectorBackend, MaskBackend, MatrixBackend, QuaternionBackend,
+        VectorBackend, overflow_check,
+    },
+    utils::safe_target_feature,
+};
+
+// `Self::Inner` follows its requirements.
+unsafe impl VectorBackend<3, Aligned> for u32 {
+    type Inner = __m128i;
+
+    safe_target_feature! {
+        #[inline]
+        fn vector_eq(vector: &Vec3A<u32>, other: &Vec3A<u32>) -> bool {
+            movemask(_mm_cmpeq_epi32(vector.0, other.0)) & 0b111 == 0b111
+        }
+
+        #[inline]
+        fn vector_ne(vector: &Vec3A<u32>, other: &Vec3A<u32>) -> bool {
+            movemask(_mm_cmpeq_epi32(vector.0, other.0)) & 0b111 != 0b111
+        }
+
+        #[inline]
+        fn vector_neg(_vector: Vec3A<u32>) -> Vec3A<u32> {
+            unreachable!("`u32` does not implement `Neg`")
+        }
+
+        #[inline]
+        fn vector_not(vector: Vec3A<u32>) -> Vec3A<u32> {
+            Vector(not(vector.0))
+        }
+
+        #[inline]
+        fn vector_add(vector: Vec3A<u32>, rhs: Vec3A<u32>) -> Vec3A<u32> {
+            overflow_check::<3, _>(|i| vector[i] + rhs[i]);
+            Vector(_mm_add_epi32(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_sub(vector: Vec3A<u32>, rhs: Vec3A<u32>) -> Vec3A<u32> {
+            overflow_check::<3, _>(|i| vector[i] - rhs[i]);
+            Vector(_mm_sub_epi32(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_mul(vector: Vec3A<u32>, rhs: Vec3A<u32>) -> Vec3A<u32> {
+            overflow_check::<3, _>(|i| vector[i] * rhs[i]);
+            Vector(mul(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_div(vector: Vec3A<u32>, rhs: Vec3A<u32>) -> Vec3A<u32> {
+            Vec3A::<u32>::new(vector.x / rhs.x, vector.y / rhs.y, vector.z / rhs.z)
+        }
+
+        #[inline]
+        fn vector_rem(vector: Vec3A<u32>, rhs: Vec3A<u32>) -> Vec3A<u32> {
+            Vec3A::<u32>::new(vector.x % rhs.x, vector.y % rhs.y, vector.z % rhs.z)
+        }
+
+        #[cfg(not(target_feature = "avx2"))]
+        #[inline]
+        fn vector_shl(vector: Vec3A<u32>, rhs: Vec3A<u32>) -> Vec3A<u32> {
+            Vec3A::<u32>::new(vector.x << rhs.x, vector.y << rhs.y, vector.z << rhs.z)
+        }
+
+        #[cfg(target_feature = "avx2")]
+        #[inline]
+        fn vector_shl(vector: Vec3A<u32>, rhs: Vec3A<u32>) -> Vec3A<u32> {
+            overflow_check::<3, _>(|i| vector[i] << rhs[i]);
+            Vector(_mm_sllv_epi32(vector.0, shift_amount(rhs.0)))
+        }
+
+        #[cfg(not(target_feature = "avx2"))]
+        #[inline]
+        fn vector_shr(vector: Vec3A<u32>, rhs: Vec3A<u32>) -> Vec3A<u32> {
+            Vec3A::<u32>::new(vector.x >> rhs.x, vector.y >> rhs.y, vector.z >> rhs.z)
+        }
+
+        #[cfg(target_feature = "avx2")]
+        #[inline]
+        fn vector_shr(vector: Vec3A<u32>, rhs: Vec3A<u32>) -> Vec3A<u32> {
+            overflow_check::<3, _>(|i| vector[i] >> rhs[i]);
+            Vector(_mm_srlv_epi32(vector.0, shift_amount(rhs.0)))
+        }
+
+        #[inline]
+        fn vector_bitand(vector: Vec3A<u32>, rhs: Vec3A<u32>) -> Vec3A<u32> {
+            Vector(_mm_and_si128(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_bitor(vector: Vec3A<u32>, rhs: Vec3A<u32>) -> Vec3A<u32> {
+            Vector(_mm_or_si128(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_bitxor(vector: Vec3A<u32>, rhs: Vec3A<u32>) -> Vec3A<u32> {
+            Vector(_mm_xor_si128(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_element_sum(vector: Vec3A<u32>) -> u32 {
+            vector.x + vector.y + vector.z
+        }
+
+        #[inline]
+        fn vector_element_product(vector: Vec3A<u32>) -> u32 {
+            vector.x * vector.y * vector.z
+        }
+
+        #[inline]
+        fn vector_dot(vector: Vec3A<u32>, rhs: Vec3A<u32>) -> u32 {
+            (vector * rhs).element_sum()
+        }
+
+        #[inline]
+        fn vector_mul_add_contracted(vector: Vec3A<u32>, a: Vec3A<u32>, b: Vec3A<u32>) -> Vec3A<u32> {
+            vector * a + b
+        }
+
+        #[inline]
+        fn vector_eq_mask(vector: Vec3A<u32>, other: Vec3A<u32>) -> Mask3A<u32> {
+            Mask(_mm_cmpeq_epi32(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_ne_mask(vector: Vec3A<u32>, other: Vec3A<u32>) -> Mask3A<u32> {
+            Mask(not(_mm_cmpeq_epi32(vector.0, other.0)))
+        }
+
+        #[inline]
+        fn vector_lt_mask(vector: Vec3A<u32>, other: Vec3A<u32>) -> Mask3A<u32> {
+            Mask(unsigned_lt(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_gt_mask(vector: Vec3A<u32>, other: Vec3A<u32>) -> Mask3A<u32> {
+            Mask(unsigned_gt(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_le_mask(vector: Vec3A<u32>, other: Vec3A<u32>) -> Mask3A<u32> {
+            Mask(not(unsigned_gt(vector.0, other.0)))
+        }
+
+        #[inline]
+        fn vector_ge_mask(vector: Vec3A<u32>, other: Vec3A<u32>) -> Mask3A<u32> {
+            Mask(not(unsigned_lt(vector.0, other.0)))
+        }
+    }
+}
+
+// `Self::Inner` follows its requirements.
+unsafe impl VectorBackend<4, Aligned> for u32 {
+    type Inner = __m128i;
+
+    safe_target_feature! {
+        #[inline]
+        fn vector_eq(vector: &Vec4A<u32>, other: &Vec4A<u32>) -> bool {
+            movemask(_mm_cmpeq_epi32(vector.0, other.0)) & 0b1111 == 0b1111
+        }
+
+        #[inline]
+        fn vector_ne(vector: &Vec4A<u32>, other: &Vec4A<u32>) -> bool {
+            movemask(_mm_cmpeq_epi32(vector.0, other.0)) & 0b1111 != 0b1111
+        }
+
+        #[inline]
+        fn vector_neg(_vector: Vec4A<u32>) -> Vec4A<u32> {
+            unreachable!("`u32` does not implement `Neg`")
+        }
+
+        #[inline]
+        fn vector_not(vector: Vec4A<u32>) -> Vec4A<u32> {
+            Vector(not(vector.0))
+        }
+
+        #[inline]
+        fn vector_add(vector: Vec4A<u32>, rhs: Vec4A<u32>) -> Vec4A<u32> {
+            overflow_check::<4, _>(|i| vector[i] + rhs[i]);
+            Vector(_mm_add_epi32(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_sub(vector: Vec4A<u32>, rhs: Vec4A<u32>) -> Vec4A<u32> {
+            overflow_check::<4, _>(|i| vector[i] - rhs[i]);
+            Vector(_mm_sub_epi32(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_mul(vector: Vec4A<u32>, rhs: Vec4A<u32>) -> Vec4A<u32> {
+            overflow_check::<4, _>(|i| vector[i] * rhs[i]);
+            Vector(mul(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_div(vector: Vec4A<u32>, rhs: Vec4A<u32>) -> Vec4A<u32> {
+            Vec4A::<u32>::new(vector.x / rhs.x, vector.y / rhs.y, vector.z / rhs.z, vector.w / rhs.w)
+        }
+
+        #[inline]
+        fn vector_rem(vector: Vec4A<u32>, rhs: Vec4A<u32>) -> Vec4A<u32> {
+            Vec4A::<u32>::new(vector.x % rhs.x, vector.y % rhs.y, vector.z % rhs.z, vector.w % rhs.w)
+        }
+
+        #[cfg(not(target_feature = "avx2"))]
+        #[inline]
+        fn vector_shl(vector: Vec4A<u32>, rhs: Vec4A<u32>) -> Vec4A<u32> {
+            Vec4A::<u32>::new(vector.x << rhs.x, vector.y << rhs.y, vector.z << rhs.z, vector.w << rhs.w)
+        }
+
+        #[cfg(target_feature = "avx2")]
+        #[inline]
+        fn vector_shl(vector: Vec4A<u32>, rhs: Vec4A<u32>) -> Vec4A<u32> {
+            overflow_check::<4, _>(|i| vector[i] << rhs[i]);
+            Vector(_mm_sllv_epi32(vector.0, shift_amount(rhs.0)))
+        }
+
+        #[cfg(not(target_feature = "avx2"))]
+        #[inline]
+        fn vector_shr(vector: Vec4A<u32>, rhs: Vec4A<u32>) -> Vec4A<u32> {
+            Vec4A::<u32>::new(vector.x >> rhs.x, vector.y >> rhs.y, vector.z >> rhs.z, vector.w >> rhs.w)
+        }
+
+        #[cfg(target_feature = "avx2")]
+        #[inline]
+        fn vector_shr(vector: Vec4A<u32>, rhs: Vec4A<u32>) -> Vec4A<u32> {
+            overflow_check::<4, _>(|i| vector[i] >> rhs[i]);
+            Vector(_mm_srlv_epi32(vector.0, shift_amount(rhs.0)))
+        }
+
+        #[inline]
+        fn vector_bitand(vector: Vec4A<u32>, rhs: Vec4A<u32>) -> Vec4A<u32> {
+            Vector(_mm_and_si128(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_bitor(vector: Vec4A<u32>, rhs: Vec4A<u32>) -> Vec4A<u32> {
+            Vector(_mm_or_si128(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_bitxor(vector: Vec4A<u32>, rhs: Vec4A<u32>) -> Vec4A<u32> {
+            Vector(_mm_xor_si128(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_element_sum(vector: Vec4A<u32>) -> u32 {
+            vector.x + vector.y + (vector.z + vector.w)
+        }
+
+        #[inline]
+        fn vector_element_product(vector: Vec4A<u32>) -> u32 {
+            vector.x * vector.y * (vector.z * vector.w)
+        }
+
+        #[inline]
+        fn vector_dot(vector: Vec4A<u32>, rhs: Vec4A<u32>) -> u32 {
+            (vector * rhs).element_sum()
+        }
+
+        #[inline]
+        fn vector_mul_add_contracted(vector: Vec4A<u32>, a: Vec4A<u32>, b: Vec4A<u32>) -> Vec4A<u32> {
+            vector * a + b
+        }
+
+        #[inline]
+        fn vector_eq_mask(vector: Vec4A<u32>, other: Vec4A<u32>) -> Mask4A<u32> {
+            Mask(_mm_cmpeq_epi32(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_ne_mask(vector: Vec4A<u32>, other: Vec4A<u32>) -> Mask4A<u32> {
+            Mask(not(_mm_cmpeq_epi32(vector.0, other.0)))
+        }
+
+        #[inline]
+        fn vector_lt_mask(vector: Vec4A<u32>, other: Vec4A<u32>) -> Mask4A<u32> {
+            Mask(unsigned_lt(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_gt_mask(vector: Vec4A<u32>, other: Vec4A<u32>) -> Mask4A<u32> {
+            Mask(unsigned_gt(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_le_mask(vector: Vec4A<u32>, other: Vec4A<u32>) -> Mask4A<u32> {
+            Mask(not(unsigned_gt(vector.0, other.0)))
+        }
+
+        #[inline]
+        fn vector_ge_mask(vector: Vec4A<u32>, other: Vec4A<u32>) -> Mask4A<u32> {
+            Mask(not(unsigned_lt(vector.0, other.0)))
+        }
+    }
+}
+
+impl QuaternionBackend<Aligned> for u32 {
+    #[inline]
+    fn quat_mul(_quat: QuatA<u32>, _rhs: QuatA<u32>) -> QuatA<u32> {
+        unreachable!("`u32` does not implement `Neg`")
+    }
+}
+
+impl MatrixBackend<3, Aligned> for u32 {
+    #[inline]
+    fn matrix_transpose(matrix: &Mat3A<u32>) -> Mat3A<u32> {
+        matrix.transpose_generic()
+    }
+
+    #[inline]
+    fn matrix_mul(matrix: &Mat3A<u32>, rhs: &Mat3A<u32>) -> Mat3A<u32> {
+        matrix.mul_generic(rhs)
+    }
+
+    #[inline]
+    fn matrix_determinant(_matrix: &Mat3A<u32>) -> u32 {
+        unreachable!("`u32` does not implement `Neg`")
+    }
+}
+
+impl MatrixBackend<4, Aligned> for u32 {
+    #[inline]
+    fn matrix_transpose(matrix: &Mat4A<u32>) -> Mat4A<u32> {
+        matrix.transpose_generic()
+    }
+
+    #[inline]
+    fn matrix_mul(matrix: &Mat4A<u32>, rhs: &Mat4A<u32>) -> Mat4A<u32> {
+        matrix.mul_generic(rhs)
+    }
+
+    #[inline]
+    fn matrix_determinant(_matrix: &Mat4A<u32>) -> u32 {
+        unreachable!("`u32` does not implement `Neg`")
+    }
+}
+
+// `Self::Inner` follows its requirements.
+unsafe impl MaskBackend<3, Aligned> for u32 {
+    type Inner = __m128i;
+
+    safe_target_feature! {
+        #[inline]
+        fn mask_from_array(array: [bool; 3]) -> Mask3A<u32> {
+            Mask(_mm_set_epi32(-(array[2] as i32), -(array[2] as i32), -(array[1] as i32), -(array[0] as i32)))
+        }
+
+        #[inline]
+        fn mask_splat(value: bool) -> Mask3A<u32> {
+            Mask(_mm_set1_epi32(-(value as i32)))
+        }
+
+        #[inline]
+        fn mask_to_array(mask: Mask3A<u32>) -> [bool; 3] {
+            let bits = movemask(mask.0);
+            [bits & 0x1 != 0, bits & 0x2 != 0, bits & 0x4 != 0]
+        }
+
+        #[inline]
+        fn mask_all(mask: Mask3A<u32>) -> bool {
+            movemask(mask.0) & 0b111 == 0b111
+        }
+
+        #[inline]
+        fn mask_any(mask: Mask3A<u32>) -> bool {
+            movemask(mask.0) & 0b111 != 0
+        }
+
+        #[inline]
+        fn mask_select(mask: Mask3A<u32>, if_true: Vec3A<u32>, if_false: Vec3A<u32>) -> Vec3A<u32> {
+            Vector(select(mask.0, if_true.0, if_false.0))
+        }
+
+        #[inline]
+        fn mask_get(mask: Mask3A<u32>, index: usize) -> bool {
+            match index {
+                0 => movemask(mask.0) & 0x1 != 0,
+                1 => movemask(mask.0) & 0x2 != 0,
+                2 => movemask(mask.0) & 0x4 != 0,
+                _ => panic!("index out of bounds"),
+            }
+        }
+
+        #[inline]
+        fn mask_set(mask: &mut Mask3A<u32>, index: usize, value: bool) {
+            if index < 3 {
+                // SAFETY: `*mut __m128i` is valid as `*mut i32` for 4 values. Adding
+                // `index` is valid because it was just checked to be less then 3,
+                // and the result is a pointer to a valid `i32`.
+                let slot = unsafe {
+                    core::ptr::from_mut::<__m128i>(&mut mask.0)
+                        .cast::<i32>()
+                        .add(index)
+                        .as_mut()
+                        .unwrap_unchecked()
+                };
+
+                *slot = -(value as i32);
+            } else {
+                panic!("index out of bounds")
+            }
+        }
+
+        #[inline]
+        fn mask_eq(mask: &Mask3A<u32>, other: &Mask3A<u32>) -> bool {
+            movemask(mask.0) & 0b111 == movemask(other.0) & 0b111
+        }
+
+        #[inline]
+        fn mask_ne(mask: &Mask3A<u32>, other: &Mask3A<u32>) -> bool {
+            !(mask == other)
+        }
+
+        #[inline]
+        fn mask_not(mask: Mask3A<u32>) -> Mask3A<u32> {
+            Mask(not(mask.0))
+        }
+
+        #[inline]
+        fn mask_bitand(mask: Mask3A<u32>, rhs: Mask3A<u32>) -> Mask3A<u32> {
+            Mask(_mm_and_si128(mask.0, rhs.0))
+        }
+
+        #[inline]
+        fn mask_bitor(mask: Mask3A<u32>, rhs: Mask3A<u32>) -> Mask3A<u32> {
+            Mask(_mm_or_si128(mask.0, rhs.0))
+        }
+
+        #[inline]
+        fn mask_bitxor(mask: Mask3A<u32>, rhs: Mask3A<u32>) -> Mask3A<u32> {
+            Mask(_mm_xor_si128(mask.0, rhs.0))
+        }
+    }
+}
+
+// `Self::Inner` follows its requirements.
+unsafe impl MaskBackend<4, Aligned> for u32 {
+    type Inner = __m128i;
+
+    safe_target_feature! {
+        #[inline]
+        fn mask_from_array(array: [bool; 4]) -> Mask4A<u32> {
+            Mask(_mm_set_epi32(-(array[3] as i32), -(array[2] as i32), -(array[1] as i32), -(array[0] as i32)))
+        }
+
+        #[inline]
+        fn mask_splat(value: bool) -> Mask4A<u32> {
+            Mask(_mm_set1_epi32(-(value as i32)))
+        }
+
+        #[inline]
+        fn mask_to_array(mask: Mask4A<u32>) -> [bool; 4] {
+            let bits = movemask(mask.0);
+            [bits & 0x1 != 0, bits & 0x2 != 0, bits & 0x4 != 0, bits & 0x8 != 0]
+        }
+
+        #[inline]
+        fn mask_all(mask: Mask4A<u32>) -> bool {
+            movemask(mask.0) & 0b1111 == 0b1111
+        }
+
+        #[inline]
+        fn mask_any(mask: Mask4A<u32>) -> bool {
+            movemask(mask.0) & 0b1111 != 0
+        }
+
+        #[inline]
+        fn mask_select(mask: Mask4A<u32>, if_true: Vec4A<u32>, if_false: Vec4A<u32>) -> Vec4A<u32> {
+            Vector(select(mask.0, if_true.0, if_false.0))
+        }
+
+        #[inline]
+        fn mask_get(mask: Mask4A<u32>, index: usize) -> bool {
+            match index {
+                0 => movemask(mask.0) & 0x1 != 0,
+                1 => movemask(mask.0) & 0x2 != 0,
+                2 => movemask(mask.0) & 0x4 != 0,
+                3 => movemask(mask.0) & 0x8 != 0,
+                _ => panic!("index out of bounds"),
+            }
+        }
+
+        #[inline]
+        fn mask_set(mask: &mut Mask4A<u32>, index: usize, value: bool) {
+            if index < 4 {
+                // SAFETY: `*mut __m128i` is valid as `*mut i32` for 4 values. Adding
+                // `index` is valid because it was just checked to be less then 4,
+                // and the result is a pointer to a valid `i32`.
+                let slot = unsafe {
+                    core::ptr::from_mut::<__m128i>(&mut mask.0)
+                        .cast::<i32>()
+                        .add(index)
+                        .as_mut()
+                        .unwrap_unchecked()
+                };
+
+                *slot = -(value as i32);
+            } else {
+                panic!("index out of bounds")
+            }
+        }
+
+        #[inline]
+        fn mask_eq(mask: &Mask4A<u32>, other: &Mask4A<u32>) -> bool {
+            movemask(mask.0) & 0b1111 == movemask(other.0) & 0b1111
+        }
+
+        #[inline]
+        fn mask_ne(mask: &Mask4A<u32>, other: &Mask4A<u32>) -> bool {
+            !(mask == other)
+        }
+
+        #[inline]
+        fn mask_not(mask: Mask4A<u32>) -> Mask4A<u32> {
+            Mask(not(mask.0))
+        }
+
+        #[inline]
+        fn mask_bitand(mask: Mask4A<u32>, rhs: Mask4A<u32>) -> Mask4A<u32> {
+            Mask(_mm_and_si128(mask.0, rhs.0))
+        }
+
+        #[inline]
+        fn mask_bitor(mask: Mask4A<u32>, rhs: Mask4A<u32>) -> Mask4A<u32> {
+            Mask(_mm_or_si128(mask.0, rhs.0))
+        }
+
+        #[inline]
+        fn mask_bitxor(mask: Mask4A<u32>, rhs: Mask4A<u32>) -> Mask4A<u32> {
+            Mask(_mm_xor_si128(mask.0, rhs.0))
+        }
+    }
+}
+
+impl IntegerVectorBackend<3, Aligned> for u32 {
+    safe_target_feature! {
+        #[inline]
+        fn vector_max(vector: Vec3A<u32>, other: Vec3A<u32>) -> Vec3A<u32> {
+            Vector(max(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_min(vector: Vec3A<u32>, other: Vec3A<u32>) -> Vec3A<u32> {
+            Vector(min(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_max_element(vector: Vec3A<u32>) -> u32 {
+            max_element(_mm_shuffle_epi32::<0b10_10_01_00>(vector.0))
+        }
+
+        #[inline]
+        fn vector_min_element(vector: Vec3A<u32>) -> u32 {
+            min_element(_mm_shuffle_epi32::<0b10_10_01_00>(vector.0))
+        }
+
+        #[inline]
+        fn vector_checked_add(vector: Vec3A<u32>, rhs: Vec3A<u32>) -> Option<Vec3A<u32>> {
+            let result = _mm_add_epi32(vector.0, rhs.0);
+            let overflow = unsigned_lt(result, vector.0);
+
+            if movemask(overflow) & 0b111 == 0 {
+                Some(Vector(result))
+            } else {
+                None
+            }
+        }
+
+        #[inline]
+        fn vector_checked_sub(vector: Vec3A<u32>, rhs: Vec3A<u32>) -> Option<Vec3A<u32>> {
+            let result = _mm_sub_epi32(vector.0, rhs.0);
+            let overflow = unsigned_lt(vector.0, rhs.0);
+
+            if movemask(overflow) & 0b111 == 0 {
+                Some(Vector(result))
+            } else {
+                None
+            }
+        }
+
+        #[inline]
+        fn vector_checked_mul(vector: Vec3A<u32>, rhs: Vec3A<u32>) -> Option<Vec3A<u32>> {
+            Some(Vec3A::<u32>::new(vector.x.checked_mul(rhs.x)?, vector.y.checked_mul(rhs.y)?, vector.z.checked_mul(rhs.z)?))
+        }
+
+        #[inline]
+        fn vector_checked_div(vector: Vec3A<u32>, rhs: Vec3A<u32>) -> Option<Vec3A<u32>> {
+            Some(Vec3A::<u32>::new(vector.x.checked_div(rhs.x)?, vector.y.checked_div(rhs.y)?, vector.z.checked_div(rhs.z)?))
+        }
+
+        #[inline]
+        fn vector_checked_rem(vector: Vec3A<u32>, rhs: Vec3A<u32>) -> Option<Vec3A<u32>> {
+            Some(Vec3A::<u32>::new(vector.x.checked_rem(rhs.x)?, vector.y.checked_rem(rhs.y)?, vector.z.checked_rem(rhs.z)?))
+        }
+
+        #[inline]
+        fn vector_saturating_add(vector: Vec3A<u32>, rhs: Vec3A<u32>) -> Vec3A<u32> {
+            Vector(saturating_add(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_saturating_sub(vector: Vec3A<u32>, rhs: Vec3A<u32>) -> Vec3A<u32> {
+            Vector(saturating_sub(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_saturating_mul(vector: Vec3A<u32>, rhs: Vec3A<u32>) -> Vec3A<u32> {
+            Vec3A::<u32>::new(vector.x.saturating_mul(rhs.x), vector.y.saturating_mul(rhs.y), vector.z.saturating_mul(rhs.z))
+        }
+
+        #[inline]
+        fn vector_saturating_div(vector: Vec3A<u32>, rhs: Vec3A<u32>) -> Vec3A<u32> {
+            Vec3A::<u32>::new(vector.x.saturating_div(rhs.x), vector.y.saturating_div(rhs.y), vector.z.saturating_div(rhs.z))
+        }
+
+        #[inline]
+        fn vector_wrapping_add(vector: Vec3A<u32>, rhs: Vec3A<u32>) -> Vec3A<u32> {
+            Vector(_mm_add_epi32(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_wrapping_sub(vector: Vec3A<u32>, rhs: Vec3A<u32>) -> Vec3A<u32> {
+            Vector(_mm_sub_epi32(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_wrapping_mul(vector: Vec3A<u32>, rhs: Vec3A<u32>) -> Vec3A<u32> {
+            Vector(mul(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_wrapping_div(vector: Vec3A<u32>, rhs: Vec3A<u32>) -> Vec3A<u32> {
+            Vec3A::<u32>::new(vector.x.wrapping_div(rhs.x), vector.y.wrapping_div(rhs.y), vector.z.wrapping_div(rhs.z))
+        }
+
+        #[inline]
+        fn vector_wrapping_rem(vector: Vec3A<u32>, rhs: Vec3A<u32>) -> Vec3A<u32> {
+            Vec3A::<u32>::new(vector.x.wrapping_rem(rhs.x), vector.y.wrapping_rem(rhs.y), vector.z.wrapping_rem(rhs.z))
+        }
+    }
+}
+
+impl IntegerVectorBackend<4, Aligned> for u32 {
+    safe_target_feature! {
+        #[inline]
+        fn vector_max(vector: Vec4A<u32>, other: Vec4A<u32>) -> Vec4A<u32> {
+            Vector(max(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_min(vector: Vec4A<u32>, other: Vec4A<u32>) -> Vec4A<u32> {
+            Vector(min(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_max_element(vector: Vec4A<u32>) -> u32 {
+            max_element(vector.0)
+        }
+
+        #[inline]
+        fn vector_min_element(vector: Vec4A<u32>) -> u32 {
+            min_element(vector.0)
+        }
+
+        #[inline]
+        fn vector_checked_add(vector: Vec4A<u32>, rhs: Vec4A<u32>) -> Option<Vec4A<u32>> {
+            let result = _mm_add_epi32(vector.0, rhs.0);
+            let overflow = unsigned_lt(result, vector.0);
+
+            if movemask(overflow) & 0b1111 == 0 {
+                Some(Vector(result))
+            } else {
+                None
+            }
+        }
+
+        #[inline]
+        fn vector_checked_sub(vector: Vec4A<u32>, rhs: Vec4A<u32>) -> Option<Vec4A<u32>> {
+            let result = _mm_sub_epi32(vector.0, rhs.0);
+            let overflow = unsigned_lt(vector.0, rhs.0);
+
+            if movemask(overflow) & 0b1111 == 0 {
+                Some(Vector(result))
+            } else {
+                None
+            }
+        }
+
+        #[inline]
+        fn vector_checked_mul(vector: Vec4A<u32>, rhs: Vec4A<u32>) -> Option<Vec4A<u32>> {
+            Some(Vec4A::<u32>::new(vector.x.checked_mul(rhs.x)?, vector.y.checked_mul(rhs.y)?, vector.z.checked_mul(rhs.z)?, vector.w.checked_mul(rhs.w)?))
+        }
+
+        #[inline]
+        fn vector_checked_div(vector: Vec4A<u32>, rhs: Vec4A<u32>) -> Option<Vec4A<u32>> {
+            Some(Vec4A::<u32>::new(vector.x.checked_div(rhs.x)?, vector.y.checked_div(rhs.y)?, vector.z.checked_div(rhs.z)?, vector.w.checked_div(rhs.w)?))
+        }
+
+        #[inline]
+        fn vector_checked_rem(vector: Vec4A<u32>, rhs: Vec4A<u32>) -> Option<Vec4A<u32>> {
+            Some(Vec4A::<u32>::new(vector.x.checked_rem(rhs.x)?, vector.y.checked_rem(rhs.y)?, vector.z.checked_rem(rhs.z)?, vector.w.checked_rem(rhs.w)?))
+        }
+
+        #[inline]
+        fn vector_saturating_add(vector: Vec4A<u32>, rhs: Vec4A<u32>) -> Vec4A<u32> {
+            Vector(saturating_add(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_saturating_sub(vector: Vec4A<u32>, rhs: Vec4A<u32>) -> Vec4A<u32> {
+            Vector(saturating_sub(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_saturating_mul(vector: Vec4A<u32>, rhs: Vec4A<u32>) -> Vec4A<u32> {
+            Vec4A::<u32>::new(vector.x.saturating_mul(rhs.x), vector.y.saturating_mul(rhs.y), vector.z.saturating_mul(rhs.z), vector.w.saturating_mul(rhs.w))
+        }
+
+        #[inline]
+        fn vector_saturating_div(vector: Vec4A<u32>, rhs: Vec4A<u32>) -> Vec4A<u32> {
+            Vec4A::<u32>::new(vector.x.saturating_div(rhs.x), vector.y.saturating_div(rhs.y), vector.z.saturating_div(rhs.z), vector.w.saturating_div(rhs.w))
+        }
+
+        #[inline]
+        fn vector_wrapping_add(vector: Vec4A<u32>, rhs: Vec4A<u32>) -> Vec4A<u32> {
+            Vector(_mm_add_epi32(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_wrapping_sub(vector: Vec4A<u32>, rhs: Vec4A<u32>) -> Vec4A<u32> {
+            Vector(_mm_sub_epi32(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_wrapping_mul(vector: Vec4A<u32>, rhs: Vec4A<u32>) -> Vec4A<u32> {
+            Vector(mul(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_wrapping_div(vector: Vec4A<u32>, rhs: Vec4A<u32>) -> Vec4A<u32> {
+            Vec4A::<u32>::new(vector.x.wrapping_div(rhs.x), vector.y.wrapping_div(rhs.y), vector.z.wrapping_div(rhs.z), vector.w.wrapping_div(rhs.w))
+        }
+
+        #[inline]
+        fn vector_wrapping_rem(vector: Vec4A<u32>, rhs: Vec4A<u32>) -> Vec4A<u32> {
+            Vec4A::<u32>::new(vector.x.wrapping_rem(rhs.x), vector.y.wrapping_rem(rhs.y), vector.z.wrapping_rem(rhs.z), vector.w.wrapping_rem(rhs.w))
+        }
+    }
+}
+
+impl CastBackend<3, Aligned> for u32 {
+    safe_target_feature! {
+        #[inline]
+        fn vector_as_f32(vector: Vec3A<u32>) -> Vec3A<f32> {
+            Vector(cvt_f32(vector.0))
+        }
+
+        #[inline]
+        #[cfg(not(target_feature = "avx"))]
+        fn vector_as_f64(vector: Vec3A<u32>) -> Vec3A<f64> {
+            // Flipping the sign bit subtracts `2^31`, which is added back after
+            // the exact conversion.
+            let vector = _mm_xor_si128(vector.0, _mm_set1_epi32(i32::MIN));
+
+            Vector(M128dPair(
+                _mm_add_pd(_mm_cvtepi32_pd(vector), _mm_set1_pd(2147483648.0)),
+                _mm_add_pd(
+                    _mm_cvtepi32_pd(_mm_unpackhi_epi64(vector, vector)),
+                    _mm_set1_pd(2147483648.0),
+                ),
+            ))
+        }
+
+        #[inline]
+        #[cfg(target_feature = "avx")]
+        fn vector_as_f64(vector: Vec3A<u32>) -> Vec3A<f64> {
+            // Flipping the sign bit subtracts `2^31`, which is added back after
+            // the exact conversion.
+            let vector = _mm_xor_si128(vector.0, _mm_set1_epi32(i32::MIN));
+
+            Vector(_mm256_add_pd(
+                _mm256_cvtepi32_pd(vector),
+                _mm256_set1_pd(2147483648.0),
+            ))
+        }
+
+        #[inline]
+        fn vector_as_i32(vector: Vec3A<u32>) -> Vec3A<i32> {
+            Vector(vector.0)
+        }
+
+        #[inline]
+        fn vector_as_u32(vector: Vec3A<u32>) -> Vec3A<u32> {
+            vector
+        }
+    }
+}
+
+impl CastBackend<4, Aligned> for u32 {
+    safe_target_feature! {
+        #[inline]
+        fn vector_as_f32(vector: Vec4A<u32>) -> Vec4A<f32> {
+            Vector(cvt_f32(vector.0))
+        }
+
+        #[inline]
+        #[cfg(not(target_feature = "avx"))]
+        fn vector_as_f64(vector: Vec4A<u32>) -> Vec4A<f64> {
+            // Flipping the sign bit subtracts `2^31`, which is added back after
+            // the exact conversion.
+            let vector = _mm_xor_si128(vector.0, _mm_set1_epi32(i32::MIN));
+
+            Vector(M128dPair(
+                _mm_add_pd(_mm_cvtepi32_pd(vector), _mm_set1_pd(2147483648.0)),
+                _mm_add_pd(
+                    _mm_cvtepi32_pd(_mm_unpackhi_epi64(vector, vector)),
+                    _mm_set1_pd(2147483648.0),
+                ),
+            ))
+        }
+
+        #[inline]
+        #[cfg(target_feature = "avx")]
+        fn vector_as_f64(vector: Vec4A<u32>) -> Vec4A<f64> {
+            // Flipping the sign bit subtracts `2^31`, which is added back after
+            // the exact conversion.
+            let vector = _mm_xor_si128(vector.0, _mm_set1_epi32(i32::MIN));
+
+            Vector(_mm256_add_pd(
+                _mm256_cvtepi32_pd(vector),
+                _mm256_set1_pd(2147483648.0),
+            ))
+        }
+
+        #[inline]
+        fn vector_as_i32(vector: Vec4A<u32>) -> Vec4A<i32> {
+            Vector(vector.0)
+        }
+
+        #[inline]
+        fn vector_as_u32(vector: Vec4A<u32>) -> Vec4A<u32> {
+            vector
+        }
+    }
+}
+
+safe_target_feature! {
+    #[inline]
+    fn movemask(vector: __m128i) -> i32 {
+        _mm_movemask_ps(_mm_castsi128_ps(vector))
+    }
+
+    #[inline]
+    fn not(vector: __m128i) -> __m128i {
+        _mm_xor_si128(vector, _mm_set1_epi32(-1))
+    }
+
+    /// Compares as unsigned integers by flipping the sign bits so that signed
+    /// comparisons give the unsigned result.
+    #[inline]
+    fn unsigned_lt(vector: __m128i, other: __m128i) -> __m128i {
+        let sign = _mm_set1_epi32(i32::MIN);
+        _mm_cmplt_epi32(_mm_xor_si128(vector, sign), _mm_xor_si128(other, sign))
+    }
+
+    #[inline]
+    fn unsigned_gt(vector: __m128i, other: __m128i) -> __m128i {
+        unsigned_lt(other, vector)
+    }
+
+    #[cfg(not(target_feature = "sse4.1"))]
+    #[inline]
+    fn mul(vector: __m128i, rhs: __m128i) -> __m128i {
+        // `_mm_mul_epu32` multiplies the even elements into 64-bit results.
+        // The low 32 bits are the same for signed and unsigned multiplication.
+        let even = _mm_mul_epu32(vector, rhs);
+        let odd = _mm_mul_epu32(_mm_srli_epi64::<32>(vector), _mm_srli_epi64::<32>(rhs));
+        _mm_unpacklo_epi32(
+            _mm_shuffle_epi32::<0b00_00_10_00>(even),
+            _mm_shuffle_epi32::<0b00_00_10_00>(odd),
+        )
+    }
+
+    #[cfg(target_feature = "sse4.1")]
+    #[inline]
+    fn mul(vector: __m128i, rhs: __m128i) -> __m128i {
+        _mm_mullo_epi32(vector, rhs)
+    }
+
+    #[cfg(target_feature = "avx2")]
+    #[inline]
+    fn shift_amount(rhs: __m128i) -> __m128i {
+        // Matches the scalar shift operators without overflow checks, which
+        // only use the low 5 bits of the shift amount.
+        _mm_and_si128(rhs, _mm_set1_epi32(31))
+    }
+
+    #[cfg(not(target_feature = "sse4.1"))]
+    #[inline]
+    fn select(mask: __m128i, if_true: __m128i, if_false: __m128i) -> __m128i {
+        _mm_or_si128(_mm_and_si128(mask, if_true), _mm_andnot_si128(mask, if_false))
+    }
+
+    #[cfg(target_feature = "sse4.1")]
+    #[inline]
+    fn select(mask: __m128i, if_true: __m128i, if_false: __m128i) -> __m128i {
+        _mm_blendv_epi8(if_false, if_true, mask)
+    }
+
+    #[cfg(not(target_feature = "sse4.1"))]
+    #[inline]
+    fn max(vector: __m128i, other: __m128i) -> __m128i {
+        select(unsigned_gt(vector, other), vector, other)
+    }
+
+    #[cfg(target_feature = "sse4.1")]
+    #[inline]
+    fn max(vector: __m128i, other: __m128i) -> __m128i {
+        _mm_max_epu32(vector, other)
+    }
+
+    #[cfg(not(target_feature = "sse4.1"))]
+    #[inline]
+    fn min(vector: __m128i, other: __m128i) -> __m128i {
+        select(unsigned_lt(vector, other), vector, other)
+    }
+
+    #[cfg(target_feature = "sse4.1")]
+    #[inline]
+    fn min(vector: __m128i, other: __m128i) -> __m128i {
+        _mm_min_epu32(vector, other)
+    }
+
+    #[inline]
+    fn max_element(vector: __m128i) -> u32 {
+        let vector = max(vector, _mm_shuffle_epi32::<0b01_00_11_10>(vector));
+        let vector = max(vector, _mm_shuffle_epi32::<0b10_11_00_01>(vector));
+        _mm_cvtsi128_si32(vector) as u32
+    }
+
+    #[inline]
+    fn min_element(vector: __m128i) -> u32 {
+        let vector = min(vector, _mm_shuffle_epi32::<0b01_00_11_10>(vector));
+        let vector = min(vector, _mm_shuffle_epi32::<0b10_11_00_01>(vector));
+        _mm_cvtsi128_si32(vector) as u32
+    }
+
+    #[inline]
+    fn saturating_add(vector: __m128i, rhs: __m128i) -> __m128i {
+        let result = _mm_add_epi32(vector, rhs);
+        // Overflowing elements are set to `MAX`.
+        _mm_or_si128(result, unsigned_lt(result, vector))
+    }
+
+    #[inline]
+    fn saturating_sub(vector: __m128i, rhs: __m128i) -> __m128i {
+        let result = _mm_sub_epi32(vector, rhs);
+        // Overflowing elements are set to `0`.
+        _mm_andnot_si128(unsigned_lt(vector, rhs), result)
+    }
+
+    #[inline]
+    fn cvt_f32(vector: __m128i) -> __m128 {
+        // Both halves are converted exactly, so the addition is the only
+        // rounding step.
+        let high = _mm_cvtepi32_ps(_mm_srli_epi32::<16>(vector));
+        let low = _mm_cvtepi32_ps(_mm_and_si128(vector, _mm_set1_epi32(0xffff)));
+
+        _mm_add_ps(_mm_mul_ps(high, _mm_set1_ps(65536.0)), low)
+    }
+}
@@ -0,0 +1,41 @@
+use crate::{
+    Aligned,
+    backend::{DefaultBackend, DefaultRepr},
+};
+
+mod f32;
+
+// SAFETY: `DefaultRepr` follows the requirements of `VectorBackend::Inner`.
+unsafe impl DefaultBackend<2, Aligned> for f64 {
+    type Repr = DefaultRepr<2, f64>;
+}
+
+// SAFETY: `DefaultRepr` follows the requirements of `VectorBackend::Inner`.
+unsafe impl DefaultBackend<3, Aligned> for f64 {
+    type Repr = DefaultRepr<3, f64>;
+}
+
+// SAFETY: `DefaultRepr` follows the requirements of `VectorBackend::Inner`.
+unsafe impl DefaultBackend<4, Aligned> for f64 {
+    type Repr = DefaultRepr<4, f64>;
+}
+
+// SAFETY: `DefaultRepr` follows the requirements of `VectorBackend::Inner`.
+unsafe impl DefaultBackend<3, Aligned> for i32 {
+    type Repr = DefaultRepr<3, i32>;
+}
+
+// SAFETY: `DefaultRepr` follows the requirements of `VectorBackend::Inner`.
+unsafe impl DefaultBackend<4, Aligned> for i32 {
+    type Repr = DefaultRepr<4, i32>;
+}
+
+// SAFETY: `DefaultRepr` follows the requirements of `VectorBackend::Inner`.
+unsafe impl DefaultBackend<3, Aligned> for u32 {
+    type Repr = DefaultRepr<3, u32>;
+}
+
+// SAFETY: `DefaultRepr` follows the requirements of `VectorBackend::Inner`.
+unsafe impl DefaultBackend<4, Aligned> for u32 {
+    type Repr = DefaultRepr<4, u32>;
+}
@@ -0,0 +1,1161 @@
+use core::arch::wasm32::*;
+
+use crate::utils::PrimitiveFloatUtils;
+use crate::{
+    Aligned, Mask, Mask3A, Mask4A, Mat3A, Mat4A, QuatA, Quaternion, Vec3A, Vec4A, Vector,
+    backend::{
+        CastBackend, FloatMatrixBackend, FloatVectorBackend, MaskBackend, MatrixBackend,
+        QuaternionBackend, VectorBackend,
+    },
+    utils::safe_target_feature,
+};
+
+// `Self::Inner` follows its requirements.
+unsafe impl VectorBackend<3, Aligned> for f32 {
+    type Inner = v128;
+
+    safe_target_feature! {
+        #[inline]
+        fn vector_eq(vector: &Vec3A<f32>, other: &Vec3A<f32>) -> bool {
+            vector.eq_mask(*other).all()
+        }
+
+        #[inline]
+        fn vector_ne(vector: &Vec3A<f32>, other: &Vec3A<f32>) -> bool {
+            !(vector == other)
+        }
+
+        #[inline]
+        fn vector_neg(vector: Vec3A<f32>) -> Vec3A<f32> {
+            Vector(f32x4_neg(vector.0))
+        }
+
+        #[inline]
+        fn vector_not(vector: Vec3A<f32>) -> Vec3A<f32> {
+            Vector(v128_not(vector.0))
+        }
+
+        #[inline]
+        fn vector_add(vector: Vec3A<f32>, rhs: Vec3A<f32>) -> Vec3A<f32> {
+            Vector(f32x4_add(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_sub(vector: Vec3A<f32>, rhs: Vec3A<f32>) -> Vec3A<f32> {
+            Vector(f32x4_sub(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_mul(vector: Vec3A<f32>, rhs: Vec3A<f32>) -> Vec3A<f32> {
+            Vector(f32x4_mul(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_div(vector: Vec3A<f32>, rhs: Vec3A<f32>) -> Vec3A<f32> {
+            Vector(f32x4_div(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_rem(vector: Vec3A<f32>, rhs: Vec3A<f32>) -> Vec3A<f32> {
+            Vector(rem(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_shl(vector: Vec3A<f32>, rhs: Vec3A<f32>) -> Vec3A<f32> {
+            Vec3A::<f32>::from_bits(vector.to_bits() << rhs.to_bits())
+        }
+
+        #[inline]
+        fn vector_shr(vector: Vec3A<f32>, rhs: Vec3A<f32>) -> Vec3A<f32> {
+            Vec3A::<f32>::from_bits(vector.to_bits() >> rhs.to_bits())
+        }
+
+        #[inline]
+        fn vector_bitand(vector: Vec3A<f32>, rhs: Vec3A<f32>) -> Vec3A<f32> {
+            Vector(v128_and(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_bitor(vector: Vec3A<f32>, rhs: Vec3A<f32>) -> Vec3A<f32> {
+            Vector(v128_or(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_bitxor(vector: Vec3A<f32>, rhs: Vec3A<f32>) -> Vec3A<f32> {
+            Vector(v128_xor(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_element_sum(vector: Vec3A<f32>) -> f32 {
+            f32x4_extract_lane::<0>(vector.0)
+                + f32x4_extract_lane::<1>(vector.0)
+                + f32x4_extract_lane::<2>(vector.0)
+        }
+
+        #[inline]
+        fn vector_element_product(vector: Vec3A<f32>) -> f32 {
+            f32x4_extract_lane::<0>(vector.0)
+                * f32x4_extract_lane::<1>(vector.0)
+                * f32x4_extract_lane::<2>(vector.0)
+        }
+
+        #[inline]
+        fn vector_dot(vector: Vec3A<f32>, rhs: Vec3A<f32>) -> f32 {
+            (vector * rhs).element_sum()
+        }
+
+        #[inline]
+        fn vector_mul_add_contracted(vector: Vec3A<f32>, a: Vec3A<f32>, b: Vec3A<f32>) -> Vec3A<f32> {
+            vector * a + b
+        }
+
+        #[inline]
+        fn vector_eq_mask(vector: Vec3A<f32>, other: Vec3A<f32>) -> Mask3A<f32> {
+            Mask(f32x4_eq(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_ne_mask(vector: Vec3A<f32>, other: Vec3A<f32>) -> Mask3A<f32> {
+            Mask(f32x4_ne(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_lt_mask(vector: Vec3A<f32>, other: Vec3A<f32>) -> Mask3A<f32> {
+            Mask(f32x4_lt(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_gt_mask(vector: Vec3A<f32>, other: Vec3A<f32>) -> Mask3A<f32> {
+            Mask(f32x4_gt(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_le_mask(vector: Vec3A<f32>, other: Vec3A<f32>) -> Mask3A<f32> {
+            Mask(f32x4_le(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_ge_mask(vector: Vec3A<f32>, other: Vec3A<f32>) -> Mask3A<f32> {
+            Mask(f32x4_ge(vector.0, other.0))
+        }
+    }
+}
+
+// `Self::Inner` follows its requirements.
+unsafe impl VectorBackend<4, Aligned> for f32 {
+    type Inner = v128;
+
+    safe_target_feature! {
+        #[inline]
+        fn vector_eq(vector: &Vec4A<f32>, other: &Vec4A<f32>) -> bool {
+            vector.eq_mask(*other).all()
+        }
+
+        #[inline]
+        fn vector_ne(vector: &Vec4A<f32>, other: &Vec4A<f32>) -> bool {
+            vector.ne_mask(*other).any()
+        }
+
+        #[inline]
+        fn vector_neg(vector: Vec4A<f32>) -> Vec4A<f32> {
+            Vector(f32x4_neg(vector.0))
+        }
+
+        #[inline]
+        fn vector_not(vector: Vec4A<f32>) -> Vec4A<f32> {
+            Vector(v128_not(vector.0))
+        }
+
+        #[inline]
+        fn vector_add(vector: Vec4A<f32>, rhs: Vec4A<f32>) -> Vec4A<f32> {
+            Vector(f32x4_add(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_sub(vector: Vec4A<f32>, rhs: Vec4A<f32>) -> Vec4A<f32> {
+            Vector(f32x4_sub(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_mul(vector: Vec4A<f32>, rhs: Vec4A<f32>) -> Vec4A<f32> {
+            Vector(f32x4_mul(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_div(vector: Vec4A<f32>, rhs: Vec4A<f32>) -> Vec4A<f32> {
+            Vector(f32x4_div(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_rem(vector: Vec4A<f32>, rhs: Vec4A<f32>) -> Vec4A<f32> {
+            Vector(rem(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_shl(vector: Vec4A<f32>, rhs: Vec4A<f32>) -> Vec4A<f32> {
+            Vec4A::<f32>::from_bits(vector.to_bits() << rhs.to_bits())
+        }
+
+        #[inline]
+        fn vector_shr(vector: Vec4A<f32>, rhs: Vec4A<f32>) -> Vec4A<f32> {
+            Vec4A::<f32>::from_bits(vector.to_bits() >> rhs.to_bits())
+        }
+
+        #[inline]
+        fn vector_bitand(vector: Vec4A<f32>, rhs: Vec4A<f32>) -> Vec4A<f32> {
+            Vector(v128_and(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_bitor(vector: Vec4A<f32>, rhs: Vec4A<f32>) -> Vec4A<f32> {
+            Vector(v128_or(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_bitxor(vector: Vec4A<f32>, rhs: Vec4A<f32>) -> Vec4A<f32> {
+            Vector(v128_xor(vector.0, rhs.0))
+        }
+
+        #[inline]
+        fn vector_element_sum(vector: Vec4A<f32>) -> f32 {
+            // (a + b) + (c + d)
+            let reduce_2 = f32x4_add(vector.0, i32x4_shuffle::<1, 0, 3, 2>(vector.0, vector.0));
+            f32x4_extract_lane::<0>(reduce_2) + f32x4_extract_lane::<2>(reduce_2)
+        }
+
+        #[inline]
+        fn vector_element_product(vector: Vec4A<f32>) -> f32 {
+            // (a * b) * (c * d)
+            let reduce_2 = f32x4_mul(vector.0, i32x4_shuffle::<1, 0, 3, 2>(vector.0, vector.0));
+            f32x4_extract_lane::<0>(reduce_2) * f32x4_extract_lane::<2>(reduce_2)
+        }
+
+        #[inline]
+        fn vector_dot(vector: Vec4A<f32>, rhs: Vec4A<f32>) -> f32 {
+            (vector * rhs).element_sum()
+        }
+
+        #[inline]
+        fn vector_mul_add_contracted(vector: Vec4A<f32>, a: Vec4A<f32>, b: Vec4A<f32>) -> Vec4A<f32> {
+            vector * a + b
+        }
+
+        #[inline]
+        fn vector_eq_mask(vector: Vec4A<f32>, other: Vec4A<f32>) -> Mask4A<f32> {
+            Mask(f32x4_eq(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_ne_mask(vector: Vec4A<f32>, other: Vec4A<f32>) -> Mask4A<f32> {
+            Mask(f32x4_ne(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_lt_mask(vector: Vec4A<f32>, other: Vec4A<f32>) -> Mask4A<f32> {
+            Mask(f32x4_lt(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_gt_mask(vector: Vec4A<f32>, other: Vec4A<f32>) -> Mask4A<f32> {
+            Mask(f32x4_gt(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_le_mask(vector: Vec4A<f32>, other: Vec4A<f32>) -> Mask4A<f32> {
+            Mask(f32x4_le(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_ge_mask(vector: Vec4A<f32>, other: Vec4A<f32>) -> Mask4A<f32> {
+            Mask(f32x4_ge(vector.0, other.0))
+        }
+    }
+}
+
+impl QuaternionBackend<Aligned> for f32 {
+    #[inline]
+    fn quat_mul(quat: QuatA<f32>, rhs: QuatA<f32>) -> QuatA<f32> {
+        const PNPN: Vec4A<f32> = Vec4A::new(0.0, -0.0, 0.0, -0.0);
+        const PPNN: Vec4A<f32> = Vec4A::new(0.0, 0.0, -0.0, -0.0);
+        const NPPN: Vec4A<f32> = Vec4A::new(-0.0, 0.0, 0.0, -0.0);
+
+        Quaternion(
+            quat.0 * rhs.0.wwww()
+                + Vec4A::<f32>::from_bits(
+                    PNPN.to_bits() ^ (quat.0.wzyx() * rhs.0.xxxx()).to_bits(),
+                )
+                + Vec4A::<f32>::from_bits(
+                    PPNN.to_bits() ^ (quat.0.zwxy() * rhs.0.yyyy()).to_bits(),
+                )
+                + Vec4A::<f32>::from_bits(
+                    NPPN.to_bits() ^ (quat.0.yxwz() * rhs.0.zzzz()).to_bits(),
+                ),
+        )
+    }
+}
+
+impl MatrixBackend<3, Aligned> for f32 {
+    #[inline]
+    fn matrix_transpose(matrix: &Mat3A<f32>) -> Mat3A<f32> {
+        matrix.transpose_generic()
+    }
+
+    #[inline]
+    fn matrix_mul(matrix: &Mat3A<f32>, rhs: &Mat3A<f32>) -> Mat3A<f32> {
+        matrix.mul_generic(rhs)
+    }
+
+    #[inline]
+    fn matrix_determinant(matrix: &Mat3A<f32>) -> f32 {
+        matrix.determinant_generic()
+    }
+}
+
+impl MatrixBackend<4, Aligned> for f32 {
+    #[inline]
+    fn matrix_transpose(matrix: &Mat4A<f32>) -> Mat4A<f32> {
+        matrix.transpose_generic()
+    }
+
+    #[inline]
+    fn matrix_mul(matrix: &Mat4A<f32>, rhs: &Mat4A<f32>) -> Mat4A<f32> {
+        matrix.mul_generic(rhs)
+    }
+
+    #[inline]
+    fn matrix_determinant(matrix: &Mat4A<f32>) -> f32 {
+        matrix.determinant_generic()
+    }
+}
+
+// `Self::Inner` follows its requirements.
+unsafe impl MaskBackend<3, Aligned> for f32 {
+    type Inner = v128;
+
+    safe_target_feature! {
+        #[inline]
+        fn mask_from_array(array: [bool; 3]) -> Mask3A<f32> {
+            Mask(i32x4(
+                -(array[0] as i32),
+                -(array[1] as i32),
+                -(array[2] as i32),
+                -(array[2] as i32),
+            ))
+        }
+
+        #[inline]
+        fn mask_splat(value: bool) -> Mask3A<f32> {
+            Mask(i32x4_splat(-(value as i32)))
+        }
+
+        #[inline]
+        fn mask_to_array(mask: Mask3A<f32>) -> [bool; 3] {
+            [
+                i32x4_extract_lane::<0>(mask.0) != 0,
+                i32x4_extract_lane::<1>(mask.0) != 0,
+                i32x4_extract_lane::<2>(mask.0) != 0,
+            ]
+        }
+
+        #[inline]
+        fn mask_all(mask: Mask3A<f32>) -> bool {
+            i32x4_bitmask(mask.0) & 0b111 == 0b111
+        }
+
+        #[inline]
+        fn mask_any(mask: Mask3A<f32>) -> bool {
+            i32x4_bitmask(mask.0) & 0b111 != 0
+        }
+
+        #[inline]
+        fn mask_select(mask: Mask3A<f32>, if_true: Vec3A<f32>, if_false: Vec3A<f32>) -> Vec3A<f32> {
+            Vector(v128_bitselect(if_true.0, if_false.0, mask.0))
+        }
+
+        #[inline]
+        fn mask_get(mask: Mask3A<f32>, index: usize) -> bool {
+            match index {
+                0 => i32x4_extract_lane::<0>(mask.0) != 0,
+                1 => i32x4_extract_lane::<1>(mask.0) != 0,
+                2 => i32x4_extract_lane::<2>(mask.0) != 0,
+                _ => panic!("index out of bounds"),
+            }
+        }
+
+        #[inline]
+        fn mask_set(mask: &mut Mask3A<f32>, index: usize, value: bool) {
+            let value = -(value as i32);
+            mask.0 = match index {
+                0 => i32x4_replace_lane::<0>(mask.0, value),
+                1 => i32x4_replace_lane::<1>(mask.0, value),
+                2 => i32x4_replace_lane::<2>(mask.0, value),
+                _ => panic!("index out of bounds"),
+            };
+        }
+
+        #[inline]
+        fn mask_eq(mask: &Mask3A<f32>, other: &Mask3A<f32>) -> bool {
+            i32x4_bitmask(i32x4_eq(mask.0, other.0)) & 0b111 == 0b111
+        }
+
+        #[inline]
+        fn mask_ne(mask: &Mask3A<f32>, other: &Mask3A<f32>) -> bool {
+            !(mask == other)
+        }
+
+        #[inline]
+        fn mask_not(mask: Mask3A<f32>) -> Mask3A<f32> {
+            Mask(v128_not(mask.0))
+        }
+
+        #[inline]
+        fn mask_bitand(mask: Mask3A<f32>, rhs: Mask3A<f32>) -> Mask3A<f32> {
+            Mask(v128_and(mask.0, rhs.0))
+        }
+
+        #[inline]
+        fn mask_bitor(mask: Mask3A<f32>, rhs: Mask3A<f32>) -> Mask3A<f32> {
+            Mask(v128_or(mask.0, rhs.0))
+        }
+
+        #[inline]
+        fn mask_bitxor(mask: Mask3A<f32>, rhs: Mask3A<f32>) -> Mask3A<f32> {
+            Mask(v128_xor(mask.0, rhs.0))
+        }
+    }
+}
+
+// `Self::Inner` follows its requirements.
+unsafe impl MaskBackend<4, Aligned> for f32 {
+    type Inner = v128;
+
+    safe_target_feature! {
+        #[inline]
+        fn mask_from_array(array: [bool; 4]) -> Mask4A<f32> {
+            Mask(i32x4(
+                -(array[0] as i32),
+                -(array[1] as i32),
+                -(array[2] as i32),
+                -(array[3] as i32),
+            ))
+        }
+
+        #[inline]
+        fn mask_splat(value: bool) -> Mask4A<f32> {
+            Mask(i32x4_splat(-(value as i32)))
+        }
+
+        #[inline]
+        fn mask_to_array(mask: Mask4A<f32>) -> [bool; 4] {
+            [
+                i32x4_extract_lane::<0>(mask.0) != 0,
+                i32x4_extract_lane::<1>(mask.0) != 0,
+                i32x4_extract_lane::<2>(mask.0) != 0,
+                i32x4_extract_lane::<3>(mask.0) != 0,
+            ]
+        }
+
+        #[inline]
+        fn mask_all(mask: Mask4A<f32>) -> bool {
+            i32x4_all_true(mask.0)
+        }
+
+        #[inline]
+        fn mask_any(mask: Mask4A<f32>) -> bool {
+            v128_any_true(mask.0)
+        }
+
+        #[inline]
+        fn mask_select(mask: Mask4A<f32>, if_true: Vec4A<f32>, if_false: Vec4A<f32>) -> Vec4A<f32> {
+            Vector(v128_bitselect(if_true.0, if_false.0, mask.0))
+        }
+
+        #[inline]
+        fn mask_get(mask: Mask4A<f32>, index: usize) -> bool {
+            match index {
+                0 => i32x4_extract_lane::<0>(mask.0) != 0,
+                1 => i32x4_extract_lane::<1>(mask.0) != 0,
+                2 => i32x4_extract_lane::<2>(mask.0) != 0,
+                3 => i32x4_extract_lane::<3>(mask.0) != 0,
+                _ => panic!("index out of bounds"),
+            }
+        }
+
+        #[inline]
+        fn mask_set(mask: &mut Mask4A<f32>, index: usize, value: bool) {
+            let value = -(value as i32);
+            mask.0 = match index {
+                0 => i32x4_replace_lane::<0>(mask.0, value),
+                1 => i32x4_replace_lane::<1>(mask.0, value),
+                2 => i32x4_replace_lane::<2>(mask.0, value),
+                3 => i32x4_replace_lane::<3>(mask.0, value),
+                _ => panic!("index out of bounds"),
+            };
+        }
+
+        #[inline]
+        fn mask_eq(mask: &Mask4A<f32>, other: &Mask4A<f32>) -> bool {
+            i32x4_all_true(i32x4_eq(mask.0, other.0))
+        }
+
+        #[inline]
+        fn mask_ne(mask: &Mask4A<f32>, other: &Mask4A<f32>) -> bool {
+            !(mask == other)
+        }
+
+        #[inline]
+        fn mask_not(mask: Mask4A<f32>) -> Mask4A<f32> {
+            Mask(v128_not(mask.0))
+        }
+
+        #[inline]
+        fn mask_bitand(mask: Mask4A<f32>, rhs: Mask4A<f32>) -> Mask4A<f32> {
+            Mask(v128_and(mask.0, rhs.0))
+        }
+
+        #[inline]
+        fn mask_bitor(mask: Mask4A<f32>, rhs: Mask4A<f32>) -> Mask4A<f32> {
+            Mask(v128_or(mask.0, rhs.0))
+        }
+
+        #[inline]
+        fn mask_bitxor(mask: Mask4A<f32>, rhs: Mask4A<f32>) -> Mask4A<f32> {
+            Mask(v128_xor(mask.0, rhs.0))
+        }
+    }
+}
+
+impl FloatVectorBackend<3, Aligned> for f32 {
+    safe_target_feature! {
+        #[inline]
+        fn vector_nan_mask(vector: Vec3A<f32>) -> Mask3A<f32> {
+            Mask(nan_mask(vector.0))
+        }
+
+        #[inline]
+        fn vector_finite_mask(vector: Vec3A<f32>) -> Mask3A<f32> {
+            Mask(finite_mask(vector.0))
+        }
+
+        #[inline]
+        fn vector_sign_positive_mask(vector: Vec3A<f32>) -> Mask3A<f32> {
+            Mask(sign_positive_mask(vector.0))
+        }
+
+        #[inline]
+        fn vector_sign_negative_mask(vector: Vec3A<f32>) -> Mask3A<f32> {
+            Mask(sign_negative_mask(vector.0))
+        }
+
+        #[inline]
+        fn vector_max(vector: Vec3A<f32>, other: Vec3A<f32>) -> Vec3A<f32> {
+            Vector(f32x4_pmax(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_min(vector: Vec3A<f32>, other: Vec3A<f32>) -> Vec3A<f32> {
+            Vector(f32x4_pmin(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_abs(vector: Vec3A<f32>) -> Vec3A<f32> {
+            Vector(f32x4_abs(vector.0))
+        }
+
+        #[inline]
+        fn vector_signum(vector: Vec3A<f32>) -> Vec3A<f32> {
+            Vector(signum(vector.0))
+        }
+
+        #[inline]
+        fn vector_copysign(vector: Vec3A<f32>, sign: Vec3A<f32>) -> Vec3A<f32> {
+            Vector(copysign(vector.0, sign.0))
+        }
+
+        #[inline]
+        fn vector_max_element(vector: Vec3A<f32>) -> f32 {
+            max_element(i32x4_shuffle::<0, 1, 2, 2>(vector.0, vector.0))
+        }
+
+        #[inline]
+        fn vector_min_element(vector: Vec3A<f32>) -> f32 {
+            min_element(i32x4_shuffle::<0, 1, 2, 2>(vector.0, vector.0))
+        }
+
+        #[inline(always)]
+        fn vector_floor(vector: Vec3A<f32>) -> Vec3A<f32> {
+            Vector(f32x4_floor(vector.0))
+        }
+
+        #[inline(always)]
+        fn vector_ceil(vector: Vec3A<f32>) -> Vec3A<f32> {
+            Vector(f32x4_ceil(vector.0))
+        }
+
+        #[inline(always)]
+        fn vector_round(vector: Vec3A<f32>) -> Vec3A<f32> {
+            Vector(round(vector.0))
+        }
+
+        #[inline(always)]
+        fn vector_trunc(vector: Vec3A<f32>) -> Vec3A<f32> {
+            Vector(f32x4_trunc(vector.0))
+        }
+
+        #[inline(always)]
+        fn vector_mul_add(vector: Vec3A<f32>, a: Vec3A<f32>, b: Vec3A<f32>) -> Vec3A<f32> {
+            Vec3A::new(
+                vector.x.mul_add(a.x, b.x),
+                vector.y.mul_add(a.y, b.y),
+                vector.z.mul_add(a.z, b.z),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_div_euclid(vector: Vec3A<f32>, rhs: Vec3A<f32>) -> Vec3A<f32> {
+            Vec3A::new(
+                vector.x.div_euclid(rhs.x),
+                vector.y.div_euclid(rhs.y),
+                vector.z.div_euclid(rhs.z),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_rem_euclid(vector: Vec3A<f32>, rhs: Vec3A<f32>) -> Vec3A<f32> {
+            Vec3A::new(
+                vector.x.rem_euclid(rhs.x),
+                vector.y.rem_euclid(rhs.y),
+                vector.z.rem_euclid(rhs.z),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_powf(vector: Vec3A<f32>, n: f32) -> Vec3A<f32> {
+            Vec3A::new(
+                PrimitiveFloatUtils::powf(vector.x, n),
+                PrimitiveFloatUtils::powf(vector.y, n),
+                PrimitiveFloatUtils::powf(vector.z, n),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_sqrt(vector: Vec3A<f32>) -> Vec3A<f32> {
+            Vector(f32x4_sqrt(vector.0))
+        }
+
+        #[inline]
+        fn vector_rsqrt(vector: Vec3A<f32>) -> Vec3A<f32> {
+            Vec3A::ONE / vector.sqrt()
+        }
+
+        #[inline]
+        fn vector_recip_fast(vector: Vec3A<f32>) -> Vec3A<f32> {
+            vector.recip()
+        }
+
+        #[inline]
+        fn vector_length_recip_fast(vector: Vec3A<f32>) -> f32 {
+            vector.length().recip()
+        }
+
+        #[inline]
+        fn vector_normalize_fast(vector: Vec3A<f32>) -> Vec3A<f32> {
+            vector / vector.length()
+        }
+
+        #[inline(always)]
+        fn vector_exp(vector: Vec3A<f32>) -> Vec3A<f32> {
+            Vec3A::new(
+                PrimitiveFloatUtils::exp(vector.x),
+                PrimitiveFloatUtils::exp(vector.y),
+                PrimitiveFloatUtils::exp(vector.z),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_exp2(vector: Vec3A<f32>) -> Vec3A<f32> {
+            Vec3A::new(
+                PrimitiveFloatUtils::exp2(vector.x),
+                PrimitiveFloatUtils::exp2(vector.y),
+                PrimitiveFloatUtils::exp2(vector.z),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_ln(vector: Vec3A<f32>) -> Vec3A<f32> {
+            Vec3A::new(
+                PrimitiveFloatUtils::ln(vector.x),
+                PrimitiveFloatUtils::ln(vector.y),
+                PrimitiveFloatUtils::ln(vector.z),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_log2(vector: Vec3A<f32>) -> Vec3A<f32> {
+            Vec3A::new(
+                PrimitiveFloatUtils::log2(vector.x),
+                PrimitiveFloatUtils::log2(vector.y),
+                PrimitiveFloatUtils::log2(vector.z),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_sin(vector: Vec3A<f32>) -> Vec3A<f32> {
+            Vec3A::new(
+                PrimitiveFloatUtils::sin(vector.x),
+                PrimitiveFloatUtils::sin(vector.y),
+                PrimitiveFloatUtils::sin(vector.z),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_cos(vector: Vec3A<f32>) -> Vec3A<f32> {
+            Vec3A::new(
+                PrimitiveFloatUtils::cos(vector.x),
+                PrimitiveFloatUtils::cos(vector.y),
+                PrimitiveFloatUtils::cos(vector.z),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_tan(vector: Vec3A<f32>) -> Vec3A<f32> {
+            Vec3A::new(
+                PrimitiveFloatUtils::tan(vector.x),
+                PrimitiveFloatUtils::tan(vector.y),
+                PrimitiveFloatUtils::tan(vector.z),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_asin(vector: Vec3A<f32>) -> Vec3A<f32> {
+            Vec3A::new(
+                PrimitiveFloatUtils::asin(vector.x),
+                PrimitiveFloatUtils::asin(vector.y),
+                PrimitiveFloatUtils::asin(vector.z),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_acos(vector: Vec3A<f32>) -> Vec3A<f32> {
+            Vec3A::new(
+                PrimitiveFloatUtils::acos(vector.x),
+                PrimitiveFloatUtils::acos(vector.y),
+                PrimitiveFloatUtils::acos(vector.z),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_atan(vector: Vec3A<f32>) -> Vec3A<f32> {
+            Vec3A::new(
+                PrimitiveFloatUtils::atan(vector.x),
+                PrimitiveFloatUtils::atan(vector.y),
+                PrimitiveFloatUtils::atan(vector.z),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_sin_cos(vector: Vec3A<f32>) -> (Vec3A<f32>, Vec3A<f32>) {
+            let x_sin_cos = PrimitiveFloatUtils::sin_cos(vector.x);
+            let y_sin_cos = PrimitiveFloatUtils::sin_cos(vector.y);
+            let z_sin_cos = PrimitiveFloatUtils::sin_cos(vector.z);
+            (
+                Vec3A::new(x_sin_cos.0, y_sin_cos.0, z_sin_cos.0),
+                Vec3A::new(x_sin_cos.1, y_sin_cos.1, z_sin_cos.1),
+            )
+        }
+    }
+}
+
+impl FloatVectorBackend<4, Aligned> for f32 {
+    safe_target_feature! {
+        #[inline]
+        fn vector_nan_mask(vector: Vec4A<f32>) -> Mask4A<f32> {
+            Mask(nan_mask(vector.0))
+        }
+
+        #[inline]
+        fn vector_finite_mask(vector: Vec4A<f32>) -> Mask4A<f32> {
+            Mask(finite_mask(vector.0))
+        }
+
+        #[inline]
+        fn vector_sign_positive_mask(vector: Vec4A<f32>) -> Mask4A<f32> {
+            Mask(sign_positive_mask(vector.0))
+        }
+
+        #[inline]
+        fn vector_sign_negative_mask(vector: Vec4A<f32>) -> Mask4A<f32> {
+            Mask(sign_negative_mask(vector.0))
+        }
+
+        #[inline]
+        fn vector_max(vector: Vec4A<f32>, other: Vec4A<f32>) -> Vec4A<f32> {
+            Vector(f32x4_pmax(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_min(vector: Vec4A<f32>, other: Vec4A<f32>) -> Vec4A<f32> {
+            Vector(f32x4_pmin(vector.0, other.0))
+        }
+
+        #[inline]
+        fn vector_abs(vector: Vec4A<f32>) -> Vec4A<f32> {
+            Vector(f32x4_abs(vector.0))
+        }
+
+        #[inline]
+        fn vector_signum(vector: Vec4A<f32>) -> Vec4A<f32> {
+            Vector(signum(vector.0))
+        }
+
+        #[inline]
+        fn vector_copysign(vector: Vec4A<f32>, sign: Vec4A<f32>) -> Vec4A<f32> {
+            Vector(copysign(vector.0, sign.0))
+        }
+
+        #[inline]
+        fn vector_max_element(vector: Vec4A<f32>) -> f32 {
+            max_element(vector.0)
+        }
+
+        #[inline]
+        fn vector_min_element(vector: Vec4A<f32>) -> f32 {
+            min_element(vector.0)
+        }
+
+        #[inline(always)]
+        fn vector_floor(vector: Vec4A<f32>) -> Vec4A<f32> {
+            Vector(f32x4_floor(vector.0))
+        }
+
+        #[inline(always)]
+        fn vector_ceil(vector: Vec4A<f32>) -> Vec4A<f32> {
+            Vector(f32x4_ceil(vector.0))
+        }
+
+        #[inline(always)]
+        fn vector_round(vector: Vec4A<f32>) -> Vec4A<f32> {
+            Vector(round(vector.0))
+        }
+
+        #[inline(always)]
+        fn vector_trunc(vector: Vec4A<f32>) -> Vec4A<f32> {
+            Vector(f32x4_trunc(vector.0))
+        }
+
+        #[inline(always)]
+        fn vector_mul_add(vector: Vec4A<f32>, a: Vec4A<f32>, b: Vec4A<f32>) -> Vec4A<f32> {
+            Vec4A::new(
+                vector.x.mul_add(a.x, b.x),
+                vector.y.mul_add(a.y, b.y),
+                vector.z.mul_add(a.z, b.z),
+                vector.w.mul_add(a.w, b.w),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_div_euclid(vector: Vec4A<f32>, rhs: Vec4A<f32>) -> Vec4A<f32> {
+            Vec4A::new(
+                vector.x.div_euclid(rhs.x),
+                vector.y.div_euclid(rhs.y),
+                vector.z.div_euclid(rhs.z),
+                vector.w.div_euclid(rhs.w),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_rem_euclid(vector: Vec4A<f32>, rhs: Vec4A<f32>) -> Vec4A<f32> {
+            Vec4A::new(
+                vector.x.rem_euclid(rhs.x),
+                vector.y.rem_euclid(rhs.y),
+                vector.z.rem_euclid(rhs.z),
+                vector.w.rem_euclid(rhs.w),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_powf(vector: Vec4A<f32>, n: f32) -> Vec4A<f32> {
+            Vec4A::new(
+                PrimitiveFloatUtils::powf(vector.x, n),
+                PrimitiveFloatUtils::powf(vector.y, n),
+                PrimitiveFloatUtils::powf(vector.z, n),
+                PrimitiveFloatUtils::powf(vector.w, n),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_sqrt(vector: Vec4A<f32>) -> Vec4A<f32> {
+            Vector(f32x4_sqrt(vector.0))
+        }
+
+        #[inline]
+        fn vector_rsqrt(vector: Vec4A<f32>) -> Vec4A<f32> {
+            Vec4A::ONE / vector.sqrt()
+        }
+
+        #[inline]
+        fn vector_recip_fast(vector: Vec4A<f32>) -> Vec4A<f32> {
+            vector.recip()
+        }
+
+        #[inline]
+        fn vector_length_recip_fast(vector: Vec4A<f32>) -> f32 {
+            vector.length().recip()
+        }
+
+        #[inline]
+        fn vector_normalize_fast(vector: Vec4A<f32>) -> Vec4A<f32> {
+            vector / vector.length()
+        }
+
+        #[inline(always)]
+        fn vector_exp(vector: Vec4A<f32>) -> Vec4A<f32> {
+            Vec4A::new(
+                PrimitiveFloatUtils::exp(vector.x),
+                PrimitiveFloatUtils::exp(vector.y),
+                PrimitiveFloatUtils::exp(vector.z),
+                PrimitiveFloatUtils::exp(vector.w),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_exp2(vector: Vec4A<f32>) -> Vec4A<f32> {
+            Vec4A::new(
+                PrimitiveFloatUtils::exp2(vector.x),
+                PrimitiveFloatUtils::exp2(vector.y),
+                PrimitiveFloatUtils::exp2(vector.z),
+                PrimitiveFloatUtils::exp2(vector.w),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_ln(vector: Vec4A<f32>) -> Vec4A<f32> {
+            Vec4A::new(
+                PrimitiveFloatUtils::ln(vector.x),
+                PrimitiveFloatUtils::ln(vector.y),
+                PrimitiveFloatUtils::ln(vector.z),
+                PrimitiveFloatUtils::ln(vector.w),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_log2(vector: Vec4A<f32>) -> Vec4A<f32> {
+            Vec4A::new(
+                PrimitiveFloatUtils::log2(vector.x),
+                PrimitiveFloatUtils::log2(vector.y),
+                PrimitiveFloatUtils::log2(vector.z),
+                PrimitiveFloatUtils::log2(vector.w),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_sin(vector: Vec4A<f32>) -> Vec4A<f32> {
+            Vec4A::new(
+                PrimitiveFloatUtils::sin(vector.x),
+                PrimitiveFloatUtils::sin(vector.y),
+                PrimitiveFloatUtils::sin(vector.z),
+                PrimitiveFloatUtils::sin(vector.w),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_cos(vector: Vec4A<f32>) -> Vec4A<f32> {
+            Vec4A::new(
+                PrimitiveFloatUtils::cos(vector.x),
+                PrimitiveFloatUtils::cos(vector.y),
+                PrimitiveFloatUtils::cos(vector.z),
+                PrimitiveFloatUtils::cos(vector.w),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_tan(vector: Vec4A<f32>) -> Vec4A<f32> {
+            Vec4A::new(
+                PrimitiveFloatUtils::tan(vector.x),
+                PrimitiveFloatUtils::tan(vector.y),
+                PrimitiveFloatUtils::tan(vector.z),
+                PrimitiveFloatUtils::tan(vector.w),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_asin(vector: Vec4A<f32>) -> Vec4A<f32> {
+            Vec4A::new(
+                PrimitiveFloatUtils::asin(vector.x),
+                PrimitiveFloatUtils::asin(vector.y),
+                PrimitiveFloatUtils::asin(vector.z),
+                PrimitiveFloatUtils::asin(vector.w),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_acos(vector: Vec4A<f32>) -> Vec4A<f32> {
+            Vec4A::new(
+                PrimitiveFloatUtils::acos(vector.x),
+                PrimitiveFloatUtils::acos(vector.y),
+                PrimitiveFloatUtils::acos(vector.z),
+                PrimitiveFloatUtils::acos(vector.w),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_atan(vector: Vec4A<f32>) -> Vec4A<f32> {
+            Vec4A::new(
+                PrimitiveFloatUtils::atan(vector.x),
+                PrimitiveFloatUtils::atan(vector.y),
+                PrimitiveFloatUtils::atan(vector.z),
+                PrimitiveFloatUtils::atan(vector.w),
+            )
+        }
+
+        #[inline(always)]
+        fn vector_sin_cos(vector: Vec4A<f32>) -> (Vec4A<f32>, Vec4A<f32>) {
+            let x_sin_cos = PrimitiveFloatUtils::sin_cos(vector.x);
+            let y_sin_cos = PrimitiveFloatUtils::sin_cos(vector.y);
+            let z_sin_cos = PrimitiveFloatUtils::sin_cos(vector.z);
+            let w_sin_cos = PrimitiveFloatUtils::sin_cos(vector.w);
+            (
+                Vec4A::new(x_sin_cos.0, y_sin_cos.0, z_sin_cos.0, w_sin_cos.0),
+                Vec4A::new(x_sin_cos.1, y_sin_cos.1, z_sin_cos.1, w_sin_cos.1),
+            )
+        }
+    }
+}
+
+impl FloatMatrixBackend<3, Aligned> for f32 {
+    #[inline]
+    fn matrix_inverse_and_determinant(matrix: &Mat3A<f32>) -> (Mat3A<f32>, f32) {
+        matrix.inverse_and_determinant_generic()
+    }
+}
+
+impl FloatMatrixBackend<4, Aligned> for f32 {
+    #[inline]
+    fn matrix_inverse_and_determinant(matrix: &Mat4A<f32>) -> (Mat4A<f32>, f32) {
+        matrix.inverse_and_determinant_generic()
+    }
+}
+
+impl CastBackend<3, Aligned> for f32 {
+    safe_target_feature! {
+        #[inline]
+        fn vector_as_f32(vector: Vec3A<f32>) -> Vec3A<f32> {
+            vector
+        }
+
+        #[inline]
+        fn vector_as_f64(vector: Vec3A<f32>) -> Vec3A<f64> {
+            vector.map(|x| x as f64)
+        }
+
+        #[inline]
+        fn vector_as_i32(vector: Vec3A<f32>) -> Vec3A<i32> {
+            vector.map(|x| x as i32)
+        }
+
+        #[inline]
+        fn vector_as_u32(vector: Vec3A<f32>) -> Vec3A<u32> {
+            vector.map(|x| x as u32)
+        }
+    }
+}
+
+impl CastBackend<4, Aligned> for f32 {
+    safe_target_feature! {
+        #[inline]
+        fn vector_as_f32(vector: Vec4A<f32>) -> Vec4A<f32> {
+            vector
+        }
+
+        #[inline]
+        fn vector_as_f64(vector: Vec4A<f32>) -> Vec4A<f64> {
+            vector.map(|x| x as f64)
+        }
+
+        #[inline]
+        fn vector_as_i32(vector: Vec4A<f32>) -> Vec4A<i32> {
+            vector.map(|x| x as i32)
+        }
+
+        #[inline]
+        fn vector_as_u32(vector: Vec4A<f32>) -> Vec4A<u32> {
+            vector.map(|x| x as u32)
+        }
+    }
+}
+
+safe_target_feature! {
+    #[inline]
+    fn rem(vector: v128, rhs: v128) -> v128 {
+        let result = f32x4_sub(vector, f32x4_mul(f32x4_trunc(f32x4_div(vector, rhs)), rhs));
+
+        let inf_mask = f32x4_eq(f32x4_abs(rhs), f32x4_splat(f32::INFINITY));
+        let zero_mask = f32x4_eq(rhs, f32x4_splat(0.0));
+        let result = v128_bitselect(vector, result, v128_or(inf_mask, f32x4_splat(-0.0)));
+
+        v128_bitselect(f32x4_splat(f32::NAN), result, zero_mask)
+    }
+
+    #[inline]
+    fn round(vector: v128) -> v128 {
+        // `f32x4_nearest` rounds half-way cases to even, but `round` rounds
+        // them away from zero. The fractional part is exact, so it can be
+        // compared against `0.5` directly. Infinity and NaN produce a NaN
+        // fractional part and keep the truncated value.
+        let trunc = f32x4_trunc(vector);
+        let fract_abs = f32x4_abs(f32x4_sub(vector, trunc));
+        let away_mask = f32x4_ge(fract_abs, f32x4_splat(0.5));
+
+        v128_bitselect(
+            f32x4_add(trunc, copysign(f32x4_splat(1.0), vector)),
+            trunc,
+            away_mask,
+        )
+    }
+
+    #[inline]
+    fn max_element(vector: v128) -> f32 {
+        let reduce_2 = f32x4_pmax(vector, i32x4_shuffle::<2, 3, 0, 1>(vector, vector));
+        let reduce_1 = f32x4_pmax(reduce_2, i32x4_shuffle::<1, 0, 3, 2>(reduce_2, reduce_2));
+        f32x4_extract_lane::<0>(reduce_1)
+    }
+
+    #[inline]
+    fn min_element(vector: v128) -> f32 {
+        let reduce_2 = f32x4_pmin(vector, i32x4_shuffle::<2, 3, 0, 1>(vector, vector));
+        let reduce_1 = f32x4_pmin(reduce_2, i32x4_shuffle::<1, 0, 3, 2>(reduce_2, reduce_2));
+        f32x4_extract_lane::<0>(reduce_1)
+    }
+
+    #[inline]
+    fn nan_mask(vector: v128) -> v128 {
+        f32x4_ne(vector, vector)
+    }
+
+    #[inline]
+    fn finite_mask(vector: v128) -> v128 {
+        f32x4_lt(f32x4_abs(vector), f32x4_splat(f32::INFINITY))
+    }
+
+    #[inline]
+    fn sign_positive_mask(vector: v128) -> v128 {
+        i32x4_eq(vector, f32x4_abs(vector))
+    }
+
+    #[inline]
+    fn sign_negative_mask(vector: v128) -> v128 {
+        i32x4_ne(vector, f32x4_abs(vector))
+    }
+
+    #[inline]
+    fn signum(vector: v128) -> v128 {
+        let result = v128_or(f32x4_splat(1.0), v128_and(vector, f32x4_splat(-0.0)));
+
+        v128_bitselect(vector, result, nan_mask(vector))
+    }
+
+    #[inline]
+    fn copysign(vector: v128, sign: v128) -> v128 {
+        v128_bitselect(sign, vector, f32x4_splat(-0.0))
+    }
+}
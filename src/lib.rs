@@ -44,9 +44,23 @@
 //! | Alignment (bytes) | 4             | 16             | 4             | 16             |
 //! | Padding (bytes)   | 0             | 0              | 0             | 0              |
 //!
+//! | Type              | [`Vec2A<f64>`] | [`Vec3A<f64>`] | [`Vec4A<f64>`] | [`Mat3A<f64>`] |
+//! | ----------------- | -------------- | -------------- | -------------- | -------------- |
+//! | Size (bytes)      | 16             | 32             | 32             | 96             |
+//! | Alignment (bytes) | 16             | 32             | 32             | 32             |
+//! | Padding (bytes)   | 0              | 8              | 0              | 24             |
+//!
 //! > This table is true only for target architectures that have SIMD and are
 //! > supported. Types incompatible with SIMD use fallback implementations.
-//! > Currently support is limited to [`f32`] types on x86 and aarch64.
+//! > Currently support is limited to [`f32`], [`i32`] and [`u32`] types on x86 and
+//! > aarch64, to [`f64`] types on x86, and to [`f32`] types on wasm32 with the
+//! > `simd128` target feature.
+//!
+//! When compiled with the `avx` target feature, [`f64`] SIMD types use 256-bit
+//! registers. With the `fma` target feature, multiplications and additions of
+//! [`f64`] SIMD types are also fused in matrix multiplication, quaternion
+//! multiplication and dot products, unless the `deterministic` feature is
+//! enabled ([see below](#determinism)).
 //!
 //! # Generics
 //!
@@ -56,9 +70,9 @@
 //! - `N`: The dimension
 //! - `A`: The alignment mode (SIMD or non-SIMD)
 //!
-//! The traits [`PrimitiveFloat`], [`PrimitiveInteger`], [`PrimitiveSigned`] and
-//! [`PrimitiveUnsigned`] give generic contexts access to most primitive
-//! functionality. These traits do not expose functions directly, they only
+//! The traits [`PrimitiveNumber`], [`PrimitiveFloat`], [`PrimitiveInteger`],
+//! [`PrimitiveSigned`] and [`PrimitiveUnsigned`] give generic contexts access
+//! to most primitive functionality. These traits do not expose functions directly, they only
 //! enable functionality for vectors, matrices, etc. For complete primitive
 //! generics, add the [`num-primitive`] crate as an optional dependency.
 //!
@@ -131,6 +145,28 @@
 //! matrix (or quaternion) you write `vector * matrix` and not
 //! `matrix * vector`. This means matrices are stored in row-major order.
 //!
+//! # Determinism
+//!
+//! By default, floating-point results may differ between targets in their last
+//! bits. Aligned [`f64`] types fuse multiplications and additions when the `fma`
+//! target feature is enabled, and transcendental functions like `sin`, `exp` and
+//! `powf` use the platform's math library.
+//!
+//! The `deterministic` feature removes these differences. With it enabled, all
+//! [`f32`] and [`f64`] vector, matrix, quaternion and affine functions return
+//! bit-identical results on every target, for both aligned and unaligned types.
+//! This includes arithmetic, `length`, `normalize`, `slerp`, `inverse` and
+//! transcendental functions, which use the software implementation from [`libm`].
+//!
+//! Exceptions:
+//!
+//! - The bit pattern of NaN results.
+//! - The sign of zero returned by `min`, `max`, `clamp`, `min_element` and
+//!   `max_element` when comparing `0.0` with `-0.0`.
+//! - SoA types and custom scalars.
+//! - 32-bit x86 targets without SSE2, because x87 computes with extended
+//!   precision.
+//!
 //! # Why another math crate?
 //!
 //! [`ggmath`] exists because existing similar libraries are missing certain
@@ -187,6 +223,17 @@
 //!
 //! - [`fixed`]: Implements [`Scalar`] for fixed-point numbers.
 //!
+//! - `deterministic`: Makes floating-point results bit-identical on every
+//!   target. See [Determinism](#determinism).
+//!
+//! - `f16`: Implements [`Scalar`] for `f16` from the [`half`] crate, and adds
+//!   conversions between `f16` vectors and [`f32`] or [`f64`] vectors.
+//!
+//! - `force-fallback`: Uses the scalar fallback implementation for all types,
+//!   even where a SIMD backend is available. Vector, matrix and quaternion types
+//!   keep their SIMD layouts, but masks use the fallback layout. This is meant
+//!   for testing SIMD backends against the fallback.
+//!
 //! - [`libm`]: Uses [`libm`] instead of [`std`] as the backend for
 //!   floating-point functions. This makes the crate [`no_std`].
 //!
@@ -208,6 +255,7 @@
 //! [The `docs.rs` page]: https://docs.rs/ggmath
 //!
 //! [`fixed`]: https://crates.io/crates/fixed
+//! [`half`]: https://crates.io/crates/half
 //!
 //! [`glam`]: https://crates.io/crates/glam
 //! [`ultraviolet`]: https://crates.io/crates/ultraviolet
@@ -237,7 +285,9 @@ pub use crate::{
     length::{Length, SupportedLength},
     mask::{Mask, Mask2, Mask2A, Mask3, Mask3A, Mask4, Mask4A},
     matrix::{Mat2, Mat2A, Mat3, Mat3A, Mat4, Mat4A, Matrix},
-    primitive_traits::{PrimitiveFloat, PrimitiveInteger, PrimitiveSigned, PrimitiveUnsigned},
+    primitive_traits::{
+        PrimitiveFloat, PrimitiveInteger, PrimitiveNumber, PrimitiveSigned, PrimitiveUnsigned,
+    },
     quaternion::{Quat, QuatA, Quaternion},
     scalar::{CustomScalar, Scalar},
     vector::{Vec2, Vec2A, Vec3, Vec3A, Vec4, Vec4A, Vector},
@@ -10,10 +10,12 @@ use core::{
 
 use crate::{
     Affine, Aligned, Alignment, Length, One, Scalar, SupportedLength, Unaligned, Vector, Zero,
+    backend::MatrixBackend,
     utils::{Repr3, Repr4, specialize, transmute_generic, transmute_mut, transmute_ref},
 };
 
 mod float;
+mod number;
 #[cfg(feature = "wide")]
 mod wide;
 #[cfg(feature = "wide")]
@@ -652,7 +654,7 @@ where
     #[inline]
     #[must_use]
     pub fn transpose(&self) -> Self {
-        specialize!(Matrix::<N, T, A>::transpose_backend(self))
+        specialize!(<T as MatrixBackend<N, A>>::matrix_transpose(self))
     }
 
     /// Transforms `vector` by the transpose of `self`.
@@ -762,7 +764,16 @@ where
     where
         T: Neg<Output = T> + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
     {
-        specialize!(Matrix::<N, T, A>::determinant_backend(self))
+        specialize!(<T as MatrixBackend<N, A>>::matrix_determinant(self))
+    }
+
+    #[track_caller]
+    #[inline(always)]
+    pub(crate) fn mul_generic(&self, rhs: &Self) -> Self
+    where
+        T: Add<Output = T> + Mul<Output = T>,
+    {
+        Self::from_row_fn(|i| self[i] * rhs)
     }
 
     /// Returns a mutable reference to the matrix's rows.
@@ -802,7 +813,7 @@ where
     }
 
     #[inline(always)]
-    fn transpose_backend(&self) -> Self {
+    pub(crate) fn transpose_generic(&self) -> Self {
         Self(self.0.xzyw())
     }
 
@@ -826,7 +837,7 @@ where
 
     #[track_caller]
     #[inline(always)]
-    fn determinant_backend(&self) -> T
+    pub(crate) fn determinant_generic(&self) -> T
     where
         T: Neg<Output = T> + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
     {
@@ -1061,7 +1072,7 @@ where
     }
 
     #[inline(always)]
-    fn transpose_backend(&self) -> Self {
+    pub(crate) fn transpose_generic(&self) -> Self {
         Self::from_rows(&[
             Vector::<3, T, A>::new(self.x_axis.x, self.y_axis.x, self.z_axis.x),
             Vector::<3, T, A>::new(self.x_axis.y, self.y_axis.y, self.z_axis.y),
@@ -1097,7 +1108,7 @@ where
 
     #[track_caller]
     #[inline(always)]
-    fn determinant_backend(&self) -> T
+    pub(crate) fn determinant_generic(&self) -> T
     where
         T: Neg<Output = T> + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
     {
@@ -1388,7 +1399,7 @@ where
     }
 
     #[inline(always)]
-    fn transpose_backend(&self) -> Self {
+    pub(crate) fn transpose_generic(&self) -> Self {
         Self::from_rows(&[
             Vector::<4, T, A>::new(self.x_axis.x, self.y_axis.x, self.z_axis.x, self.w_axis.x),
             Vector::<4, T, A>::new(self.x_axis.y, self.y_axis.y, self.z_axis.y, self.w_axis.y),
@@ -1427,7 +1438,7 @@ where
 
     #[track_caller]
     #[inline(always)]
-    fn determinant_backend(&self) -> T
+    pub(crate) fn determinant_generic(&self) -> T
     where
         T: Neg<Output = T> + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
     {
@@ -2186,7 +2197,7 @@ macro_rules! impl_mul {
             #[inline]
             #[track_caller]
             fn mul(self, rhs: &Matrix<N, T, A>) -> Self::Output {
-                Matrix::from_row_fn(|i| self[i] * rhs)
+                specialize!(<T as MatrixBackend<N, A>>::matrix_mul(self, rhs))
             }
         }
     };
@@ -2204,6 +2215,9 @@ impl_mul!(
     /// For primitive types this operation is cross-platform deterministic and
     /// fully consistent with scalar addition and multiplication, including
     /// floating-point precision and integer panics.
+    ///
+    /// The exception is aligned [`f64`] types when the `fma` target feature is
+    /// enabled, where multiplications and additions may be fused.
 );
 
 macro_rules! impl_vector_mul {
@@ -2234,12 +2248,12 @@ macro_rules! impl_vector_mul {
             #[inline]
             #[track_caller]
             fn mul(self, rhs: &Matrix<N, T, A>) -> Self::Output {
-                match N {
-                    2 => rhs[0] * self[0] + rhs[1] * self[1],
-                    3 => rhs[0] * self[0] + rhs[1] * self[1] + rhs[2] * self[2],
-                    4 => rhs[0] * self[0] + rhs[1] * self[1] + rhs[2] * self[2] + rhs[3] * self[3],
-                    _ => unreachable!(),
+                let mut result = rhs[0] * self[0];
+                for i in 1..N {
+                    result = rhs[i].mul_add_contracted(Vector::splat(self[i]), result);
                 }
+
+                result
             }
         }
 
@@ -2287,6 +2301,9 @@ impl_vector_mul!(
     /// For primitive types this operation is cross-platform deterministic and
     /// fully consistent with scalar addition and multiplication, including
     /// floating-point precision and integer panics.
+    ///
+    /// The exception is aligned [`f64`] types when the `fma` target feature is
+    /// enabled, where multiplications and additions may be fused.
 );
 
 macro_rules! impl_mul_assign_scalar {
@@ -2380,6 +2397,9 @@ impl_mul_assign!(
     /// fully consistent with scalar addition and multiplication, including
     /// floating-point precision and integer panics.
     ///
+    /// The exception is aligned [`f64`] types when the `fma` target feature is
+    /// enabled, where multiplications and additions may be fused.
+    ///
     /// This operation is fully consistent with `matrix * matrix`.
 );
 
@@ -2425,6 +2445,9 @@ impl_vector_mul_assign!(
     /// For primitive types this operation is cross-platform deterministic and
     /// fully consistent with scalar addition and multiplication, including
     /// floating-point precision and integer panics.
+    ///
+    /// The exception is aligned [`f64`] types when the `fma` target feature is
+    /// enabled, where multiplications and additions may be fused.
 );
 
 macro_rules! impl_div_scalar {
@@ -2596,7 +2619,7 @@ mod tests {
 
     use crate::{
         Affine, Aligned, Mask, Mat2A, Mat3A, Mat4A, Matrix, Unaligned, Vec2A, Vec3A, Vec4A, Vector,
-        test_utils::{assert_panic, assert_test_eq, for_types, random_iter},
+        test_utils::{assert_panic, assert_test_eq, for_types, mul_add_contracted, random_iter},
     };
 
     #[test]
@@ -3028,6 +3051,29 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_matrix_backend() {
+        for_types!(|T: PrimitiveFloat, A| {
+            for [matrix_1, matrix_2] in random_iter::<[Matrix<2, T, A>; 2]>() {
+                assert_test_eq!(matrix_1.transpose(), matrix_1.transpose_generic());
+                assert_test_eq!(matrix_1 * matrix_2, matrix_1.mul_generic(&matrix_2));
+                assert_test_eq!(matrix_1.determinant(), matrix_1.determinant_generic());
+            }
+
+            for [matrix_1, matrix_2] in random_iter::<[Matrix<3, T, A>; 2]>() {
+                assert_test_eq!(matrix_1.transpose(), matrix_1.transpose_generic());
+                assert_test_eq!(matrix_1 * matrix_2, matrix_1.mul_generic(&matrix_2));
+                assert_test_eq!(matrix_1.determinant(), matrix_1.determinant_generic());
+            }
+
+            for [matrix_1, matrix_2] in random_iter::<[Matrix<4, T, A>; 2]>() {
+                assert_test_eq!(matrix_1.transpose(), matrix_1.transpose_generic());
+                assert_test_eq!(matrix_1 * matrix_2, matrix_1.mul_generic(&matrix_2));
+                assert_test_eq!(matrix_1.determinant(), matrix_1.determinant_generic());
+            }
+        });
+    }
+
     #[test]
     fn test_from_row_array() {
         for_types!(|T: PrimitiveNumber, A| {
@@ -3578,27 +3624,42 @@ mod tests {
     #[test]
     fn test_vector_mul() {
         for_types!(|T: PrimitiveFloat, A| {
+            let mul_add = mul_add_contracted::<T, A>;
+
             for (vector, matrix) in random_iter::<(Vector<2, T, A>, Matrix<2, T, A>)>() {
                 assert_test_eq!(
                     vector * matrix,
-                    matrix.x_axis * vector.x + matrix.y_axis * vector.y,
+                    Vector::from_fn(|i| mul_add(
+                        matrix.y_axis[i],
+                        vector.y,
+                        matrix.x_axis[i] * vector.x
+                    )),
                 );
             }
 
             for (vector, matrix) in random_iter::<(Vector<3, T, A>, Matrix<3, T, A>)>() {
                 assert_test_eq!(
                     vector * matrix,
-                    matrix.x_axis * vector.x + matrix.y_axis * vector.y + matrix.z_axis * vector.z,
+                    Vector::from_fn(|i| mul_add(
+                        matrix.z_axis[i],
+                        vector.z,
+                        mul_add(matrix.y_axis[i], vector.y, matrix.x_axis[i] * vector.x)
+                    )),
                 );
             }
 
             for (vector, matrix) in random_iter::<(Vector<4, T, A>, Matrix<4, T, A>)>() {
                 assert_test_eq!(
                     vector * matrix,
-                    matrix.x_axis * vector.x
-                        + matrix.y_axis * vector.y
-                        + matrix.z_axis * vector.z
-                        + matrix.w_axis * vector.w,
+                    Vector::from_fn(|i| mul_add(
+                        matrix.w_axis[i],
+                        vector.w,
+                        mul_add(
+                            matrix.z_axis[i],
+                            vector.z,
+                            mul_add(matrix.y_axis[i], vector.y, matrix.x_axis[i] * vector.x)
+                        )
+                    )),
                 );
             }
         });
@@ -1,6 +1,7 @@
 use crate::{
     Alignment, EulerRot, FloatExt, Length, Matrix, PrimitiveFloat, Quaternion, SupportedLength,
     Vector,
+    backend::FloatMatrixBackend,
     utils::{PrimitiveFloatUtils, specialize},
 };
 
@@ -126,7 +127,7 @@ where
 
     #[inline]
     fn inverse_and_determinant(&self) -> (Self, T) {
-        specialize!(Matrix::<N, T, A>::inverse_and_determinant_backend(self))
+        specialize!(<T as FloatMatrixBackend<N, A>>::matrix_inverse_and_determinant(self))
     }
 
     /// Returns the element-wise reciprocal (inverse) of a matrix, `1 / self`.
@@ -278,7 +279,7 @@ where
     }
 
     #[inline(always)]
-    fn inverse_and_determinant_backend(&self) -> (Self, T) {
+    pub(crate) fn inverse_and_determinant_generic(&self) -> (Self, T) {
         if const { align_of::<Self>() > align_of::<T>() } {
             // `[a*d, b*c, b*c, a*d]`
             let products = self.0 * self.0.wzyx();
@@ -1020,7 +1021,7 @@ where
     }
 
     #[inline(always)]
-    fn inverse_and_determinant_backend(&self) -> (Self, T) {
+    pub(crate) fn inverse_and_determinant_generic(&self) -> (Self, T) {
         let x_cross_y = self.x_axis.cross(self.y_axis);
         let determinant = x_cross_y.dot(self.z_axis);
 
@@ -2203,8 +2204,7 @@ where
     #[inline]
     #[must_use]
     pub fn project_point(&self, point: Vector<3, T, A>) -> Vector<3, T, A> {
-        let result =
-            self.x_axis * point.x + self.y_axis * point.y + self.z_axis * point.z + self.w_axis;
+        let result = point.to_homogeneous() * self;
 
         (result / result.w).xyz()
     }
@@ -2223,7 +2223,7 @@ where
     }
 
     #[inline(always)]
-    fn inverse_and_determinant_backend(&self) -> (Self, T) {
+    pub(crate) fn inverse_and_determinant_generic(&self) -> (Self, T) {
         let [m00, m01, m02, m03] = self.x_axis.to_array();
         let [m10, m11, m12, m13] = self.y_axis.to_array();
         let [m20, m21, m22, m23] = self.z_axis.to_array();
@@ -2327,7 +2327,8 @@ mod tests {
     use crate::{
         EulerRot, FloatExt, Mat3A, Mat4A, Matrix, Quaternion, Vec2A, Vec3A, Vec4A, Vector,
         test_utils::{
-            assert_debug_panic, assert_panic_test_eq, assert_test_eq, for_types, random_iter,
+            assert_debug_panic, assert_panic_test_eq, assert_test_eq, contraction_tolerance,
+            for_types, random_iter,
         },
     };
 
@@ -2437,6 +2438,32 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_inverse_matrix_backend() {
+        for_types!(|T: PrimitiveFloat, A| {
+            for matrix in random_iter::<Matrix<2, T, A>>() {
+                assert_test_eq!(
+                    matrix.inverse_and_determinant(),
+                    matrix.inverse_and_determinant_generic()
+                );
+            }
+
+            for matrix in random_iter::<Matrix<3, T, A>>() {
+                assert_test_eq!(
+                    matrix.inverse_and_determinant(),
+                    matrix.inverse_and_determinant_generic()
+                );
+            }
+
+            for matrix in random_iter::<Matrix<4, T, A>>() {
+                assert_test_eq!(
+                    matrix.inverse_and_determinant(),
+                    matrix.inverse_and_determinant_generic()
+                );
+            }
+        });
+    }
+
     #[test]
     fn test_try_inverse() {
         for_types!(|N, T: PrimitiveFloat, A| {
@@ -2581,7 +2608,8 @@ mod tests {
             for (vector, angle) in random_iter::<(Vector<2, T, A>, T)>() {
                 assert_test_eq!(
                     vector * Matrix::<2, T, A>::from_angle(angle),
-                    vector.rotate(angle)
+                    vector.rotate(angle),
+                    abs <= contraction_tolerance::<T, A>(vector.length()),
                 );
                 assert_test_eq!(
                     Matrix::<3, T, A>::from_angle(angle).transform_point(vector),
@@ -2714,6 +2742,7 @@ mod tests {
                 assert_test_eq!(
                     vector * Matrix::<3, T, A>::from_rotation_x(angle),
                     vector.rotate_x(angle),
+                    abs <= contraction_tolerance::<T, A>(vector.length()),
                     0.0 = -0.0
                 );
                 assert_test_eq!(
@@ -2736,6 +2765,7 @@ mod tests {
                 assert_test_eq!(
                     vector * Matrix::<3, T, A>::from_rotation_y(angle),
                     vector.rotate_y(angle),
+                    abs <= contraction_tolerance::<T, A>(vector.length()),
                     0.0 = -0.0
                 );
                 assert_test_eq!(
@@ -2758,6 +2788,7 @@ mod tests {
                 assert_test_eq!(
                     vector * Matrix::<3, T, A>::from_rotation_z(angle),
                     vector.rotate_z(angle),
+                    abs <= contraction_tolerance::<T, A>(vector.length()),
                     0.0 = -0.0
                 );
                 assert_test_eq!(
@@ -0,0 +1,138 @@
+use crate::{Alignment, Length, Matrix, PrimitiveNumber, SupportedLength};
+
+impl<const N: usize, T, A: Alignment> Matrix<N, T, A>
+where
+    Length<N>: SupportedLength,
+    T: PrimitiveNumber,
+{
+    /// Converts the elements of `self` to `U` with `as` semantics.
+    ///
+    /// See [`Vector::cast`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ggmath::{Mat2, Vec2};
+    /// #
+    /// let matrix = Mat2::from_rows(&[Vec2::new(1.5, 2.0), Vec2::new(-3.0, 4.5)]);
+    ///
+    /// assert_eq!(
+    ///     matrix.cast::<i32>(),
+    ///     Mat2::from_rows(&[Vec2::new(1, 2), Vec2::new(-3, 4)]),
+    /// );
+    /// ```
+    ///
+    /// [`Vector::cast`]: crate::Vector::cast
+    #[inline]
+    #[must_use]
+    pub fn cast<U: PrimitiveNumber>(&self) -> Matrix<N, U, A> {
+        Matrix::from_row_fn(|i| self[i].cast())
+    }
+
+    /// Converts the elements of `self` to `U`, returning `None` if any element
+    /// is non-finite or out of the range of `U`.
+    ///
+    /// See [`Vector::try_cast`] for details.
+    ///
+    /// [`Vector::try_cast`]: crate::Vector::try_cast
+    #[inline]
+    #[must_use]
+    pub fn try_cast<U: PrimitiveNumber>(&self) -> Option<Matrix<N, U, A>> {
+        if (0..N).all(|i| self[i].cast_fits::<U>()) {
+            Some(self.cast())
+        } else {
+            None
+        }
+    }
+
+    /// Converts the elements of `self` to [`f32`] with `as` semantics.
+    ///
+    /// Equivalent to `self.cast::<f32>()`.
+    #[inline]
+    #[must_use]
+    pub fn as_f32(&self) -> Matrix<N, f32, A> {
+        self.cast()
+    }
+
+    /// Converts the elements of `self` to [`f64`] with `as` semantics.
+    ///
+    /// Equivalent to `self.cast::<f64>()`.
+    #[inline]
+    #[must_use]
+    pub fn as_f64(&self) -> Matrix<N, f64, A> {
+        self.cast()
+    }
+
+    /// Converts the elements of `self` to [`i32`] with `as` semantics.
+    ///
+    /// Equivalent to `self.cast::<i32>()`.
+    #[inline]
+    #[must_use]
+    pub fn as_i32(&self) -> Matrix<N, i32, A> {
+        self.cast()
+    }
+
+    /// Converts the elements of `self` to [`u32`] with `as` semantics.
+    ///
+    /// Equivalent to `self.cast::<u32>()`.
+    #[inline]
+    #[must_use]
+    pub fn as_u32(&self) -> Matrix<N, u32, A> {
+        self.cast()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Mat2, Matrix, Vec2,
+        test_utils::{assert_test_eq, for_types, random_iter},
+    };
+
+    #[test]
+    fn test_cast() {
+        for_types!(|N, T: PrimitiveNumber, A| {
+            for matrix in random_iter::<Matrix<N, T, A>>() {
+                assert_test_eq!(
+                    matrix.cast::<f32>(),
+                    Matrix::from_row_fn(|i| matrix[i].cast::<f32>())
+                );
+                assert_eq!(
+                    matrix.cast::<i8>(),
+                    Matrix::from_row_fn(|i| matrix[i].cast::<i8>())
+                );
+                assert_eq!(
+                    matrix.cast::<u64>(),
+                    Matrix::from_row_fn(|i| matrix[i].cast::<u64>())
+                );
+
+                assert_test_eq!(matrix.as_f32(), matrix.cast::<f32>());
+                assert_test_eq!(matrix.as_f64(), matrix.cast::<f64>());
+                assert_eq!(matrix.as_i32(), matrix.cast::<i32>());
+                assert_eq!(matrix.as_u32(), matrix.cast::<u32>());
+            }
+        });
+    }
+
+    #[test]
+    fn test_try_cast() {
+        for_types!(|N, T: PrimitiveNumber, A| {
+            for matrix in random_iter::<Matrix<N, T, A>>() {
+                let expected = (0..N)
+                    .all(|i| matrix[i].try_cast::<i16>().is_some())
+                    .then(|| matrix.cast::<i16>());
+
+                assert_eq!(matrix.try_cast::<i16>(), expected);
+            }
+        });
+
+        let matrix = Mat2::from_rows(&[Vec2::new(1.0, 2.0), Vec2::new(3.0, f64::NAN)]);
+        assert_eq!(matrix.try_cast::<i32>(), None);
+
+        let matrix = Mat2::from_rows(&[Vec2::new(1.0, 2.0), Vec2::new(3.0, 4.0)]);
+        assert_eq!(
+            matrix.try_cast::<u8>(),
+            Some(Mat2::from_rows(&[Vec2::new(1, 2), Vec2::new(3, 4)]))
+        );
+    }
+}
@@ -6,10 +6,70 @@ use core::{
 
 use crate::{
     Aligned, NegOne, One, Scalar, Unaligned, Zero,
-    backend::{FloatVectorBackend, IntegerVectorBackend, SignedVectorBackend},
-    utils::{FloatUtils, PrimitiveFloatUtils, PrimitiveIntegerUtils, PrimitiveSignedUtils},
+    backend::{
+        CastBackend, CastTargetBackend, FloatMatrixBackend, FloatVectorBackend,
+        IntegerVectorBackend, SignedVectorBackend,
+    },
+    utils::{
+        FloatUtils, PrimitiveFloatUtils, PrimitiveIntegerUtils, PrimitiveNumberUtils,
+        PrimitiveSignedUtils,
+    },
 };
 
+/// A trait for all primitive number types.
+///
+/// Implemented for [`f32`], [`f64`], [`i8`], [`i16`], [`i32`], [`i64`],
+/// [`i128`], [`isize`], [`u8`], [`u16`], [`u32`], [`u64`], [`u128`] and
+/// [`usize`].
+///
+/// This trait can be used in generic contexts to access functionality shared
+/// by all primitive numbers, like converting between element types. This trait
+/// does not expose any functions directly.
+///
+/// # Examples
+///
+/// ```
+/// # use ggmath::{PrimitiveNumber, Vec3};
+/// #
+/// fn example<T: PrimitiveNumber>(vector: Vec3<T>) -> Vec3<f32> {
+///     vector.cast()
+/// }
+///
+/// assert_eq!(
+///     example::<i32>(Vec3::new(1, 2, 3)),
+///     Vec3::new(1.0, 2.0, 3.0),
+/// );
+/// ```
+#[expect(private_bounds)]
+pub trait PrimitiveNumber:
+    Sealed
+    + Debug
+    + Clone
+    + Copy
+    + PartialEq
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + Sum
+    + Product
+    + Scalar
+    + Zero
+    + One
+    + PrimitiveNumberUtils
+    + CastBackend<2, Aligned>
+    + CastBackend<3, Aligned>
+    + CastBackend<4, Aligned>
+    + CastBackend<2, Unaligned>
+    + CastBackend<3, Unaligned>
+    + CastBackend<4, Unaligned>
+    + CastTargetBackend
+    + num_primitive::PrimitiveNumber
+{
+}
+
 /// A trait for all primitive floating-point types.
 ///
 /// Implemented for [`f32`] and [`f64`].
@@ -35,6 +95,7 @@ use crate::{
 #[expect(private_bounds)]
 pub trait PrimitiveFloat:
     Sealed
+    + PrimitiveNumber
     + Debug
     + Clone
     + Copy
@@ -59,6 +120,12 @@ pub trait PrimitiveFloat:
     + FloatVectorBackend<2, Unaligned>
     + FloatVectorBackend<3, Unaligned>
     + FloatVectorBackend<4, Unaligned>
+    + FloatMatrixBackend<2, Aligned>
+    + FloatMatrixBackend<3, Aligned>
+    + FloatMatrixBackend<4, Aligned>
+    + FloatMatrixBackend<2, Unaligned>
+    + FloatMatrixBackend<3, Unaligned>
+    + FloatMatrixBackend<4, Unaligned>
     + FloatUtils
     + num_primitive::PrimitiveFloat<Bits = <Self as PrimitiveFloat>::Bits>
 {
@@ -96,6 +163,7 @@ pub trait PrimitiveFloat:
 #[expect(private_bounds)]
 pub trait PrimitiveInteger:
     Sealed
+    + PrimitiveNumber
     + Debug
     + Clone
     + Copy
@@ -195,6 +263,21 @@ pub trait PrimitiveUnsigned:
     type Signed: PrimitiveSigned;
 }
 
+impl PrimitiveNumber for f32 {}
+impl PrimitiveNumber for f64 {}
+impl PrimitiveNumber for i8 {}
+impl PrimitiveNumber for i16 {}
+impl PrimitiveNumber for i32 {}
+impl PrimitiveNumber for i64 {}
+impl PrimitiveNumber for i128 {}
+impl PrimitiveNumber for isize {}
+impl PrimitiveNumber for u8 {}
+impl PrimitiveNumber for u16 {}
+impl PrimitiveNumber for u32 {}
+impl PrimitiveNumber for u64 {}
+impl PrimitiveNumber for u128 {}
+impl PrimitiveNumber for usize {}
+
 impl PrimitiveFloat for f32 {
     type Bits = u32;
 }
@@ -276,6 +359,8 @@ impl Sealed for usize {}
 /// This will not be necessary once cfg on trait bounds is supported.
 #[cfg(not(feature = "num-primitive"))]
 mod num_primitive {
+    pub trait PrimitiveNumber {}
+
     pub trait PrimitiveFloat {
         type Bits;
     }
@@ -290,6 +375,8 @@ mod num_primitive {
         type Signed;
     }
 
+    impl<T> PrimitiveNumber for T {}
+
     impl PrimitiveFloat for f32 {
         type Bits = u32;
     }
@@ -11,6 +11,7 @@ use crate::{
 };
 
 mod float;
+mod number;
 #[cfg(feature = "wide")]
 mod wide;
 #[cfg(feature = "wide")]
@@ -576,6 +577,9 @@ where
     /// For primitive types this operation is cross-platform deterministic and
     /// fully consistent with scalar addition and multiplication, including
     /// floating-point precision and integer panics.
+    ///
+    /// The exception is aligned [`f64`] types when the `fma` target feature is
+    /// enabled, where multiplications and additions may be fused.
     #[inline]
     #[track_caller]
     fn mul(self, rhs: Self) -> Self::Output {
@@ -677,6 +681,9 @@ where
     /// For primitive types this operation is cross-platform deterministic and
     /// fully consistent with scalar addition and multiplication, including
     /// floating-point precision and integer panics.
+    ///
+    /// The exception is aligned [`f64`] types when the `fma` target feature is
+    /// enabled, where multiplications and additions may be fused.
     #[inline]
     #[track_caller]
     fn mul_assign(&mut self, rhs: Self) {
@@ -694,6 +694,25 @@ where
         self.0.length()
     }
 
+    /// Returns an approximation of `1 / self.length()`.
+    ///
+    /// See [`Vector::length_recip_fast`] for the error bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ggmath::QuatA;
+    /// #
+    /// let quat = QuatA::from_xyzw(1.0, 2.0, 4.0, 10.0);
+    ///
+    /// assert!((quat.length_recip_fast() - 1.0_f32 / 11.0).abs() < 1e-4);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn length_recip_fast(self) -> T {
+        self.0.length_recip_fast()
+    }
+
     /// Returns `self` normalized to length `1`.
     ///
     /// # Panics
@@ -726,6 +745,36 @@ where
         result
     }
 
+    /// Returns an approximation of [`normalize`].
+    ///
+    /// See [`Vector::normalize_fast`] for the error bounds.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled:
+    ///
+    /// Panics if `self` is a zero quaternion, or if the result is non finite or
+    /// zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ggmath::QuatA;
+    /// #
+    /// let quat = QuatA::from_xyzw(1.0, 2.0, 3.0, 4.0);
+    /// let normalize = quat.normalize_fast();
+    ///
+    /// assert!((normalize.to_vector() - quat.normalize().to_vector()).abs().max_element() < 1e-4);
+    /// ```
+    ///
+    /// [`normalize`]: Self::normalize
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn normalize_fast(self) -> Self {
+        Self(self.0.normalize_fast())
+    }
+
     /// Returns [`normalize`], or `None` if `self` is zero or if the result is
     /// non finite or zero.
     ///
@@ -1488,6 +1537,30 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_length_recip_fast() {
+        for_types!(|T: PrimitiveFloat, A| {
+            for quat in random_iter::<Quaternion<T, A>>() {
+                assert_test_eq!(
+                    quat.length_recip_fast(),
+                    quat.to_vector().length_recip_fast()
+                );
+            }
+        });
+    }
+
+    #[test]
+    fn test_normalize_fast() {
+        for_types!(|T: PrimitiveFloat, A| {
+            for quat in random_iter::<Quaternion<T, A>>() {
+                assert_panic_test_eq!(
+                    quat.normalize_fast(),
+                    Quaternion::from_vector(quat.to_vector().normalize_fast())
+                );
+            }
+        });
+    }
+
     #[test]
     fn test_try_normalize() {
         for_types!(|T: PrimitiveFloat, A| {
@@ -0,0 +1,123 @@
+use crate::{Alignment, PrimitiveNumber, Quaternion};
+
+impl<T, A: Alignment> Quaternion<T, A>
+where
+    T: PrimitiveNumber,
+{
+    /// Converts the elements of `self` to `U` with `as` semantics.
+    ///
+    /// See [`Vector::cast`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ggmath::Quat;
+    /// #
+    /// let quat = Quat::from_xyzw(0.0f64, 0.0, 0.0, 1.0);
+    ///
+    /// assert_eq!(quat.cast::<f32>(), Quat::from_xyzw(0.0, 0.0, 0.0, 1.0));
+    /// ```
+    ///
+    /// [`Vector::cast`]: crate::Vector::cast
+    #[inline]
+    #[must_use]
+    pub fn cast<U: PrimitiveNumber>(self) -> Quaternion<U, A> {
+        Quaternion(self.0.cast())
+    }
+
+    /// Converts the elements of `self` to `U`, returning `None` if any element
+    /// is non-finite or out of the range of `U`.
+    ///
+    /// See [`Vector::try_cast`] for details.
+    ///
+    /// [`Vector::try_cast`]: crate::Vector::try_cast
+    #[inline]
+    #[must_use]
+    pub fn try_cast<U: PrimitiveNumber>(self) -> Option<Quaternion<U, A>> {
+        self.0.try_cast().map(Quaternion)
+    }
+
+    /// Converts the elements of `self` to [`f32`] with `as` semantics.
+    ///
+    /// Equivalent to `self.cast::<f32>()`.
+    #[inline]
+    #[must_use]
+    pub fn as_f32(self) -> Quaternion<f32, A> {
+        self.cast()
+    }
+
+    /// Converts the elements of `self` to [`f64`] with `as` semantics.
+    ///
+    /// Equivalent to `self.cast::<f64>()`.
+    #[inline]
+    #[must_use]
+    pub fn as_f64(self) -> Quaternion<f64, A> {
+        self.cast()
+    }
+
+    /// Converts the elements of `self` to [`i32`] with `as` semantics.
+    ///
+    /// Equivalent to `self.cast::<i32>()`.
+    #[inline]
+    #[must_use]
+    pub fn as_i32(self) -> Quaternion<i32, A> {
+        self.cast()
+    }
+
+    /// Converts the elements of `self` to [`u32`] with `as` semantics.
+    ///
+    /// Equivalent to `self.cast::<u32>()`.
+    #[inline]
+    #[must_use]
+    pub fn as_u32(self) -> Quaternion<u32, A> {
+        self.cast()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Quat, Quaternion,
+        test_utils::{assert_test_eq, for_types, random_iter},
+    };
+
+    #[test]
+    fn test_cast() {
+        for_types!(|T: PrimitiveNumber, A| {
+            for quat in random_iter::<Quaternion<T, A>>() {
+                assert_test_eq!(
+                    quat.cast::<f32>(),
+                    Quaternion::from_vector(quat.to_vector().cast::<f32>())
+                );
+                assert_eq!(
+                    quat.cast::<i64>(),
+                    Quaternion::from_vector(quat.to_vector().cast::<i64>())
+                );
+
+                assert_test_eq!(quat.as_f32(), quat.cast::<f32>());
+                assert_test_eq!(quat.as_f64(), quat.cast::<f64>());
+                assert_eq!(quat.as_i32(), quat.cast::<i32>());
+                assert_eq!(quat.as_u32(), quat.cast::<u32>());
+            }
+        });
+    }
+
+    #[test]
+    fn test_try_cast() {
+        for_types!(|T: PrimitiveNumber, A| {
+            for quat in random_iter::<Quaternion<T, A>>() {
+                assert_eq!(
+                    quat.try_cast::<i32>(),
+                    quat.to_vector()
+                        .try_cast::<i32>()
+                        .map(Quaternion::from_vector)
+                );
+            }
+        });
+
+        assert_eq!(
+            Quat::from_xyzw(0.0, 0.0, f64::INFINITY, 1.0).try_cast::<f32>(),
+            None
+        );
+    }
+}
@@ -1,6 +1,8 @@
 use crate::{
-    Aligned, Alignment, Unaligned,
-    backend::{DefaultBackend, MaskBackend, QuaternionBackend, VectorBackend},
+    Aligned, Alignment, Length, SupportedLength, Unaligned,
+    backend::{
+        DefaultBackend, DefaultRepr, MaskBackend, MatrixBackend, QuaternionBackend, VectorBackend,
+    },
 };
 
 /// A trait for elements of vectors.
@@ -32,6 +34,12 @@ pub trait Scalar:
     + VectorBackend<4, Unaligned>
     + QuaternionBackend<Aligned>
     + QuaternionBackend<Unaligned>
+    + MatrixBackend<2, Aligned>
+    + MatrixBackend<3, Aligned>
+    + MatrixBackend<4, Aligned>
+    + MatrixBackend<2, Unaligned>
+    + MatrixBackend<3, Unaligned>
+    + MatrixBackend<4, Unaligned>
     + MaskBackend<2, Aligned>
     + MaskBackend<3, Aligned>
     + MaskBackend<4, Aligned>
@@ -62,8 +70,15 @@ pub trait CustomScalar: Copy {}
 #[diagnostic::do_not_recommend]
 impl<T> Scalar for T where T: CustomScalar {}
 
+// SAFETY: `DefaultRepr` follows the requirements of `VectorBackend::Inner`.
 #[diagnostic::do_not_recommend]
-impl<T, const N: usize, A: Alignment> DefaultBackend<N, A> for T where T: CustomScalar {}
+unsafe impl<T, const N: usize, A: Alignment> DefaultBackend<N, A> for T
+where
+    T: CustomScalar,
+    Length<N>: SupportedLength,
+{
+    type Repr = DefaultRepr<N, T>;
+}
 
 impl Scalar for f32 {}
 
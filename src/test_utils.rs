@@ -1,6 +1,9 @@
-pub(crate) use crate::test_utils::{assert_panic::*, for_types::*, random_iter::*, test_eq::*};
+pub(crate) use crate::test_utils::{
+    assert_panic::*, contraction::*, for_types::*, random_iter::*, test_eq::*,
+};
 
 mod assert_panic;
+mod contraction;
 mod for_types;
 mod random_iter;
 mod test_eq;
@@ -0,0 +1,41 @@
+use core::any::TypeId;
+
+use crate::Alignment;
+
+/// Returns whether aligned SIMD operations on `T` fuse multiplications and
+/// additions into a single rounding on the current target.
+pub fn is_contracted<T: 'static, A: Alignment>() -> bool {
+    cfg!(all(
+        target_feature = "fma",
+        not(feature = "force-fallback"),
+        not(feature = "deterministic")
+    )) && A::IS_ALIGNED
+        && TypeId::of::<T>() == TypeId::of::<f64>()
+}
+
+/// Computes `(a * b) + c` the way a SIMD operation on `Vector<N, T, A>` does,
+/// fusing it into a single rounding when [`is_contracted`] returns `true`.
+pub fn mul_add_contracted<T, A: Alignment>(a: T, b: T, c: T) -> T
+where
+    T: num_primitive::PrimitiveFloat,
+{
+    if is_contracted::<T, A>() {
+        a.mul_add(b, c)
+    } else {
+        a * b + c
+    }
+}
+
+/// Returns the maximum absolute error that contraction may introduce to a sum
+/// of products whose terms are at most `magnitude`, or zero if operations on
+/// `T` are not contracted.
+pub fn contraction_tolerance<T, A: Alignment>(magnitude: T) -> T
+where
+    T: num_primitive::PrimitiveFloat,
+{
+    if is_contracted::<T, A>() {
+        magnitude * T::EPSILON * T::from(4u8)
+    } else {
+        T::from(0u8)
+    }
+}
@@ -1051,10 +1051,10 @@ macro_rules! for_types {
 }
 pub(crate) use for_types;
 
-use crate::{One, Scalar, Zero};
+use crate::{One, PrimitiveNumber, Scalar, Zero};
 
 #[doc(hidden)]
-pub trait Number: Scalar + Zero + One + num_primitive::PrimitiveNumber {}
+pub trait Number: Scalar + Zero + One + PrimitiveNumber + num_primitive::PrimitiveNumber {}
 
 impl Number for f32 {}
 impl Number for f64 {}
@@ -4,6 +4,9 @@ mod bytemuck;
 #[cfg(feature = "fixed")]
 mod fixed;
 
+#[cfg(feature = "f16")]
+mod half;
+
 #[cfg(feature = "mint")]
 mod mint;
 
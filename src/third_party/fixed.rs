@@ -3,13 +3,22 @@ use fixed::{
     FixedU128,
 };
 
-use crate::{Alignment, Scalar, Zero, backend::DefaultBackend};
+use crate::{
+    Alignment, Length, Scalar, SupportedLength, Zero,
+    backend::{DefaultBackend, DefaultRepr},
+};
 
 macro_rules! impl_fixed {
     ($Fixed:ident) => {
         impl<Frac> Scalar for $Fixed<Frac> {}
 
-        impl<const N: usize, Frac, A: Alignment> DefaultBackend<N, A> for $Fixed<Frac> {}
+        // SAFETY: `DefaultRepr` follows the requirements of `VectorBackend::Inner`.
+        unsafe impl<const N: usize, Frac, A: Alignment> DefaultBackend<N, A> for $Fixed<Frac>
+        where
+            Length<N>: SupportedLength,
+        {
+            type Repr = DefaultRepr<N, $Fixed<Frac>>;
+        }
 
         impl<Frac> Zero for $Fixed<Frac> {
             const ZERO: Self = Self::ZERO;
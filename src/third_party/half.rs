@@ -0,0 +1,28 @@
+use half::f16;
+
+use crate::{
+    Alignment, Length, NegOne, One, Scalar, SupportedLength, Zero,
+    backend::{DefaultBackend, DefaultRepr},
+};
+
+impl Scalar for f16 {}
+
+// SAFETY: `DefaultRepr` follows the requirements of `VectorBackend::Inner`.
+unsafe impl<const N: usize, A: Alignment> DefaultBackend<N, A> for f16
+where
+    Length<N>: SupportedLength,
+{
+    type Repr = DefaultRepr<N, f16>;
+}
+
+impl Zero for f16 {
+    const ZERO: Self = Self::ZERO;
+}
+
+impl One for f16 {
+    const ONE: Self = Self::ONE;
+}
+
+impl NegOne for f16 {
+    const NEG_ONE: Self = Self::NEG_ONE;
+}
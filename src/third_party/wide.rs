@@ -6,13 +6,22 @@ use wide::{
     u64x4, u64x8,
 };
 
-use crate::{Alignment, NegOne, One, Scalar, Zero, backend::DefaultBackend};
+use crate::{
+    Alignment, Length, NegOne, One, Scalar, SupportedLength, Zero,
+    backend::{DefaultBackend, DefaultRepr},
+};
 
 macro_rules! float_impl {
     ($T:ident, $F:ident, $N:literal) => {
         impl Scalar for $T {}
 
-        impl<const N: usize, A: Alignment> DefaultBackend<N, A> for $T {}
+        // SAFETY: `DefaultRepr` follows the requirements of `VectorBackend::Inner`.
+        unsafe impl<const N: usize, A: Alignment> DefaultBackend<N, A> for $T
+        where
+            Length<N>: SupportedLength,
+        {
+            type Repr = DefaultRepr<N, $T>;
+        }
 
         impl Zero for $T {
             const ZERO: Self = unsafe { transmute::<[$F; $N], $T>([0.0; $N]) };
@@ -38,7 +47,13 @@ macro_rules! int_impl {
     ($T:ident) => {
         impl Scalar for $T {}
 
-        impl<const N: usize, A: Alignment> DefaultBackend<N, A> for $T {}
+        // SAFETY: `DefaultRepr` follows the requirements of `VectorBackend::Inner`.
+        unsafe impl<const N: usize, A: Alignment> DefaultBackend<N, A> for $T
+        where
+            Length<N>: SupportedLength,
+        {
+            type Repr = DefaultRepr<N, $T>;
+        }
 
         impl Zero for $T {
             const ZERO: Self = Self::ZERO;
@@ -69,7 +84,13 @@ macro_rules! uint_impl {
     ($T:ident) => {
         impl Scalar for $T {}
 
-        impl<const N: usize, A: Alignment> DefaultBackend<N, A> for $T {}
+        // SAFETY: `DefaultRepr` follows the requirements of `VectorBackend::Inner`.
+        unsafe impl<const N: usize, A: Alignment> DefaultBackend<N, A> for $T
+        where
+            Length<N>: SupportedLength,
+        {
+            type Repr = DefaultRepr<N, $T>;
+        }
 
         impl Zero for $T {
             const ZERO: Self = Self::ZERO;
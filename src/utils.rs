@@ -1,6 +1,10 @@
-#[cfg(any(
-    target_feature = "sse2",
-    all(target_arch = "aarch64", target_feature = "neon")
+#[cfg(all(
+    not(feature = "force-fallback"),
+    any(
+        target_feature = "sse2",
+        all(target_arch = "aarch64", target_feature = "neon"),
+        all(target_arch = "wasm32", target_feature = "simd128")
+    )
 ))]
 pub(crate) use crate::utils::safe_target_feature::*;
 #[cfg(feature = "wide")]
@@ -10,9 +14,13 @@ pub(crate) use crate::utils::{math::*, primitive_traits::*, repr::*, specialize:
 mod math;
 mod primitive_traits;
 mod repr;
-#[cfg(any(
-    target_feature = "sse2",
-    all(target_arch = "aarch64", target_feature = "neon")
+#[cfg(all(
+    not(feature = "force-fallback"),
+    any(
+        target_feature = "sse2",
+        all(target_arch = "aarch64", target_feature = "neon"),
+        all(target_arch = "wasm32", target_feature = "simd128")
+    )
 ))]
 mod safe_target_feature;
 mod specialize;
@@ -2,6 +2,15 @@
 #[allow(unused_imports, reason = "rustc incorrectly marks this as unused")]
 use crate::utils::PrimitiveFloatUtils;
 
+/// Software implementations of transcendental functions.
+///
+/// These are built only from basic IEEE 754 operations, so unlike the platform
+/// math library, they return bit-identical results on every target. They are
+/// used by the `libm` feature because [`std`] is unavailable, and by the
+/// `deterministic` feature for reproducibility.
+#[cfg(any(feature = "libm", feature = "deterministic"))]
+pub(crate) type SoftFloat<T> = libm::Libm<T>;
+
 pub trait FloatUtils {
     /// Approximate arc cosine.
     ///
@@ -2,6 +2,79 @@
 use libm::Libm;
 
 use crate::PrimitiveUnsigned;
+#[cfg(any(feature = "libm", feature = "deterministic"))]
+use crate::utils::SoftFloat;
+
+/// An internal trait that wraps primitive-number conversions used in generic
+/// contexts.
+///
+/// The `as_*` functions behave like `as` casts. The `fits_*` functions return
+/// `true` if the matching `as` cast neither saturates nor produces a non-finite
+/// value, ignoring the fractional part of floats converted to integers.
+pub(crate) trait PrimitiveNumberUtils: Sized + Copy {
+    fn as_f32(self) -> f32;
+
+    fn as_f64(self) -> f64;
+
+    fn as_i8(self) -> i8;
+
+    fn as_i16(self) -> i16;
+
+    fn as_i32(self) -> i32;
+
+    fn as_i64(self) -> i64;
+
+    fn as_i128(self) -> i128;
+
+    fn as_isize(self) -> isize;
+
+    fn as_u8(self) -> u8;
+
+    fn as_u16(self) -> u16;
+
+    fn as_u32(self) -> u32;
+
+    fn as_u64(self) -> u64;
+
+    fn as_u128(self) -> u128;
+
+    fn as_usize(self) -> usize;
+
+    fn fits_f32(self) -> bool;
+
+    fn fits_f64(self) -> bool;
+
+    fn fits_i8(self) -> bool;
+
+    fn fits_i16(self) -> bool;
+
+    fn fits_i32(self) -> bool;
+
+    fn fits_i64(self) -> bool;
+
+    fn fits_i128(self) -> bool;
+
+    fn fits_isize(self) -> bool;
+
+    fn fits_u8(self) -> bool;
+
+    fn fits_u16(self) -> bool;
+
+    fn fits_u32(self) -> bool;
+
+    fn fits_u64(self) -> bool;
+
+    fn fits_u128(self) -> bool;
+
+    fn fits_usize(self) -> bool;
+
+    /// Computes `value as Self`.
+    fn cast_from<T: PrimitiveNumberUtils>(value: T) -> Self;
+
+    /// Returns whether `value as Self` is in range (see the `fits_*`
+    /// functions).
+    fn fits_from<T: PrimitiveNumberUtils>(value: T) -> bool;
+}
 
 /// An internal trait that wraps primitive-float functions used in generic
 /// contexts.
@@ -179,6 +252,91 @@ pub(crate) trait PrimitiveSignedUtils: Sized {
     fn is_negative(self) -> bool;
 }
 
+macro_rules! impl_number {
+    ($T:ident: $kind:ident, $as_self:ident, $fits_self:ident) => {
+        impl_number!(
+            @impl $T: $kind, $as_self, $fits_self;
+            f32: float, as_f32, fits_f32;
+            f64: float, as_f64, fits_f64;
+            i8: int, as_i8, fits_i8;
+            i16: int, as_i16, fits_i16;
+            i32: int, as_i32, fits_i32;
+            i64: int, as_i64, fits_i64;
+            i128: int, as_i128, fits_i128;
+            isize: int, as_isize, fits_isize;
+            u8: int, as_u8, fits_u8;
+            u16: int, as_u16, fits_u16;
+            u32: int, as_u32, fits_u32;
+            u64: int, as_u64, fits_u64;
+            u128: int, as_u128, fits_u128;
+            usize: int, as_usize, fits_usize
+        );
+    };
+    (
+        @impl $T:ident: $kind:ident, $as_self:ident, $fits_self:ident;
+        $($U:ident: $ukind:ident, $as_u:ident, $fits_u:ident);*
+    ) => {
+        impl PrimitiveNumberUtils for $T {
+            $(
+                #[inline(always)]
+                #[allow(clippy::unnecessary_cast)]
+                fn $as_u(self) -> $U {
+                    self as $U
+                }
+
+                #[inline(always)]
+                #[allow(clippy::unnecessary_cast)]
+                fn $fits_u(self) -> bool {
+                    impl_number!(@fits self, $kind $T => $ukind $U)
+                }
+            )*
+
+            #[inline(always)]
+            fn cast_from<T: PrimitiveNumberUtils>(value: T) -> Self {
+                value.$as_self()
+            }
+
+            #[inline(always)]
+            fn fits_from<T: PrimitiveNumberUtils>(value: T) -> bool {
+                value.$fits_self()
+            }
+        }
+    };
+    (@fits $value:ident, int $T:ident => int $U:ident) => {
+        $U::try_from($value).is_ok()
+    };
+    (@fits $value:ident, int $T:ident => float $U:ident) => {
+        ($value as $U).is_finite()
+    };
+    (@fits $value:ident, float $T:ident => float $U:ident) => {
+        $value.is_finite() && ($value as $U).is_finite()
+    };
+    (@fits $value:ident, float $T:ident => int $U:ident) => {{
+        // `$U::MIN` and `$U::MAX + 1` are powers of two (or zero), so they
+        // are exactly representable unless they overflow to infinity, which
+        // still results in a correct comparison.
+        let truncated = <$T as PrimitiveFloatUtils>::trunc($value);
+
+        $value.is_finite()
+            && truncated >= $U::MIN as $T
+            && truncated < ($U::MAX / 2 + 1) as $T * 2.0
+    }};
+}
+impl_number!(f32: float, as_f32, fits_f32);
+impl_number!(f64: float, as_f64, fits_f64);
+impl_number!(i8: int, as_i8, fits_i8);
+impl_number!(i16: int, as_i16, fits_i16);
+impl_number!(i32: int, as_i32, fits_i32);
+impl_number!(i64: int, as_i64, fits_i64);
+impl_number!(i128: int, as_i128, fits_i128);
+impl_number!(isize: int, as_isize, fits_isize);
+impl_number!(u8: int, as_u8, fits_u8);
+impl_number!(u16: int, as_u16, fits_u16);
+impl_number!(u32: int, as_u32, fits_u32);
+impl_number!(u64: int, as_u64, fits_u64);
+impl_number!(u128: int, as_u128, fits_u128);
+impl_number!(usize: int, as_usize, fits_usize);
+
 macro_rules! impl_float {
     ($T:ident, $UnsignedT:ident) => {
         impl PrimitiveFloatUtils for $T {
@@ -361,16 +519,16 @@ macro_rules! impl_float {
                 if r < 0.0 { r + rhs.abs() } else { r }
             }
 
-            #[cfg(not(feature = "libm"))]
+            #[cfg(not(any(feature = "libm", feature = "deterministic")))]
             #[inline(always)]
             fn powf(self, n: Self) -> Self {
                 self.powf(n)
             }
 
-            #[cfg(feature = "libm")]
+            #[cfg(any(feature = "libm", feature = "deterministic"))]
             #[inline(always)]
             fn powf(self, n: Self) -> Self {
-                Libm::<$T>::pow(self, n)
+                SoftFloat::<$T>::pow(self, n)
             }
 
             #[cfg(not(feature = "libm"))]
@@ -385,148 +543,148 @@ macro_rules! impl_float {
                 Libm::<$T>::sqrt(self)
             }
 
-            #[cfg(not(feature = "libm"))]
+            #[cfg(not(any(feature = "libm", feature = "deterministic")))]
             #[inline(always)]
             fn exp(self) -> Self {
                 self.exp()
             }
 
-            #[cfg(feature = "libm")]
+            #[cfg(any(feature = "libm", feature = "deterministic"))]
             #[inline(always)]
             fn exp(self) -> Self {
-                Libm::<$T>::exp(self)
+                SoftFloat::<$T>::exp(self)
             }
 
-            #[cfg(not(feature = "libm"))]
+            #[cfg(not(any(feature = "libm", feature = "deterministic")))]
             #[inline(always)]
             fn exp2(self) -> Self {
                 self.exp2()
             }
 
-            #[cfg(feature = "libm")]
+            #[cfg(any(feature = "libm", feature = "deterministic"))]
             #[inline(always)]
             fn exp2(self) -> Self {
-                Libm::<$T>::exp2(self)
+                SoftFloat::<$T>::exp2(self)
             }
 
-            #[cfg(not(feature = "libm"))]
+            #[cfg(not(any(feature = "libm", feature = "deterministic")))]
             #[inline(always)]
             fn ln(self) -> Self {
                 self.ln()
             }
 
-            #[cfg(feature = "libm")]
+            #[cfg(any(feature = "libm", feature = "deterministic"))]
             #[inline(always)]
             fn ln(self) -> Self {
-                Libm::<$T>::log(self)
+                SoftFloat::<$T>::log(self)
             }
 
-            #[cfg(not(feature = "libm"))]
+            #[cfg(not(any(feature = "libm", feature = "deterministic")))]
             #[inline(always)]
             fn log2(self) -> Self {
                 self.log2()
             }
 
-            #[cfg(feature = "libm")]
+            #[cfg(any(feature = "libm", feature = "deterministic"))]
             #[inline(always)]
             fn log2(self) -> Self {
-                Libm::<$T>::log2(self)
+                SoftFloat::<$T>::log2(self)
             }
 
-            #[cfg(not(feature = "libm"))]
+            #[cfg(not(any(feature = "libm", feature = "deterministic")))]
             #[inline(always)]
             fn sin(self) -> Self {
                 self.sin()
             }
 
-            #[cfg(feature = "libm")]
+            #[cfg(any(feature = "libm", feature = "deterministic"))]
             #[inline(always)]
             fn sin(self) -> Self {
-                Libm::<$T>::sin(self)
+                SoftFloat::<$T>::sin(self)
             }
 
-            #[cfg(not(feature = "libm"))]
+            #[cfg(not(any(feature = "libm", feature = "deterministic")))]
             #[inline(always)]
             fn cos(self) -> Self {
                 self.cos()
             }
 
-            #[cfg(feature = "libm")]
+            #[cfg(any(feature = "libm", feature = "deterministic"))]
             #[inline(always)]
             fn cos(self) -> Self {
-                Libm::<$T>::cos(self)
+                SoftFloat::<$T>::cos(self)
             }
 
-            #[cfg(not(feature = "libm"))]
+            #[cfg(not(any(feature = "libm", feature = "deterministic")))]
             #[inline(always)]
             fn tan(self) -> Self {
                 self.tan()
             }
 
-            #[cfg(feature = "libm")]
+            #[cfg(any(feature = "libm", feature = "deterministic"))]
             #[inline(always)]
             fn tan(self) -> Self {
-                Libm::<$T>::tan(self)
+                SoftFloat::<$T>::tan(self)
             }
 
-            #[cfg(not(feature = "libm"))]
+            #[cfg(not(any(feature = "libm", feature = "deterministic")))]
             #[inline(always)]
             fn asin(self) -> Self {
                 self.asin()
             }
 
-            #[cfg(feature = "libm")]
+            #[cfg(any(feature = "libm", feature = "deterministic"))]
             #[inline(always)]
             fn asin(self) -> Self {
-                Libm::<$T>::asin(self)
+                SoftFloat::<$T>::asin(self)
             }
 
-            #[cfg(not(feature = "libm"))]
+            #[cfg(not(any(feature = "libm", feature = "deterministic")))]
             #[inline(always)]
             fn acos(self) -> Self {
                 self.acos()
             }
 
-            #[cfg(feature = "libm")]
+            #[cfg(any(feature = "libm", feature = "deterministic"))]
             #[inline(always)]
             fn acos(self) -> Self {
-                Libm::<$T>::acos(self)
+                SoftFloat::<$T>::acos(self)
             }
 
-            #[cfg(not(feature = "libm"))]
+            #[cfg(not(any(feature = "libm", feature = "deterministic")))]
             #[inline(always)]
             fn atan(self) -> Self {
                 self.atan()
             }
 
-            #[cfg(feature = "libm")]
+            #[cfg(any(feature = "libm", feature = "deterministic"))]
             #[inline(always)]
             fn atan(self) -> Self {
-                Libm::<$T>::atan(self)
+                SoftFloat::<$T>::atan(self)
             }
 
-            #[cfg(not(feature = "libm"))]
+            #[cfg(not(any(feature = "libm", feature = "deterministic")))]
             #[inline(always)]
             fn atan2(self, other: Self) -> Self {
                 self.atan2(other)
             }
 
-            #[cfg(feature = "libm")]
+            #[cfg(any(feature = "libm", feature = "deterministic"))]
             #[inline(always)]
             fn atan2(self, other: Self) -> Self {
-                Libm::<$T>::atan2(self, other)
+                SoftFloat::<$T>::atan2(self, other)
             }
 
-            #[cfg(not(feature = "libm"))]
+            #[cfg(not(any(feature = "libm", feature = "deterministic")))]
             #[inline(always)]
             fn sin_cos(self) -> (Self, Self) {
                 self.sin_cos()
             }
 
-            #[cfg(feature = "libm")]
+            #[cfg(any(feature = "libm", feature = "deterministic"))]
             #[inline(always)]
             fn sin_cos(self) -> (Self, Self) {
-                Libm::<$T>::sincos(self)
+                SoftFloat::<$T>::sincos(self)
             }
 
             #[cfg(not(feature = "num-primitive"))]
@@ -11,13 +11,21 @@ macro_rules! safe_target_feature {
         fn $f:ident($($param:ident: $Param:ty),* $(,)?) $(-> $Ret:ty)? $body:block
     )*) => {$(
         $(#[$meta])*
+        // Calling `#[target_feature]` functions is safe on wasm, because
+        // unsupported instructions fail validation instead of causing UB.
+        #[cfg_attr(target_arch = "wasm32", allow(unused_unsafe))]
         fn $f($($param: $Param),*) $(-> $Ret)? {
             // Use this pattern to add more features. Make sure to keep the
             // cfg correct, else there will be unsoundness.
             #[cfg_attr(target_feature = "sse2", target_feature(enable = "sse2"))]
             #[cfg_attr(target_feature = "ssse3", target_feature(enable = "ssse3"))]
             #[cfg_attr(target_feature = "sse4.1", target_feature(enable = "sse4.1"))]
+            #[cfg_attr(target_feature = "avx", target_feature(enable = "avx"))]
+            #[cfg_attr(target_feature = "avx2", target_feature(enable = "avx2"))]
+            #[cfg_attr(target_feature = "fma", target_feature(enable = "fma"))]
+            #[cfg_attr(target_feature = "f16c", target_feature(enable = "f16c"))]
             #[cfg_attr(target_feature = "neon", target_feature(enable = "neon"))]
+            #[cfg_attr(target_feature = "simd128", target_feature(enable = "simd128"))]
             #[inline]
             fn $f($($param: $Param),*) $(-> $Ret)? $body
 
@@ -17,7 +17,11 @@ use crate::{
 
 mod bool;
 mod float;
+#[cfg(feature = "f16")]
+mod half;
 mod integer;
+mod number;
+mod packing;
 mod signed;
 mod swizzle;
 mod unsigned;
@@ -28,6 +32,8 @@ mod wide_float;
 #[cfg(feature = "wide")]
 mod wide_integer;
 #[cfg(feature = "wide")]
+mod wide_packing;
+#[cfg(feature = "wide")]
 mod wide_signed;
 #[cfg(feature = "wide")]
 mod wide_unsigned;
@@ -746,7 +752,20 @@ where
     where
         T: Add<Output = T> + Mul<Output = T>,
     {
-        (self * rhs).element_sum()
+        specialize!(<T as VectorBackend<N, A>>::vector_dot(self, rhs))
+    }
+
+    /// Computes `(self * a) + b`. Depending on the target, the multiplication
+    /// and addition may be fused into a single rounding.
+    #[inline]
+    #[track_caller]
+    pub(crate) fn mul_add_contracted(self, a: Self, b: Self) -> Self
+    where
+        T: Add<Output = T> + Mul<Output = T>,
+    {
+        specialize!(<T as VectorBackend<N, A>>::vector_mul_add_contracted(
+            self, a, b
+        ))
     }
 
     /// Computes the squared length/magnitude of `self`.
@@ -771,7 +790,7 @@ where
     where
         T: Add<Output = T> + Mul<Output = T>,
     {
-        (self * self).element_sum()
+        self.dot(self)
     }
 
     /// Computes the squared Euclidean distance between `self` and `other`.
@@ -2661,7 +2680,10 @@ mod tests {
 
     use crate::{
         Aligned, Mask, Matrix, Unaligned, Vec2, Vec2A, Vec3, Vec3A, Vec4, Vec4A, Vector,
-        test_utils::{assert_panic, assert_panic_test_eq, assert_test_eq, for_types, random_iter},
+        test_utils::{
+            assert_panic, assert_panic_test_eq, assert_test_eq, for_types, mul_add_contracted,
+            random_iter,
+        },
         utils::{Repr2, Repr3, Repr4},
     };
 
@@ -3042,9 +3064,17 @@ mod tests {
                 let [x1, y1, z1, w1] = vector.to_array();
                 let [x2, y2, z2, w2] = other.to_array();
 
-                assert_test_eq!(vector.xy().dot(other.xy()), x1 * x2 + y1 * y2);
-                assert_test_eq!(vector.xyz().dot(other.xyz()), x1 * x2 + y1 * y2 + z1 * z2);
-                assert_test_eq!(vector.dot(other), x1 * x2 + y1 * y2 + (z1 * z2 + w1 * w2));
+                let mul_add = mul_add_contracted::<T, A>;
+
+                assert_test_eq!(vector.xy().dot(other.xy()), mul_add(y1, y2, x1 * x2));
+                assert_test_eq!(
+                    vector.xyz().dot(other.xyz()),
+                    mul_add(z1, z2, mul_add(y1, y2, x1 * x2))
+                );
+                assert_test_eq!(
+                    vector.dot(other),
+                    mul_add(y1, y2, x1 * x2) + mul_add(w1, w2, z1 * z2)
+                );
             }
         });
         for_types!(|T: PrimitiveInteger, A| {
@@ -3064,10 +3094,17 @@ mod tests {
         for_types!(|T: PrimitiveFloat, A| {
             for vector in random_iter::<Vector<4, T, A>>() {
                 let [x, y, z, w] = vector.to_array();
+                let mul_add = mul_add_contracted::<T, A>;
 
-                assert_test_eq!(vector.xy().length_squared(), x * x + y * y);
-                assert_test_eq!(vector.xyz().length_squared(), x * x + y * y + z * z);
-                assert_test_eq!(vector.length_squared(), x * x + y * y + (z * z + w * w));
+                assert_test_eq!(vector.xy().length_squared(), mul_add(y, y, x * x));
+                assert_test_eq!(
+                    vector.xyz().length_squared(),
+                    mul_add(z, z, mul_add(y, y, x * x))
+                );
+                assert_test_eq!(
+                    vector.length_squared(),
+                    mul_add(y, y, x * x) + mul_add(w, w, z * z)
+                );
             }
         });
         for_types!(|T: PrimitiveInteger, A| {
@@ -3087,19 +3124,20 @@ mod tests {
             for [vector, other] in random_iter::<[Vector<4, T, A>; 2]>() {
                 let [x1, y1, z1, w1] = vector.to_array();
                 let [x2, y2, z2, w2] = other.to_array();
+                let [dx, dy, dz, dw] = [x1 - x2, y1 - y2, z1 - z2, w1 - w2];
+                let mul_add = mul_add_contracted::<T, A>;
 
                 assert_test_eq!(
                     vector.xy().distance_squared(other.xy()),
-                    (x1 - x2) * (x1 - x2) + (y1 - y2) * (y1 - y2)
+                    mul_add(dy, dy, dx * dx)
                 );
                 assert_test_eq!(
                     vector.xyz().distance_squared(other.xyz()),
-                    (x1 - x2) * (x1 - x2) + (y1 - y2) * (y1 - y2) + (z1 - z2) * (z1 - z2)
+                    mul_add(dz, dz, mul_add(dy, dy, dx * dx))
                 );
                 assert_test_eq!(
                     vector.distance_squared(other),
-                    ((x1 - x2) * (x1 - x2) + (y1 - y2) * (y1 - y2))
-                        + ((z1 - z2) * (z1 - z2) + (w1 - w2) * (w1 - w2))
+                    mul_add(dy, dy, dx * dx) + mul_add(dw, dw, dz * dz)
                 );
             }
         });
@@ -3899,6 +3937,26 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_rem_f64_exact() {
+        for_types!(|N, A| {
+            for [a, b] in [
+                [1e17, 3.0],
+                [5.3, 0.1],
+                [-7.25, 2.0],
+                [1e300, 7.0],
+                [-1e300, 0.3],
+                [-0.0, 1.0],
+                [1.0, f64::INFINITY],
+                [1.0, 0.0],
+                [f64::INFINITY, 1.0],
+            ] {
+                let [a, b] = [a, b].map(Vector::<N, f64, A>::splat);
+                assert_test_eq!(a % b, Vector::from_fn(|i| a[i] % b[i]));
+            }
+        });
+    }
+
     #[test]
     fn test_rem_assign() {
         for_types!(|N, A| {
@@ -189,6 +189,63 @@ where
         Self::ONE / self
     }
 
+    /// Returns an approximation of [`recip`], `1 / self`.
+    ///
+    /// For aligned [`f32`] vectors on x86 and aarch64 this refines a hardware
+    /// estimate, and the relative error is at most `2^-12` (about `2.4e-4`).
+    /// Subnormal inputs and results may be flushed to zero. Otherwise, and with
+    /// the `deterministic` feature, this is exactly [`recip`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ggmath::Vec3A;
+    /// #
+    /// let vector = Vec3A::new(2.0, 3.0, 4.0);
+    /// let recip = vector.recip_fast();
+    ///
+    /// assert!((recip - vector.recip()).abs().max_element() < 1e-4);
+    /// ```
+    ///
+    /// [`recip`]: Self::recip
+    #[inline]
+    #[must_use]
+    pub fn recip_fast(self) -> Self {
+        if cfg!(feature = "deterministic") {
+            self.recip()
+        } else {
+            specialize!(<T as FloatVectorBackend<N, A>>::vector_recip_fast(self))
+        }
+    }
+
+    /// Returns an approximation of the element-wise reciprocal square root,
+    /// `1 / self.sqrt()`.
+    ///
+    /// For aligned [`f32`] vectors on x86 and aarch64 this refines a hardware
+    /// estimate, and the relative error is at most `2^-12` (about `2.4e-4`).
+    /// Subnormal inputs may be flushed to zero. Otherwise, and with the
+    /// `deterministic` feature, this is exactly `1 / self.sqrt()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ggmath::Vec3A;
+    /// #
+    /// let vector = Vec3A::new(4.0, 16.0, 0.25);
+    /// let rsqrt = vector.rsqrt();
+    ///
+    /// assert!((rsqrt - Vec3A::new(0.5, 0.25, 2.0)).abs().max_element() < 1e-3);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn rsqrt(self) -> Self {
+        if cfg!(feature = "deterministic") {
+            Self::ONE / self.sqrt()
+        } else {
+            specialize!(<T as FloatVectorBackend<N, A>>::vector_rsqrt(self))
+        }
+    }
+
     /// Returns the maximum elements between `self` and `other`.
     ///
     /// Equivalent to `(self.x.max(other.x), self.y.max(other.y), ...)`.
@@ -637,6 +694,10 @@ where
     /// The precision of this function is non-deterministic. This means it
     /// varies by platform, version, and can even differ within the same
     /// execution from one invocation to the next.
+    ///
+    /// For `Vec3A<f32>` and `Vec4A<f32>` on x86 and aarch64, this uses a
+    /// polynomial approximation with a maximum error of 1 ULP (units in the
+    /// last place), unless the `deterministic` feature is enabled.
     #[inline]
     #[must_use]
     pub fn exp(self) -> Self {
@@ -650,6 +711,10 @@ where
     /// The precision of this function is non-deterministic. This means it
     /// varies by platform, version, and can even differ within the same
     /// execution from one invocation to the next.
+    ///
+    /// For `Vec3A<f32>` and `Vec4A<f32>` on x86 and aarch64, this uses a
+    /// polynomial approximation with a maximum error of 2 ULP (units in the
+    /// last place), unless the `deterministic` feature is enabled.
     #[inline]
     #[must_use]
     pub fn exp2(self) -> Self {
@@ -663,6 +728,10 @@ where
     /// The precision of this function is non-deterministic. This means it
     /// varies by platform, version, and can even differ within the same
     /// execution from one invocation to the next.
+    ///
+    /// For `Vec3A<f32>` and `Vec4A<f32>` on x86 and aarch64, this uses a
+    /// polynomial approximation with a maximum error of 1 ULP (units in the
+    /// last place), unless the `deterministic` feature is enabled.
     #[inline]
     #[must_use]
     pub fn ln(self) -> Self {
@@ -677,6 +746,10 @@ where
     /// varies by platform, version, and can even differ within the same
     /// execution from one invocation to the next.
     ///
+    /// For `Vec3A<f32>` and `Vec4A<f32>` on x86 and aarch64, this uses a
+    /// polynomial approximation with a maximum error of 2 ULP (units in the
+    /// last place), unless the `deterministic` feature is enabled.
+    ///
     /// # Examples
     ///
     /// ```
@@ -699,6 +772,11 @@ where
     /// The precision of this function is non-deterministic. This means it
     /// varies by platform, version, and can even differ within the same
     /// execution from one invocation to the next.
+    ///
+    /// For `Vec3A<f32>` and `Vec4A<f32>` on x86 and aarch64, this uses a
+    /// polynomial approximation with a maximum error of 3 ULP (units in the
+    /// last place) for elements in `-8192.0..=8192.0`. Larger elements, and the
+    /// `deterministic` feature, use the scalar implementation.
     #[inline]
     #[must_use]
     pub fn sin(self) -> Self {
@@ -712,6 +790,11 @@ where
     /// The precision of this function is non-deterministic. This means it
     /// varies by platform, version, and can even differ within the same
     /// execution from one invocation to the next.
+    ///
+    /// For `Vec3A<f32>` and `Vec4A<f32>` on x86 and aarch64, this uses a
+    /// polynomial approximation with a maximum error of 3 ULP (units in the
+    /// last place) for elements in `-8192.0..=8192.0`. Larger elements, and the
+    /// `deterministic` feature, use the scalar implementation.
     #[inline]
     #[must_use]
     pub fn cos(self) -> Self {
@@ -751,6 +834,10 @@ where
     /// The precision of this function is non-deterministic. This means it
     /// varies by platform, version, and can even differ within the same
     /// execution from one invocation to the next.
+    ///
+    /// For `Vec3A<f32>` and `Vec4A<f32>` on x86 and aarch64, this uses a
+    /// polynomial approximation with a maximum error of 2 ULP (units in the
+    /// last place), unless the `deterministic` feature is enabled.
     #[inline]
     #[must_use]
     pub fn acos(self) -> Self {
@@ -764,6 +851,10 @@ where
     /// The precision of this function is non-deterministic. This means it
     /// varies by platform, version, and can even differ within the same
     /// execution from one invocation to the next.
+    ///
+    /// For `Vec3A<f32>` and `Vec4A<f32>` on x86 and aarch64, this uses a
+    /// polynomial approximation with a maximum error of 3 ULP (units in the
+    /// last place), unless the `deterministic` feature is enabled.
     #[inline]
     #[must_use]
     pub fn atan(self) -> Self {
@@ -780,6 +871,11 @@ where
     /// The precision of this function is non-deterministic. This means it
     /// varies by platform, version, and can even differ within the same
     /// execution from one invocation to the next.
+    ///
+    /// For `Vec3A<f32>` and `Vec4A<f32>` on x86 and aarch64, this uses a
+    /// polynomial approximation with a maximum error of 3 ULP (units in the
+    /// last place) for elements in `-8192.0..=8192.0`. Larger elements, and the
+    /// `deterministic` feature, use the scalar implementation.
     #[inline]
     #[must_use]
     pub fn sin_cos(self) -> (Self, Self) {
@@ -898,6 +994,33 @@ where
         PrimitiveFloatUtils::sqrt(self.dot(self))
     }
 
+    /// Returns an approximation of `1 / self.length()`.
+    ///
+    /// This has the same error bounds as [`rsqrt`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ggmath::Vec3A;
+    /// #
+    /// let vector = Vec3A::new(2.0, 3.0, 6.0);
+    ///
+    /// assert!((vector.length_recip_fast() - 1.0_f32 / 7.0).abs() < 1e-4);
+    /// ```
+    ///
+    /// [`rsqrt`]: Self::rsqrt
+    #[inline]
+    #[must_use]
+    pub fn length_recip_fast(self) -> T {
+        if cfg!(feature = "deterministic") {
+            T::ONE / self.length()
+        } else {
+            specialize!(<T as FloatVectorBackend<N, A>>::vector_length_recip_fast(
+                self
+            ))
+        }
+    }
+
     /// Computes the Euclidean distance between `self` and `other`.
     ///
     /// # Examples
@@ -948,6 +1071,51 @@ where
         result
     }
 
+    /// Returns an approximation of [`normalize`].
+    ///
+    /// This scales `self` by [`length_recip_fast`], and has the same error
+    /// bounds as [`rsqrt`]. Without a fast approximation, and with the
+    /// `deterministic` feature, this is exactly [`normalize`].
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled:
+    ///
+    /// Panics if `self` is a zero vector, or if the result is non finite or
+    /// zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ggmath::Vec3A;
+    /// #
+    /// let vector = Vec3A::new(1.0, 2.0, 3.0);
+    /// let normalize = vector.normalize_fast();
+    ///
+    /// assert!((normalize - vector.normalize()).abs().max_element() < 1e-4);
+    /// ```
+    ///
+    /// [`normalize`]: Self::normalize
+    /// [`length_recip_fast`]: Self::length_recip_fast
+    /// [`rsqrt`]: Self::rsqrt
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn normalize_fast(self) -> Self {
+        let result = if cfg!(feature = "deterministic") {
+            self / self.length()
+        } else {
+            specialize!(<T as FloatVectorBackend<N, A>>::vector_normalize_fast(self))
+        };
+
+        debug_assert!(
+            result.is_finite() && result != Self::ZERO,
+            "vector is zero or non-finite: {self:?}.normalize_fast()"
+        );
+
+        result
+    }
+
     /// Returns [`normalize`], or `None` if `self` is zero or if the result is
     /// non finite or zero.
     ///
@@ -2014,10 +2182,10 @@ mod tests {
     #[cfg(not(feature = "num-primitive"))]
     use crate::utils::PrimitiveFloatUtils;
     use crate::{
-        FloatExt, Mask, Vec2A, Vec3A, Vector,
+        FloatExt, Mask, Unaligned, Vec2A, Vec3A, Vec4A, Vector,
         test_utils::{
             assert_debug_panic, assert_panic_test_eq, assert_test_eq, assert_test_eq_or_panic,
-            for_types, random_iter,
+            contraction_tolerance, for_types, is_contracted, mul_add_contracted, random_iter,
         },
     };
 
@@ -2077,7 +2245,11 @@ mod tests {
     #[test]
     fn test_sign_positive_mask() {
         for_types!(|N, T: PrimitiveFloat, A| {
-            for vector in random_iter::<Vector<N, T, A>>() {
+            for vector in [0.0, -0.0, T::INFINITY, T::NEG_INFINITY, T::NAN, -T::NAN]
+                .map(Vector::<N, T, A>::splat)
+                .into_iter()
+                .chain(random_iter())
+            {
                 assert_eq!(
                     vector.sign_positive_mask(),
                     Mask::from_fn(|i| vector[i].is_sign_positive())
@@ -2089,7 +2261,11 @@ mod tests {
     #[test]
     fn test_sign_negative_mask() {
         for_types!(|N, T: PrimitiveFloat, A| {
-            for vector in random_iter::<Vector<N, T, A>>() {
+            for vector in [0.0, -0.0, T::INFINITY, T::NEG_INFINITY, T::NAN, -T::NAN]
+                .map(Vector::<N, T, A>::splat)
+                .into_iter()
+                .chain(random_iter())
+            {
                 assert_eq!(
                     vector.sign_negative_mask(),
                     Mask::from_fn(|i| vector[i].is_sign_negative())
@@ -2107,6 +2283,38 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_recip_fast() {
+        for_types!(|N, T: PrimitiveFloat, A| {
+            for vector in random_iter::<Vector<N, T, A>>() {
+                let expected = vector.recip();
+                if (0..N).any(|i| vector[i].is_subnormal() || expected[i].is_subnormal()) {
+                    continue;
+                }
+
+                assert_test_eq!(
+                    vector.recip_fast(),
+                    expected,
+                    abs <= expected.abs() / 4096.0
+                );
+            }
+        });
+    }
+
+    #[test]
+    fn test_rsqrt() {
+        for_types!(|N, T: PrimitiveFloat, A| {
+            for vector in random_iter::<Vector<N, T, A>>() {
+                if (0..N).any(|i| vector[i].is_subnormal()) {
+                    continue;
+                }
+
+                let expected = vector.map(|x| 1.0 / x.sqrt());
+                assert_test_eq!(vector.rsqrt(), expected, abs <= expected.abs() / 4096.0);
+            }
+        });
+    }
+
     #[test]
     fn test_max() {
         for_types!(|N, T: PrimitiveFloat, A| {
@@ -2288,6 +2496,101 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_rounding_f64_precision_limit() {
+        const LIMIT: f64 = 4503599627370496.0;
+
+        for_types!(|N, A| {
+            for vector in [
+                LIMIT - 1.5,
+                LIMIT - 1.0,
+                LIMIT - 0.75,
+                LIMIT - 0.5,
+                LIMIT - 0.25,
+                LIMIT,
+                LIMIT + 1.0,
+                LIMIT * 2.0,
+                LIMIT * 2.0 + 2.0,
+                -0.0,
+                -0.25,
+                -0.5,
+                -0.75,
+                f64::INFINITY,
+                f64::NAN,
+            ]
+            .into_iter()
+            .flat_map(|x| [x, -x])
+            .map(Vector::<N, f64, A>::splat)
+            {
+                assert_test_eq!(vector.floor(), vector.map(f64::floor));
+                assert_test_eq!(vector.ceil(), vector.map(f64::ceil));
+                assert_test_eq!(vector.round(), vector.map(f64::round));
+                assert_test_eq!(vector.trunc(), vector.map(f64::trunc));
+            }
+        });
+    }
+
+    #[test]
+    fn test_simd_bits_eq_fallback() {
+        for_types!(|N, T: PrimitiveFloat, A| {
+            let bits = |vector: Vector<N, T, A>| {
+                vector
+                    .to_alignment::<Unaligned>()
+                    .map(|x| if x.is_nan() { T::NAN } else { x })
+                    .to_bits()
+            };
+            let scalar_bits = |x: T| if x.is_nan() { T::NAN } else { x }.to_bits();
+
+            for (vector, other, mask) in [
+                0.0,
+                (0.5 as T).next_down(),
+                0.5,
+                1.5,
+                2.5,
+                8388607.5,
+                8388608.0,
+                16777215.0,
+                1e30,
+                T::MIN_POSITIVE,
+                T::INFINITY,
+                T::NAN,
+            ]
+            .into_iter()
+            .flat_map(|x| [x, -x])
+            .map(|x| {
+                (
+                    Vector::<N, T, A>::splat(x),
+                    Vector::splat(-x),
+                    Mask::from_fn(|i| i % 2 == 0),
+                )
+            })
+            .chain(random_iter())
+            {
+                let fallback = vector.to_alignment::<Unaligned>();
+                let fallback_other = other.to_alignment::<Unaligned>();
+
+                assert_eq!(bits(vector.floor()), bits(fallback.floor().to_alignment()));
+                assert_eq!(bits(vector.ceil()), bits(fallback.ceil().to_alignment()));
+                assert_eq!(bits(vector.round()), bits(fallback.round().to_alignment()));
+                assert_eq!(bits(vector.trunc()), bits(fallback.trunc().to_alignment()));
+                if !is_contracted::<T, A>() {
+                    assert_eq!(
+                        scalar_bits(vector.dot(other)),
+                        scalar_bits(fallback.dot(fallback_other))
+                    );
+                }
+                assert_eq!(
+                    mask.select(vector, other)
+                        .to_alignment::<Unaligned>()
+                        .to_bits(),
+                    mask.to_alignment::<Unaligned>()
+                        .select(fallback, fallback_other)
+                        .to_bits()
+                );
+            }
+        });
+    }
+
     #[test]
     fn test_fract() {
         for_types!(|N, T: PrimitiveFloat, A| {
@@ -2490,6 +2793,72 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_f32_max_ulp_error() {
+        // Checks the error bounds documented for `Vec3A<f32>` and `Vec4A<f32>`
+        // against `f64` results, on a sample of every `f32` bit pattern.
+        fn ulp_error(result: f32, expected: f64) -> f64 {
+            let rounded = expected as f32;
+            if result.is_nan() || rounded.is_nan() || result.is_infinite() || rounded.is_infinite()
+            {
+                return if result.to_bits() == rounded.to_bits()
+                    || (result.is_nan() && rounded.is_nan())
+                {
+                    0.0
+                } else {
+                    f64::INFINITY
+                };
+            }
+
+            let exponent = rounded.abs().max(f32::MIN_POSITIVE).log2().floor() as i32;
+            (result as f64 - expected).abs() / 2f64.powi(exponent - 23)
+        }
+
+        let check = |name: &str,
+                     max_error: f64,
+                     f: fn(Vec3A<f32>) -> Vec3A<f32>,
+                     g: fn(Vec4A<f32>) -> Vec4A<f32>,
+                     expected: fn(f64) -> f64| {
+            for bits in (0..=u32::MAX).step_by(16381) {
+                let x = f32::from_bits(bits);
+                let vec3 = Vec3A::new(x, -x, x * 0.5);
+                let vec4 = Vec4A::new(x, -x, x * 0.5, x * 3.0);
+
+                for (result, x) in f(vec3).to_array().into_iter().zip(vec3.to_array()) {
+                    let error = ulp_error(result, expected(x as f64));
+                    assert!(error <= max_error, "`{name}({x:e})` has {error} ULP error");
+                }
+                for (result, x) in g(vec4).to_array().into_iter().zip(vec4.to_array()) {
+                    let error = ulp_error(result, expected(x as f64));
+                    assert!(error <= max_error, "`{name}({x:e})` has {error} ULP error");
+                }
+            }
+        };
+
+        check("exp", 1.0, |v| v.exp(), |v| v.exp(), f64::exp);
+        check("exp2", 2.0, |v| v.exp2(), |v| v.exp2(), f64::exp2);
+        check("ln", 1.0, |v| v.ln(), |v| v.ln(), f64::ln);
+        check("log2", 2.0, |v| v.log2(), |v| v.log2(), f64::log2);
+        check("sin", 3.0, |v| v.sin(), |v| v.sin(), f64::sin);
+        check("cos", 3.0, |v| v.cos(), |v| v.cos(), f64::cos);
+        check("acos", 2.0, |v| v.acos(), |v| v.acos(), f64::acos);
+        check("atan", 3.0, |v| v.atan(), |v| v.atan(), f64::atan);
+        check(
+            "sin_cos.0",
+            3.0,
+            |v| v.sin_cos().0,
+            |v| v.sin_cos().0,
+            f64::sin,
+        );
+        check(
+            "sin_cos.1",
+            3.0,
+            |v| v.sin_cos().1,
+            |v| v.sin_cos().1,
+            f64::cos,
+        );
+    }
+
     #[test]
     fn test_lerp() {
         for_types!(|N, T: PrimitiveFloat, A| {
@@ -2659,6 +3028,8 @@ mod tests {
     #[test]
     fn test_length() {
         for_types!(|T: PrimitiveFloat, A| {
+            let mul_add = mul_add_contracted::<T, A>;
+
             for vector in [
                 [0.0, 0.0],
                 [-0.0, -0.0],
@@ -2672,7 +3043,7 @@ mod tests {
             {
                 let [x, y] = vector.to_array();
 
-                assert_test_eq!(vector.length(), (x * x + y * y).sqrt());
+                assert_test_eq!(vector.length(), mul_add(y, y, x * x).sqrt());
             }
 
             for vector in [
@@ -2688,7 +3059,7 @@ mod tests {
             {
                 let [x, y, z] = vector.to_array();
 
-                assert_test_eq!(vector.length(), (x * x + y * y + z * z).sqrt());
+                assert_test_eq!(vector.length(), mul_add(z, z, mul_add(y, y, x * x)).sqrt());
             }
 
             for vector in [
@@ -2704,7 +3075,10 @@ mod tests {
             {
                 let [x, y, z, w] = vector.to_array();
 
-                assert_test_eq!(vector.length(), (x * x + y * y + (z * z + w * w)).sqrt());
+                assert_test_eq!(
+                    vector.length(),
+                    (mul_add(y, y, x * x) + mul_add(w, w, z * z)).sqrt()
+                );
             }
         });
     }
@@ -2712,23 +3086,26 @@ mod tests {
     #[test]
     fn test_distance() {
         for_types!(|T: PrimitiveFloat, A| {
+            let mul_add = mul_add_contracted::<T, A>;
+
             for [vector, other] in random_iter::<[Vector<2, T, A>; 2]>() {
                 let [x1, y1] = vector.to_array();
                 let [x2, y2] = other.to_array();
 
-                assert_test_eq!(
-                    vector.distance(other),
-                    ((x1 - x2) * (x1 - x2) + (y1 - y2) * (y1 - y2)).sqrt()
-                );
+                let [dx, dy] = [x1 - x2, y1 - y2];
+
+                assert_test_eq!(vector.distance(other), mul_add(dy, dy, dx * dx).sqrt());
             }
 
             for [vector, other] in random_iter::<[Vector<3, T, A>; 2]>() {
                 let [x1, y1, z1] = vector.to_array();
                 let [x2, y2, z2] = other.to_array();
 
+                let [dx, dy, dz] = [x1 - x2, y1 - y2, z1 - z2];
+
                 assert_test_eq!(
                     vector.distance(other),
-                    ((x1 - x2) * (x1 - x2) + (y1 - y2) * (y1 - y2) + (z1 - z2) * (z1 - z2)).sqrt()
+                    mul_add(dz, dz, mul_add(dy, dy, dx * dx)).sqrt()
                 );
             }
 
@@ -2736,11 +3113,11 @@ mod tests {
                 let [x1, y1, z1, w1] = vector.to_array();
                 let [x2, y2, z2, w2] = other.to_array();
 
+                let [dx, dy, dz, dw] = [x1 - x2, y1 - y2, z1 - z2, w1 - w2];
+
                 assert_test_eq!(
                     vector.distance(other),
-                    (((x1 - x2) * (x1 - x2) + (y1 - y2) * (y1 - y2))
-                        + ((z1 - z2) * (z1 - z2) + (w1 - w2) * (w1 - w2)))
-                        .sqrt()
+                    (mul_add(dy, dy, dx * dx) + mul_add(dw, dw, dz * dz)).sqrt()
                 );
             }
         });
@@ -2764,6 +3141,47 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_length_recip_fast() {
+        for_types!(|N, T: PrimitiveFloat, A| {
+            for vector in random_iter::<Vector<N, T, A>>() {
+                if vector.dot(vector).is_subnormal() {
+                    continue;
+                }
+
+                let expected = 1.0 / vector.length();
+                assert_test_eq!(
+                    vector.length_recip_fast(),
+                    expected,
+                    abs <= expected.abs() / 4096.0
+                );
+            }
+        });
+    }
+
+    #[test]
+    fn test_normalize_fast() {
+        for_types!(|N, T: PrimitiveFloat, A| {
+            for vector in [Vector::<N, T, A>::ZERO].into_iter().chain(random_iter()) {
+                if !vector.length().is_finite() || !vector.length().recip().is_finite() {
+                    assert_debug_panic!(vector.normalize_fast());
+                    continue;
+                }
+
+                if vector.dot(vector).is_subnormal() {
+                    continue;
+                }
+
+                let expected = vector.normalize();
+                assert_test_eq!(
+                    vector.normalize_fast(),
+                    expected,
+                    abs <= expected.abs() / 4096.0 + T::MIN_POSITIVE
+                );
+            }
+        });
+    }
+
     #[test]
     fn test_try_normalize() {
         for_types!(|N, T: PrimitiveFloat, A| {
@@ -3229,7 +3647,11 @@ mod tests {
                     Vector::ZERO
                 };
 
-                assert_test_eq!(vector.any_orthogonal_vector().dot(vector), 0.0);
+                assert_test_eq!(
+                    vector.any_orthogonal_vector().dot(vector),
+                    0.0,
+                    abs <= contraction_tolerance::<T, A>(vector.length_squared()),
+                );
                 if vector != Vector::ZERO {
                     assert!(vector.any_orthogonal_vector() != Vector::ZERO);
                 }
@@ -0,0 +1,189 @@
+use half::f16;
+
+use crate::{Alignment, Length, SupportedLength, Vector, backend::HalfBackend, utils::specialize};
+
+impl<const N: usize, A: Alignment> Vector<N, f32, A>
+where
+    Length<N>: SupportedLength,
+{
+    /// Converts the elements of `self` to [`f16`], rounding to the nearest
+    /// representable value.
+    ///
+    /// Values too large for [`f16`] become infinity, and NaN stays NaN.
+    ///
+    /// On x86 with the `f16c` target feature and on aarch64, 4-element vectors
+    /// are converted with a single instruction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ggmath::Vec4;
+    /// # use half::f16;
+    /// #
+    /// let vector = Vec4::new(1.0f32, -0.5, 1e6, 0.1);
+    ///
+    /// assert_eq!(
+    ///     vector.as_f16(),
+    ///     Vec4::new(f16::ONE, f16::from_f32(-0.5), f16::INFINITY, f16::from_f32(0.1)),
+    /// );
+    /// ```
+    ///
+    /// [`f16`]: half::f16
+    #[inline]
+    #[must_use]
+    pub fn as_f16(self) -> Vector<N, f16, A> {
+        specialize!(<f32 as HalfBackend<N, A>>::vector_to_f16(self))
+    }
+}
+
+impl<const N: usize, A: Alignment> Vector<N, f64, A>
+where
+    Length<N>: SupportedLength,
+{
+    /// Converts the elements of `self` to [`f16`], rounding to the nearest
+    /// representable value.
+    ///
+    /// Values too large for [`f16`] become infinity, and NaN stays NaN.
+    ///
+    /// [`f16`]: half::f16
+    #[inline]
+    #[must_use]
+    pub fn as_f16(self) -> Vector<N, f16, A> {
+        self.map(f16::from_f64)
+    }
+}
+
+impl<const N: usize, A: Alignment> Vector<N, f16, A>
+where
+    Length<N>: SupportedLength,
+{
+    /// Converts the elements of `self` to [`f32`]. This conversion is lossless.
+    ///
+    /// On x86 with the `f16c` target feature and on aarch64, 4-element vectors
+    /// are converted with a single instruction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ggmath::Vec4;
+    /// # use half::f16;
+    /// #
+    /// let vector = Vec4::new(f16::ONE, f16::NEG_ONE, f16::INFINITY, f16::from_f32(0.5));
+    ///
+    /// assert_eq!(vector.as_f32(), Vec4::new(1.0, -1.0, f32::INFINITY, 0.5));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn as_f32(self) -> Vector<N, f32, A> {
+        specialize!(<f32 as HalfBackend<N, A>>::vector_from_f16(self))
+    }
+
+    /// Converts the elements of `self` to [`f64`]. This conversion is lossless.
+    #[inline]
+    #[must_use]
+    pub fn as_f64(self) -> Vector<N, f64, A> {
+        self.map(f16::to_f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use half::f16;
+
+    use crate::{
+        Vector,
+        test_utils::{for_types, random_iter},
+    };
+
+    #[test]
+    fn test_as_f16() {
+        for_types!(|N, A| {
+            for vector in random_iter::<Vector<N, f32, A>>() {
+                assert_eq!(
+                    vector.as_f16().to_array().map(f16::to_bits),
+                    vector.map(f16::from_f32).to_array().map(f16::to_bits)
+                );
+            }
+
+            for vector in random_iter::<Vector<N, f64, A>>() {
+                assert_eq!(
+                    vector.as_f16().to_array().map(f16::to_bits),
+                    vector.map(f16::from_f64).to_array().map(f16::to_bits)
+                );
+            }
+        });
+    }
+
+    #[test]
+    fn test_as_f16_edge_cases() {
+        for_types!(|A| {
+            // 2049 and 2051 are halfway between representable values and round
+            // to even, 65520 is the smallest value that overflows to infinity,
+            // and 2^-25 is halfway between 0 and the smallest subnormal.
+            let vector = Vector::<4, f32, A>::new(2049.0, 2051.0, 65520.0, 2f32.powi(-25));
+            assert_eq!(
+                vector.as_f16(),
+                Vector::<4, _, A>::new(
+                    f16::from_f32(2048.0),
+                    f16::from_f32(2052.0),
+                    f16::INFINITY,
+                    f16::ZERO
+                )
+            );
+
+            let vector = Vector::<4, f32, A>::new(65504.0, -6e-8, f32::NEG_INFINITY, -0.0);
+            assert_eq!(
+                vector.as_f16().to_array().map(f16::to_bits),
+                [
+                    f16::MAX.to_bits(),
+                    f16::from_bits(0x8001).to_bits(),
+                    f16::NEG_INFINITY.to_bits(),
+                    f16::NEG_ZERO.to_bits()
+                ]
+            );
+
+            let vector = Vector::<4, f32, A>::new(f32::NAN, 1.0, 2.0, 3.0);
+            assert!(vector.as_f16().x.is_nan());
+        });
+    }
+
+    #[test]
+    fn test_from_f16() {
+        for_types!(|N, A| {
+            for vector in random_iter::<Vector<N, f32, A>>() {
+                let vector = vector.as_f16();
+
+                assert_eq!(
+                    vector.as_f32().to_array().map(f32::to_bits),
+                    vector.map(f16::to_f32).to_array().map(f32::to_bits)
+                );
+                assert_eq!(
+                    vector.as_f64().to_array().map(f64::to_bits),
+                    vector.map(f16::to_f64).to_array().map(f64::to_bits)
+                );
+            }
+        });
+
+        for_types!(|A| {
+            let vector = Vector::<4, f16, A>::new(
+                f16::MIN_POSITIVE_SUBNORMAL,
+                f16::MAX,
+                f16::NEG_INFINITY,
+                f16::NEG_ZERO,
+            );
+            assert_eq!(
+                vector.as_f32().to_array().map(f32::to_bits),
+                [2f32.powi(-24), 65504.0, f32::NEG_INFINITY, -0.0].map(f32::to_bits)
+            );
+            assert!(Vector::<4, f16, A>::splat(f16::NAN).as_f32().x.is_nan());
+
+            for bits in 0..=u16::MAX {
+                let value = f16::from_bits(bits);
+                let vector = Vector::<4, f16, A>::splat(value);
+                if !value.is_nan() {
+                    assert_eq!(vector.as_f32().as_f16(), vector);
+                }
+            }
+        });
+    }
+}
@@ -305,7 +305,7 @@ impl_integer!(usize);
 #[cfg(test)]
 mod tests {
     use crate::{
-        Vector,
+        Unaligned, Vector,
         test_utils::{assert_panic_test_eq, assert_test_eq_or_panic, for_types, random_iter},
     };
 
@@ -735,4 +735,83 @@ mod tests {
             }
         });
     }
+
+    #[test]
+    fn test_simd_eq_fallback() {
+        for_types!(|N, T: PrimitiveInteger, A| {
+            let edges = [T::MIN, T::MAX, 0, 1, T::MAX / 2 + 1];
+            let edge_iter = (0..edges.len()).flat_map(|j| {
+                (0..edges.len()).map(move |k| {
+                    [
+                        Vector::<N, T, A>::from_fn(|i| edges[(i + j) % edges.len()]),
+                        Vector::from_fn(|i| edges[(i * 3 + k) % edges.len()]),
+                    ]
+                })
+            });
+
+            for [vector, other] in edge_iter.chain(random_iter()) {
+                let fallback = vector.to_alignment::<Unaligned>();
+                let fallback_other = other.to_alignment::<Unaligned>();
+
+                assert_eq!(
+                    vector.wrapping_add(other),
+                    fallback.wrapping_add(fallback_other).to_alignment()
+                );
+                assert_eq!(
+                    vector.wrapping_sub(other),
+                    fallback.wrapping_sub(fallback_other).to_alignment()
+                );
+                assert_eq!(
+                    vector.wrapping_mul(other),
+                    fallback.wrapping_mul(fallback_other).to_alignment()
+                );
+                assert_eq!(
+                    vector.saturating_add(other),
+                    fallback.saturating_add(fallback_other).to_alignment()
+                );
+                assert_eq!(
+                    vector.saturating_sub(other),
+                    fallback.saturating_sub(fallback_other).to_alignment()
+                );
+                assert_eq!(
+                    vector.checked_add(other),
+                    fallback
+                        .checked_add(fallback_other)
+                        .map(Vector::to_alignment)
+                );
+                assert_eq!(
+                    vector.checked_sub(other),
+                    fallback
+                        .checked_sub(fallback_other)
+                        .map(Vector::to_alignment)
+                );
+                assert_eq!(
+                    vector.max(other),
+                    fallback.max(fallback_other).to_alignment()
+                );
+                assert_eq!(
+                    vector.min(other),
+                    fallback.min(fallback_other).to_alignment()
+                );
+                assert_eq!(vector.max_element(), fallback.max_element());
+                assert_eq!(vector.min_element(), fallback.min_element());
+                assert_eq!(
+                    vector.lt_mask(other).to_alignment::<Unaligned>(),
+                    fallback.lt_mask(fallback_other)
+                );
+                assert_eq!(
+                    vector.gt_mask(other).to_alignment::<Unaligned>(),
+                    fallback.gt_mask(fallback_other)
+                );
+                assert_eq!(
+                    vector.le_mask(other).to_alignment::<Unaligned>(),
+                    fallback.le_mask(fallback_other)
+                );
+                assert_eq!(
+                    vector.ge_mask(other).to_alignment::<Unaligned>(),
+                    fallback.ge_mask(fallback_other)
+                );
+            }
+        });
+    }
 }
@@ -0,0 +1,234 @@
+use crate::{Alignment, Length, PrimitiveNumber, SupportedLength, Vector};
+
+impl<const N: usize, T, A: Alignment> Vector<N, T, A>
+where
+    Length<N>: SupportedLength,
+    T: PrimitiveNumber,
+{
+    /// Converts the elements of `self` to `U` with `as` semantics.
+    ///
+    /// Floats are truncated and saturated when converted to integers, with NaN
+    /// converted to `0`. See [`try_cast`] for a checked conversion.
+    ///
+    /// Equivalent to `(self.x as U, self.y as U, ...)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ggmath::Vec3;
+    /// #
+    /// let vector = Vec3::new(1.5, -2.5, 300.0);
+    ///
+    /// assert_eq!(vector.cast::<i32>(), Vec3::new(1, -2, 300));
+    /// assert_eq!(vector.cast::<u8>(), Vec3::new(1, 0, 255));
+    /// ```
+    ///
+    /// [`try_cast`]: Self::try_cast
+    #[inline]
+    #[must_use]
+    pub fn cast<U: PrimitiveNumber>(self) -> Vector<N, U, A> {
+        U::vector_cast_from(self)
+    }
+
+    /// Converts the elements of `self` to `U`, returning `None` if any element
+    /// is non-finite or out of the range of `U`.
+    ///
+    /// Floats converted to integers are truncated, so the fractional part of
+    /// an element does not cause the conversion to fail.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ggmath::Vec3;
+    /// #
+    /// assert_eq!(Vec3::new(1.5, -2.5, 3.0).try_cast::<i32>(), Some(Vec3::new(1, -2, 3)));
+    /// assert_eq!(Vec3::new(1.0, -2.0, 3.0).try_cast::<u32>(), None);
+    /// assert_eq!(Vec3::new(1.0, 2.0, f32::NAN).try_cast::<i32>(), None);
+    /// assert_eq!(Vec3::new(1.0, 2.0, 1e300).try_cast::<f32>(), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn try_cast<U: PrimitiveNumber>(self) -> Option<Vector<N, U, A>> {
+        if self.cast_fits::<U>() {
+            Some(self.cast())
+        } else {
+            None
+        }
+    }
+
+    /// Converts the elements of `self` to [`f32`] with `as` semantics.
+    ///
+    /// Equivalent to `self.cast::<f32>()`.
+    #[inline]
+    #[must_use]
+    pub fn as_f32(self) -> Vector<N, f32, A> {
+        self.cast()
+    }
+
+    /// Converts the elements of `self` to [`f64`] with `as` semantics.
+    ///
+    /// Equivalent to `self.cast::<f64>()`.
+    #[inline]
+    #[must_use]
+    pub fn as_f64(self) -> Vector<N, f64, A> {
+        self.cast()
+    }
+
+    /// Converts the elements of `self` to [`i32`] with `as` semantics.
+    ///
+    /// Equivalent to `self.cast::<i32>()`.
+    #[inline]
+    #[must_use]
+    pub fn as_i32(self) -> Vector<N, i32, A> {
+        self.cast()
+    }
+
+    /// Converts the elements of `self` to [`u32`] with `as` semantics.
+    ///
+    /// Equivalent to `self.cast::<u32>()`.
+    #[inline]
+    #[must_use]
+    pub fn as_u32(self) -> Vector<N, u32, A> {
+        self.cast()
+    }
+
+    /// Returns `true` if [`try_cast`] would succeed.
+    ///
+    /// [`try_cast`]: Self::try_cast
+    #[inline]
+    pub(crate) fn cast_fits<U: PrimitiveNumber>(self) -> bool {
+        self.iter().all(U::fits_from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Vec3, Vector,
+        test_utils::{assert_test_eq, for_types, random_iter},
+    };
+
+    #[test]
+    fn test_cast() {
+        for_types!(|N, T: PrimitiveNumber, A| {
+            for vector in random_iter::<Vector<N, T, A>>() {
+                assert_test_eq!(vector.cast::<f32>(), vector.map(|x| x.as_to::<f32>()));
+                assert_test_eq!(vector.cast::<f64>(), vector.map(|x| x.as_to::<f64>()));
+                assert_eq!(vector.cast::<i8>(), vector.map(|x| x.as_to::<i8>()));
+                assert_eq!(vector.cast::<i32>(), vector.map(|x| x.as_to::<i32>()));
+                assert_eq!(vector.cast::<i64>(), vector.map(|x| x.as_to::<i64>()));
+                assert_eq!(vector.cast::<u8>(), vector.map(|x| x.as_to::<u8>()));
+                assert_eq!(vector.cast::<u32>(), vector.map(|x| x.as_to::<u32>()));
+                assert_eq!(vector.cast::<u128>(), vector.map(|x| x.as_to::<u128>()));
+
+                assert_test_eq!(vector.as_f32(), vector.cast::<f32>());
+                assert_test_eq!(vector.as_f64(), vector.cast::<f64>());
+                assert_eq!(vector.as_i32(), vector.cast::<i32>());
+                assert_eq!(vector.as_u32(), vector.cast::<u32>());
+            }
+        });
+    }
+
+    #[test]
+    fn test_cast_edge_cases() {
+        for_types!(|A| {
+            let vector = Vector::<4, f32, A>::new(f32::NAN, f32::INFINITY, -1e10, 4294967040.0);
+            assert_eq!(
+                vector.cast::<i32>(),
+                Vector::<4, _, A>::new(0, i32::MAX, i32::MIN, i32::MAX)
+            );
+            assert_eq!(
+                vector.cast::<u32>(),
+                Vector::<4, _, A>::new(0, u32::MAX, 0, 4294967040)
+            );
+
+            let vector = Vector::<4, f32, A>::new(-0.9, 2147483520.0, -2147483648.0, 2147483648.0);
+            assert_eq!(
+                vector.cast::<i32>(),
+                Vector::<4, _, A>::new(0, 2147483520, i32::MIN, i32::MAX)
+            );
+            assert_eq!(
+                vector.cast::<u32>(),
+                Vector::<4, _, A>::new(0, 2147483520, 0, 2147483648)
+            );
+
+            let vector = Vector::<4, u32, A>::new(u32::MAX, 16777217, 2147483649, 0);
+            assert_eq!(
+                vector.cast::<f32>(),
+                Vector::<4, _, A>::new(4294967296.0, 16777216.0, 2147483648.0, 0.0)
+            );
+            assert_eq!(
+                vector.cast::<i32>(),
+                Vector::<4, _, A>::new(-1, 16777217, -2147483647, 0)
+            );
+
+            let vector = Vector::<4, f64, A>::new(1e300, -1e-300, 0.1, f64::NEG_INFINITY);
+            assert_eq!(
+                vector.cast::<f32>(),
+                Vector::<4, _, A>::new(f32::INFINITY, -0.0, 0.1, f32::NEG_INFINITY)
+            );
+        });
+    }
+
+    #[test]
+    fn test_try_cast() {
+        for_types!(|N, T: PrimitiveNumber, A| {
+            for vector in random_iter::<Vector<N, T, A>>() {
+                if let Some(result) = vector.try_cast::<i32>() {
+                    assert_eq!(result, vector.cast::<i32>());
+                }
+                if let Some(result) = vector.try_cast::<f32>() {
+                    assert_test_eq!(result, vector.cast::<f32>());
+                }
+            }
+        });
+
+        assert_eq!(
+            Vec3::new(-1.9, 2.9, 3.0).try_cast::<i8>(),
+            Some(Vec3::new(-1, 2, 3))
+        );
+        assert_eq!(
+            Vec3::new(-0.9, 255.9, 0.0).try_cast::<u8>(),
+            Some(Vec3::new(0, 255, 0))
+        );
+        assert_eq!(Vec3::new(-1.0, 2.0, 3.0).try_cast::<u8>(), None);
+        assert_eq!(Vec3::new(256.0, 2.0, 3.0).try_cast::<u8>(), None);
+        assert_eq!(
+            Vec3::new(-128.9, 127.9, 0.0).try_cast::<i8>(),
+            Some(Vec3::new(-128, 127, 0))
+        );
+        assert_eq!(Vec3::new(-129.0, 0.0, 0.0).try_cast::<i8>(), None);
+        assert_eq!(Vec3::new(128.0, 0.0, 0.0).try_cast::<i8>(), None);
+        assert_eq!(Vec3::new(2147483648.0f32, 0.0, 0.0).try_cast::<i32>(), None);
+        assert_eq!(
+            Vec3::new(-2147483648.0f32, 0.0, 0.0).try_cast::<i32>(),
+            Some(Vec3::new(i32::MIN, 0, 0))
+        );
+        assert_eq!(
+            Vec3::new(4294967040.0f32, 0.0, 0.0).try_cast::<u32>(),
+            Some(Vec3::new(4294967040, 0, 0))
+        );
+        assert_eq!(Vec3::new(4294967296.0f32, 0.0, 0.0).try_cast::<u32>(), None);
+        assert_eq!(
+            Vec3::new(3.0e38f32, 0.0, 0.0).try_cast::<u128>(),
+            Some(Vec3::new(3.0e38f32 as u128, 0, 0))
+        );
+        assert_eq!(Vec3::new(f32::NAN, 0.0, 0.0).try_cast::<i32>(), None);
+        assert_eq!(Vec3::new(f32::INFINITY, 0.0, 0.0).try_cast::<f64>(), None);
+        assert_eq!(Vec3::new(1e300, 0.0, 0.0).try_cast::<f32>(), None);
+        assert_eq!(
+            Vec3::new(1e-300, 0.0, 0.0).try_cast::<f32>(),
+            Some(Vec3::new(0.0, 0.0, 0.0))
+        );
+        assert_eq!(Vec3::new(-1, 2, 300).try_cast::<u8>(), None);
+        assert_eq!(
+            Vec3::new(1, 2, 300).try_cast::<i16>(),
+            Some(Vec3::new(1, 2, 300))
+        );
+        assert_eq!(Vec3::new(u128::MAX, 0, 0).try_cast::<f32>(), None);
+        assert_eq!(
+            Vec3::new(u128::MAX, 0, 0).try_cast::<f64>(),
+            Some(Vec3::new(u128::MAX as f64, 0.0, 0.0))
+        );
+    }
+}
@@ -0,0 +1,520 @@
+use crate::{Alignment, Length, SupportedLength, Vector};
+
+/// `1.5 * 2^23`. Adding and then subtracting this rounds an [`f32`] with a
+/// magnitude below `2^22` to the nearest integer, with ties to even.
+const ROUND_MAGIC: f32 = 12582912.0;
+
+/// Rounds the elements of `vector` to the nearest integer, with ties to even.
+///
+/// Unlike [`Vector::round`], the rounding matches SIMD conversion
+/// instructions, so SoA packing produces the same results.
+#[inline]
+fn round_ties_even<const N: usize, A: Alignment>(vector: Vector<N, f32, A>) -> Vector<N, f32, A>
+where
+    Length<N>: SupportedLength,
+{
+    vector + ROUND_MAGIC - ROUND_MAGIC
+}
+
+impl<A: Alignment> Vector<2, f32, A> {
+    /// Packs the elements of `self` into a `u32` as two 16-bit unsigned
+    /// normalized integers.
+    ///
+    /// Elements are clamped to `[0, 1]`, scaled by `65535` and rounded to the
+    /// nearest integer, with ties to even. `x` is stored in the lowest bits.
+    ///
+    /// This matches `packUnorm2x16` in GLSL.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled:
+    ///
+    /// Panics if any element is NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ggmath::Vec2;
+    /// #
+    /// assert_eq!(Vec2::new(1.0, 0.0).pack_unorm2x16(), 0x0000ffff);
+    /// assert_eq!(Vec2::new(0.5, 2.0).pack_unorm2x16(), 0xffff8000);
+    /// ```
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn pack_unorm2x16(self) -> u32 {
+        let v = round_ties_even(self.clamp(Self::ZERO, Self::ONE) * 65535.0).as_u32();
+
+        v.x | v.y << 16
+    }
+
+    /// Unpacks two 16-bit unsigned normalized integers from a `u32`.
+    ///
+    /// This is the inverse of [`pack_unorm2x16`], and matches
+    /// `unpackUnorm2x16` in GLSL.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ggmath::Vec2;
+    /// #
+    /// assert_eq!(Vec2::<f32>::unpack_unorm2x16(0x0000ffff), Vec2::new(1.0, 0.0));
+    /// ```
+    ///
+    /// [`pack_unorm2x16`]: Self::pack_unorm2x16
+    #[inline]
+    #[must_use]
+    pub fn unpack_unorm2x16(packed: u32) -> Self {
+        (Vector::<2, u32, A>::new(packed, packed >> 16) & 0xffff).as_f32() / 65535.0
+    }
+
+    /// Packs the elements of `self` into a `u32` as two 16-bit signed
+    /// normalized integers.
+    ///
+    /// Elements are clamped to `[-1, 1]`, scaled by `32767` and rounded to the
+    /// nearest integer, with ties to even. `x` is stored in the lowest bits.
+    ///
+    /// This matches `packSnorm2x16` in GLSL.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled:
+    ///
+    /// Panics if any element is NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ggmath::Vec2;
+    /// #
+    /// assert_eq!(Vec2::new(1.0, -1.0).pack_snorm2x16(), 0x80017fff);
+    /// ```
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn pack_snorm2x16(self) -> u32 {
+        let v = round_ties_even(self.clamp(Self::NEG_ONE, Self::ONE) * 32767.0).as_i32();
+        let v = v.cast_unsigned() & 0xffff;
+
+        v.x | v.y << 16
+    }
+
+    /// Unpacks two 16-bit signed normalized integers from a `u32`.
+    ///
+    /// This is the inverse of [`pack_snorm2x16`], and matches
+    /// `unpackSnorm2x16` in GLSL. `-32768` unpacks to `-1.0`, like `-32767`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ggmath::Vec2;
+    /// #
+    /// assert_eq!(Vec2::<f32>::unpack_snorm2x16(0x80017fff), Vec2::new(1.0, -1.0));
+    /// assert_eq!(Vec2::<f32>::unpack_snorm2x16(0x80008000), Vec2::new(-1.0, -1.0));
+    /// ```
+    ///
+    /// [`pack_snorm2x16`]: Self::pack_snorm2x16
+    #[inline]
+    #[must_use]
+    pub fn unpack_snorm2x16(packed: u32) -> Self {
+        let v = Vector::<2, u32, A>::new(packed << 16, packed).cast_signed() >> 16;
+
+        (v.as_f32() / 32767.0).max(Self::NEG_ONE)
+    }
+
+    /// Decodes a unit vector from octahedral encoding.
+    ///
+    /// This is the inverse of [`encode_octahedral`]. `self` is expected to be
+    /// in `[-1, 1]`, and the result is normalized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ggmath::{Vec2, Vec3};
+    /// #
+    /// assert_eq!(Vec2::new(0.0, 0.0).decode_octahedral(), Vec3::new(0.0, 0.0, 1.0));
+    /// assert_eq!(Vec2::new(1.0, 1.0).decode_octahedral(), Vec3::new(0.0, 0.0, -1.0));
+    /// ```
+    ///
+    /// [`encode_octahedral`]: Vector::encode_octahedral
+    #[inline]
+    #[must_use]
+    pub fn decode_octahedral(self) -> Vector<3, f32, A> {
+        let z = 1.0 - self.abs().element_sum();
+        let t = Self::splat((-z).max(0.0));
+        let xy = self - t.copysign(self);
+
+        Vector::<3, f32, A>::new(xy.x, xy.y, z).normalize()
+    }
+}
+
+impl<A: Alignment> Vector<3, f32, A> {
+    /// Encodes `self` as a 2D point in `[-1, 1]` by projecting it onto an
+    /// octahedron and unfolding the octahedron's lower half.
+    ///
+    /// Octahedral encoding stores directions, like normals, in two components
+    /// with a nearly uniform precision. The result can be quantized with
+    /// [`pack_snorm2x16`] and decoded with [`decode_octahedral`]. `self` does
+    /// not need to be normalized, but must not be zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ggmath::{Vec2, Vec3};
+    /// #
+    /// assert_eq!(Vec3::new(0.0, 0.0, -2.0).encode_octahedral(), Vec2::new(1.0, 1.0));
+    ///
+    /// let normal = Vec3::new(0.0, 0.6, -0.8);
+    /// let packed = normal.encode_octahedral().pack_snorm2x16();
+    /// let decoded = Vec2::<f32>::unpack_snorm2x16(packed).decode_octahedral();
+    ///
+    /// assert!(decoded.abs_diff_eq(normal, 1e-4));
+    /// ```
+    ///
+    /// [`pack_snorm2x16`]: Vector::pack_snorm2x16
+    /// [`decode_octahedral`]: Vector::decode_octahedral
+    #[inline]
+    #[must_use]
+    pub fn encode_octahedral(self) -> Vector<2, f32, A> {
+        let n = self / self.abs().element_sum();
+        let xy = Vector::<2, f32, A>::new(n.x, n.y);
+
+        if n.z >= 0.0 {
+            xy
+        } else {
+            (Vector::<2, f32, A>::ONE - xy.yx().abs()) * Vector::<2, f32, A>::ONE.copysign(xy)
+        }
+    }
+}
+
+impl<A: Alignment> Vector<4, f32, A> {
+    /// Packs the elements of `self` into a `u32` as four 8-bit unsigned
+    /// normalized integers.
+    ///
+    /// Elements are clamped to `[0, 1]`, scaled by `255` and rounded to the
+    /// nearest integer, with ties to even. `x` is stored in the lowest bits.
+    ///
+    /// This matches `packUnorm4x8` in GLSL and the `R8G8B8A8_UNORM` vertex
+    /// format.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled:
+    ///
+    /// Panics if any element is NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ggmath::Vec4;
+    /// #
+    /// assert_eq!(Vec4::new(1.0, 0.0, 0.2, 2.0).pack_unorm4x8(), 0xff3300ff);
+    /// ```
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn pack_unorm4x8(self) -> u32 {
+        let v = round_ties_even(self.clamp(Self::ZERO, Self::ONE) * 255.0).as_u32();
+
+        v.x | v.y << 8 | v.z << 16 | v.w << 24
+    }
+
+    /// Unpacks four 8-bit unsigned normalized integers from a `u32`.
+    ///
+    /// This is the inverse of [`pack_unorm4x8`], and matches
+    /// `unpackUnorm4x8` in GLSL.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ggmath::Vec4;
+    /// #
+    /// assert_eq!(Vec4::<f32>::unpack_unorm4x8(0xff3300ff), Vec4::new(1.0, 0.0, 0.2, 1.0));
+    /// ```
+    ///
+    /// [`pack_unorm4x8`]: Self::pack_unorm4x8
+    #[inline]
+    #[must_use]
+    pub fn unpack_unorm4x8(packed: u32) -> Self {
+        let v = Vector::<4, u32, A>::new(packed, packed >> 8, packed >> 16, packed >> 24);
+
+        (v & 0xff).as_f32() / 255.0
+    }
+
+    /// Packs the elements of `self` into a `u32` as four 8-bit signed
+    /// normalized integers.
+    ///
+    /// Elements are clamped to `[-1, 1]`, scaled by `127` and rounded to the
+    /// nearest integer, with ties to even. `x` is stored in the lowest bits.
+    ///
+    /// This matches `packSnorm4x8` in GLSL and the `R8G8B8A8_SNORM` vertex
+    /// format.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled:
+    ///
+    /// Panics if any element is NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ggmath::Vec4;
+    /// #
+    /// assert_eq!(Vec4::new(1.0, -1.0, 0.0, -2.0).pack_snorm4x8(), 0x8100817f);
+    /// ```
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn pack_snorm4x8(self) -> u32 {
+        let v = round_ties_even(self.clamp(Self::NEG_ONE, Self::ONE) * 127.0).as_i32();
+        let v = v.cast_unsigned() & 0xff;
+
+        v.x | v.y << 8 | v.z << 16 | v.w << 24
+    }
+
+    /// Unpacks four 8-bit signed normalized integers from a `u32`.
+    ///
+    /// This is the inverse of [`pack_snorm4x8`], and matches
+    /// `unpackSnorm4x8` in GLSL. `-128` unpacks to `-1.0`, like `-127`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ggmath::Vec4;
+    /// #
+    /// assert_eq!(Vec4::<f32>::unpack_snorm4x8(0x8100817f), Vec4::new(1.0, -1.0, 0.0, -1.0));
+    /// assert_eq!(Vec4::<f32>::unpack_snorm4x8(0x80808080), Vec4::splat(-1.0));
+    /// ```
+    ///
+    /// [`pack_snorm4x8`]: Self::pack_snorm4x8
+    #[inline]
+    #[must_use]
+    pub fn unpack_snorm4x8(packed: u32) -> Self {
+        let v = Vector::<4, u32, A>::new(packed << 24, packed << 16, packed << 8, packed);
+        let v = v.cast_signed() >> 24;
+
+        (v.as_f32() / 127.0).max(Self::NEG_ONE)
+    }
+
+    /// Packs the elements of `self` into a `u32` as three 10-bit and one 2-bit
+    /// unsigned normalized integers.
+    ///
+    /// Elements are clamped to `[0, 1]`, scaled by `1023` (`3` for `w`) and
+    /// rounded to the nearest integer, with ties to even. `x` is stored in
+    /// the lowest bits and `w` in the highest 2 bits.
+    ///
+    /// This matches the `RGB10_A2` texture format and the `A2B10G10R10`
+    /// vertex format.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled:
+    ///
+    /// Panics if any element is NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ggmath::Vec4;
+    /// #
+    /// assert_eq!(Vec4::new(1.0, 0.0, 0.0, 1.0).pack_rgb10a2(), 0xc00003ff);
+    /// ```
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn pack_rgb10a2(self) -> u32 {
+        let scale = Self::new(1023.0, 1023.0, 1023.0, 3.0);
+        let v = round_ties_even(self.clamp(Self::ZERO, Self::ONE) * scale).as_u32();
+
+        v.x | v.y << 10 | v.z << 20 | v.w << 30
+    }
+
+    /// Unpacks three 10-bit and one 2-bit unsigned normalized integers from a
+    /// `u32`.
+    ///
+    /// This is the inverse of [`pack_rgb10a2`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ggmath::Vec4;
+    /// #
+    /// assert_eq!(Vec4::<f32>::unpack_rgb10a2(0xc00003ff), Vec4::new(1.0, 0.0, 0.0, 1.0));
+    /// ```
+    ///
+    /// [`pack_rgb10a2`]: Self::pack_rgb10a2
+    #[inline]
+    #[must_use]
+    pub fn unpack_rgb10a2(packed: u32) -> Self {
+        let v = Vector::<4, u32, A>::new(packed, packed >> 10, packed >> 20, packed >> 30);
+        let mask = Vector::<4, u32, A>::new(0x3ff, 0x3ff, 0x3ff, 0x3);
+
+        (v & mask).as_f32() / Self::new(1023.0, 1023.0, 1023.0, 3.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Vector,
+        test_utils::{for_types, random_iter},
+    };
+
+    #[test]
+    fn test_pack_round_trip() {
+        for_types!(|A| {
+            for packed in random_iter::<u32>() {
+                let vector = Vector::<4, f32, A>::unpack_unorm4x8(packed);
+                assert_eq!(vector.pack_unorm4x8(), packed);
+
+                let vector = Vector::<2, f32, A>::unpack_unorm2x16(packed);
+                assert_eq!(vector.pack_unorm2x16(), packed);
+
+                let vector = Vector::<4, f32, A>::unpack_rgb10a2(packed);
+                assert_eq!(vector.pack_rgb10a2(), packed);
+            }
+
+            for byte in 0..=u8::MAX {
+                let packed = u32::from_le_bytes([byte, byte, byte, byte]);
+
+                let vector = Vector::<4, f32, A>::unpack_unorm4x8(packed);
+                assert_eq!(vector, Vector::<4, f32, A>::splat(byte as f32 / 255.0));
+                assert_eq!(vector.pack_unorm4x8(), packed);
+
+                let vector = Vector::<4, f32, A>::unpack_snorm4x8(packed);
+                let expected = (byte as i8 as f32 / 127.0).max(-1.0);
+                assert_eq!(vector, Vector::<4, f32, A>::splat(expected));
+
+                // `-128` unpacks to `-1.0`, which packs to `-127`.
+                if byte != 0x80 {
+                    assert_eq!(vector.pack_snorm4x8(), packed);
+                }
+            }
+
+            for value in 0..=u16::MAX {
+                let packed = u32::from(value) | u32::from(value) << 16;
+
+                let vector = Vector::<2, f32, A>::unpack_unorm2x16(packed);
+                assert_eq!(vector, Vector::<2, f32, A>::splat(value as f32 / 65535.0));
+                assert_eq!(vector.pack_unorm2x16(), packed);
+
+                let vector = Vector::<2, f32, A>::unpack_snorm2x16(packed);
+                let expected = (value as i16 as f32 / 32767.0).max(-1.0);
+                assert_eq!(vector, Vector::<2, f32, A>::splat(expected));
+
+                if value != 0x8000 {
+                    assert_eq!(vector.pack_snorm2x16(), packed);
+                }
+            }
+
+            for value in 0..1024 {
+                let packed = value | value << 10 | value << 20 | (value & 3) << 30;
+                let vector = Vector::<4, f32, A>::unpack_rgb10a2(packed);
+                assert_eq!(
+                    vector,
+                    Vector::<4, f32, A>::new(
+                        value as f32 / 1023.0,
+                        value as f32 / 1023.0,
+                        value as f32 / 1023.0,
+                        (value & 3) as f32 / 3.0
+                    )
+                );
+                assert_eq!(vector.pack_rgb10a2(), packed);
+            }
+        });
+    }
+
+    #[test]
+    fn test_pack_edge_cases() {
+        for_types!(|A| {
+            // Out of range values are clamped.
+            let vector = Vector::<4, f32, A>::new(-1.0, 2.0, f32::INFINITY, f32::NEG_INFINITY);
+            assert_eq!(vector.pack_unorm4x8(), 0x00ffff00);
+            assert_eq!(vector.pack_snorm4x8(), 0x817f7f81);
+            assert_eq!(vector.pack_rgb10a2(), 0x3ffffc00);
+
+            let vector = Vector::<2, f32, A>::new(-2.0, f32::INFINITY);
+            assert_eq!(vector.pack_unorm2x16(), 0xffff0000);
+            assert_eq!(vector.pack_snorm2x16(), 0x7fff8001);
+
+            // Halfway values round to even.
+            let vector = Vector::<4, f32, A>::splat(0.5);
+            assert_eq!(vector.pack_unorm4x8(), 0x80808080);
+            assert_eq!(vector.pack_snorm4x8(), 0x40404040);
+            assert_eq!((-vector).pack_snorm4x8(), 0xc0c0c0c0);
+            assert_eq!(vector.pack_rgb10a2(), 0xa0080200);
+
+            let vector = Vector::<2, f32, A>::new(0.5, -0.5);
+            assert_eq!(vector.pack_unorm2x16(), 0x00008000);
+            assert_eq!(vector.pack_snorm2x16(), 0xc0004000);
+
+            // Values just below halfway round down.
+            let vector = Vector::<4, f32, A>::splat(0.49999997);
+            assert_eq!(vector.pack_unorm4x8(), 0x7f7f7f7f);
+
+            let vector = Vector::<4, f32, A>::new(-0.0, 1.0, 1.0, 1.0);
+            assert_eq!(vector.pack_unorm4x8(), 0xffffff00);
+            assert_eq!(vector.pack_snorm4x8(), 0x7f7f7f00);
+            assert_eq!(vector.pack_rgb10a2(), 0xfffffc00);
+
+            assert_eq!(
+                Vector::<4, f32, A>::unpack_snorm4x8(0x80817f00),
+                Vector::<4, f32, A>::new(0.0, 1.0, -1.0, -1.0)
+            );
+            assert_eq!(
+                Vector::<4, f32, A>::unpack_rgb10a2(0x7ff003ff),
+                Vector::<4, f32, A>::new(1.0, 0.0, 1.0, 1.0 / 3.0)
+            );
+        });
+    }
+
+    #[test]
+    fn test_octahedral() {
+        for_types!(|A| {
+            for vector in random_iter::<Vector<3, f32, A>>() {
+                let l1 = vector.abs().element_sum();
+                if !(1e-10..1e30).contains(&l1) {
+                    continue;
+                }
+
+                let normal = (vector / l1).normalize();
+                let encoded = vector.encode_octahedral();
+                assert!(encoded.abs().max_element() <= 1.0);
+                assert!(encoded.decode_octahedral().abs_diff_eq(normal, 1e-5));
+
+                let packed = encoded.pack_snorm2x16();
+                let decoded = Vector::<2, f32, A>::unpack_snorm2x16(packed).decode_octahedral();
+                assert!(decoded.abs_diff_eq(normal, 1e-4));
+            }
+
+            let axes = [
+                (Vector::<3, f32, A>::X, Vector::<2, f32, A>::new(1.0, 0.0)),
+                (Vector::<3, f32, A>::Y, Vector::<2, f32, A>::new(0.0, 1.0)),
+                (Vector::<3, f32, A>::Z, Vector::<2, f32, A>::new(0.0, 0.0)),
+                (
+                    Vector::<3, f32, A>::NEG_X,
+                    Vector::<2, f32, A>::new(-1.0, 0.0),
+                ),
+                (
+                    Vector::<3, f32, A>::NEG_Y,
+                    Vector::<2, f32, A>::new(0.0, -1.0),
+                ),
+                (
+                    Vector::<3, f32, A>::NEG_Z,
+                    Vector::<2, f32, A>::new(1.0, 1.0),
+                ),
+            ];
+            for (axis, encoded) in axes {
+                assert_eq!(axis.encode_octahedral(), encoded);
+                assert_eq!(encoded.decode_octahedral(), axis);
+            }
+
+            // The corners of the unfolded square all decode to `-Z`.
+            for (x, y) in [(1.0, 1.0), (-1.0, 1.0), (1.0, -1.0), (-1.0, -1.0)] {
+                let corner = Vector::<2, f32, A>::new(x, y);
+                assert_eq!(corner.decode_octahedral(), Vector::<3, f32, A>::NEG_Z);
+            }
+        });
+    }
+}
@@ -0,0 +1,327 @@
+use wide::{f32x4, f32x8, f32x16, i32x4, i32x8, i32x16, u32x4, u32x8, u32x16};
+
+use crate::{Alignment, Vector};
+
+macro_rules! impl_wide_packing {
+    ($Wide:ident, $SignedWide:ident, $UnsignedWide:ident, $from_signed:ident) => {
+        impl<A: Alignment> Vector<2, $Wide, A> {
+            /// For each lane, packs the elements of `self` into a `u32` as two
+            /// 16-bit unsigned normalized integers.
+            ///
+            /// See [`Vector::pack_unorm2x16`] for details.
+            #[inline]
+            #[must_use]
+            pub fn pack_unorm2x16(self) -> $UnsignedWide {
+                let v = self.clamp(Self::ZERO, Self::ONE) * $Wide::splat(65535.0);
+                let v = v.map($Wide::round_int).cast_unsigned();
+
+                v.x | v.y << 16
+            }
+
+            /// For each lane, unpacks two 16-bit unsigned normalized integers
+            /// from a `u32`.
+            ///
+            /// See [`Vector::unpack_unorm2x16`] for details.
+            #[inline]
+            #[must_use]
+            pub fn unpack_unorm2x16(packed: $UnsignedWide) -> Self {
+                let v = Vector::<2, $UnsignedWide, A>::new(packed, packed >> 16);
+                let v = (v & $UnsignedWide::splat(0xffff)).cast_signed();
+
+                v.map($Wide::$from_signed) / $Wide::splat(65535.0)
+            }
+
+            /// For each lane, packs the elements of `self` into a `u32` as two
+            /// 16-bit signed normalized integers.
+            ///
+            /// See [`Vector::pack_snorm2x16`] for details.
+            #[inline]
+            #[must_use]
+            pub fn pack_snorm2x16(self) -> $UnsignedWide {
+                let v = self.clamp(Self::NEG_ONE, Self::ONE) * $Wide::splat(32767.0);
+                let v = v.map($Wide::round_int).cast_unsigned() & $UnsignedWide::splat(0xffff);
+
+                v.x | v.y << 16
+            }
+
+            /// For each lane, unpacks two 16-bit signed normalized integers
+            /// from a `u32`.
+            ///
+            /// See [`Vector::unpack_snorm2x16`] for details.
+            #[inline]
+            #[must_use]
+            pub fn unpack_snorm2x16(packed: $UnsignedWide) -> Self {
+                let v = Vector::<2, $UnsignedWide, A>::new(packed << 16, packed);
+                let v = v.cast_signed() >> $SignedWide::splat(16);
+
+                (v.map($Wide::$from_signed) / $Wide::splat(32767.0)).max(Self::NEG_ONE)
+            }
+
+            /// For each lane, decodes a unit vector from octahedral encoding.
+            ///
+            /// See [`Vector::decode_octahedral`] for details.
+            #[inline]
+            #[must_use]
+            pub fn decode_octahedral(self) -> Vector<3, $Wide, A> {
+                let z = $Wide::ONE - self.abs().element_sum();
+                let t = Self::splat((-z).max($Wide::ZERO));
+                let xy = self - t.copysign(self);
+
+                Vector::<3, $Wide, A>::new(xy.x, xy.y, z).normalize()
+            }
+        }
+
+        impl<A: Alignment> Vector<3, $Wide, A> {
+            /// For each lane, encodes `self` as a 2D point in `[-1, 1]` by
+            /// projecting it onto an octahedron and unfolding the octahedron's
+            /// lower half.
+            ///
+            /// See [`Vector::encode_octahedral`] for details.
+            #[inline]
+            #[must_use]
+            pub fn encode_octahedral(self) -> Vector<2, $Wide, A> {
+                let n = self / self.abs().element_sum();
+                let xy = Vector::<2, $Wide, A>::new(n.x, n.y);
+                let folded = (Vector::<2, $Wide, A>::ONE - xy.yx().abs())
+                    * Vector::<2, $Wide, A>::ONE.copysign(xy);
+
+                let lower = n.z.simd_lt($Wide::ZERO);
+                Vector::<2, $Wide, A>::new(lower.blend(folded.x, xy.x), lower.blend(folded.y, xy.y))
+            }
+        }
+
+        impl<A: Alignment> Vector<4, $Wide, A> {
+            /// For each lane, packs the elements of `self` into a `u32` as four
+            /// 8-bit unsigned normalized integers.
+            ///
+            /// See [`Vector::pack_unorm4x8`] for details.
+            #[inline]
+            #[must_use]
+            pub fn pack_unorm4x8(self) -> $UnsignedWide {
+                let v = self.clamp(Self::ZERO, Self::ONE) * $Wide::splat(255.0);
+                let v = v.map($Wide::round_int).cast_unsigned();
+
+                v.x | v.y << 8 | v.z << 16 | v.w << 24
+            }
+
+            /// For each lane, unpacks four 8-bit unsigned normalized integers
+            /// from a `u32`.
+            ///
+            /// See [`Vector::unpack_unorm4x8`] for details.
+            #[inline]
+            #[must_use]
+            pub fn unpack_unorm4x8(packed: $UnsignedWide) -> Self {
+                let v = Vector::<4, $UnsignedWide, A>::new(
+                    packed,
+                    packed >> 8,
+                    packed >> 16,
+                    packed >> 24,
+                );
+                let v = (v & $UnsignedWide::splat(0xff)).cast_signed();
+
+                v.map($Wide::$from_signed) / $Wide::splat(255.0)
+            }
+
+            /// For each lane, packs the elements of `self` into a `u32` as four
+            /// 8-bit signed normalized integers.
+            ///
+            /// See [`Vector::pack_snorm4x8`] for details.
+            #[inline]
+            #[must_use]
+            pub fn pack_snorm4x8(self) -> $UnsignedWide {
+                let v = self.clamp(Self::NEG_ONE, Self::ONE) * $Wide::splat(127.0);
+                let v = v.map($Wide::round_int).cast_unsigned() & $UnsignedWide::splat(0xff);
+
+                v.x | v.y << 8 | v.z << 16 | v.w << 24
+            }
+
+            /// For each lane, unpacks four 8-bit signed normalized integers
+            /// from a `u32`.
+            ///
+            /// See [`Vector::unpack_snorm4x8`] for details.
+            #[inline]
+            #[must_use]
+            pub fn unpack_snorm4x8(packed: $UnsignedWide) -> Self {
+                let v = Vector::<4, $UnsignedWide, A>::new(
+                    packed << 24,
+                    packed << 16,
+                    packed << 8,
+                    packed,
+                );
+                let v = v.cast_signed() >> $SignedWide::splat(24);
+
+                (v.map($Wide::$from_signed) / $Wide::splat(127.0)).max(Self::NEG_ONE)
+            }
+
+            /// For each lane, packs the elements of `self` into a `u32` as
+            /// three 10-bit and one 2-bit unsigned normalized integers.
+            ///
+            /// See [`Vector::pack_rgb10a2`] for details.
+            #[inline]
+            #[must_use]
+            pub fn pack_rgb10a2(self) -> $UnsignedWide {
+                let scale = Self::new(
+                    $Wide::splat(1023.0),
+                    $Wide::splat(1023.0),
+                    $Wide::splat(1023.0),
+                    $Wide::splat(3.0),
+                );
+                let v = self.clamp(Self::ZERO, Self::ONE) * scale;
+                let v = v.map($Wide::round_int).cast_unsigned();
+
+                v.x | v.y << 10 | v.z << 20 | v.w << 30
+            }
+
+            /// For each lane, unpacks three 10-bit and one 2-bit unsigned
+            /// normalized integers from a `u32`.
+            ///
+            /// See [`Vector::unpack_rgb10a2`] for details.
+            #[inline]
+            #[must_use]
+            pub fn unpack_rgb10a2(packed: $UnsignedWide) -> Self {
+                let v = Vector::<4, $UnsignedWide, A>::new(
+                    packed,
+                    packed >> 10,
+                    packed >> 20,
+                    packed >> 30,
+                );
+                let mask = Vector::<4, $UnsignedWide, A>::new(
+                    $UnsignedWide::splat(0x3ff),
+                    $UnsignedWide::splat(0x3ff),
+                    $UnsignedWide::splat(0x3ff),
+                    $UnsignedWide::splat(0x3),
+                );
+                let scale = Self::new(
+                    $Wide::splat(1023.0),
+                    $Wide::splat(1023.0),
+                    $Wide::splat(1023.0),
+                    $Wide::splat(3.0),
+                );
+
+                (v & mask).cast_signed().map($Wide::$from_signed) / scale
+            }
+        }
+    };
+}
+impl_wide_packing!(f32x4, i32x4, u32x4, from_i32x4);
+impl_wide_packing!(f32x8, i32x8, u32x8, from_i32x8);
+impl_wide_packing!(f32x16, i32x16, u32x16, from_i32x16);
+
+#[cfg(test)]
+mod tests {
+    use wide::{f32x4, u32x4};
+
+    use crate::{
+        Unaligned, Vector,
+        test_utils::{for_types, random_iter},
+    };
+
+    fn without_nan<const N: usize>(
+        vector: Vector<N, f32x4, Unaligned>,
+    ) -> Vector<N, f32x4, Unaligned>
+    where
+        crate::Length<N>: crate::SupportedLength,
+    {
+        vector.map(|x| x.is_nan().blend(f32x4::ZERO, x))
+    }
+
+    #[test]
+    fn test_pack() {
+        for vector in random_iter::<Vector<2, f32x4, Unaligned>>() {
+            let vector = without_nan(vector);
+
+            assert_eq!(
+                vector.pack_unorm2x16(),
+                u32x4::new(core::array::from_fn(|i| vector.lane(i).pack_unorm2x16()))
+            );
+            assert_eq!(
+                vector.pack_snorm2x16(),
+                u32x4::new(core::array::from_fn(|i| vector.lane(i).pack_snorm2x16()))
+            );
+        }
+
+        for vector in random_iter::<Vector<4, f32x4, Unaligned>>() {
+            let vector = without_nan(vector);
+
+            assert_eq!(
+                vector.pack_unorm4x8(),
+                u32x4::new(core::array::from_fn(|i| vector.lane(i).pack_unorm4x8()))
+            );
+            assert_eq!(
+                vector.pack_snorm4x8(),
+                u32x4::new(core::array::from_fn(|i| vector.lane(i).pack_snorm4x8()))
+            );
+            assert_eq!(
+                vector.pack_rgb10a2(),
+                u32x4::new(core::array::from_fn(|i| vector.lane(i).pack_rgb10a2()))
+            );
+        }
+
+        let vector = Vector::<4, f32x4, Unaligned>::splat(f32x4::new([0.5, -0.5, 2.0, -0.0]));
+        assert_eq!(
+            vector.pack_unorm4x8(),
+            u32x4::new([0x80808080, 0, 0xffffffff, 0])
+        );
+        assert_eq!(
+            vector.pack_snorm4x8(),
+            u32x4::new([0x40404040, 0xc0c0c0c0, 0x7f7f7f7f, 0])
+        );
+    }
+
+    #[test]
+    fn test_unpack() {
+        for packed in random_iter::<u32x4>() {
+            let lanes = packed.to_array();
+
+            assert_eq!(
+                Vector::<2, f32x4, Unaligned>::unpack_unorm2x16(packed),
+                Vector::from_lane_fn(|i| Vector::<2, f32, Unaligned>::unpack_unorm2x16(lanes[i]))
+            );
+            assert_eq!(
+                Vector::<2, f32x4, Unaligned>::unpack_snorm2x16(packed),
+                Vector::from_lane_fn(|i| Vector::<2, f32, Unaligned>::unpack_snorm2x16(lanes[i]))
+            );
+            assert_eq!(
+                Vector::<4, f32x4, Unaligned>::unpack_unorm4x8(packed),
+                Vector::from_lane_fn(|i| Vector::<4, f32, Unaligned>::unpack_unorm4x8(lanes[i]))
+            );
+            assert_eq!(
+                Vector::<4, f32x4, Unaligned>::unpack_snorm4x8(packed),
+                Vector::from_lane_fn(|i| Vector::<4, f32, Unaligned>::unpack_snorm4x8(lanes[i]))
+            );
+            assert_eq!(
+                Vector::<4, f32x4, Unaligned>::unpack_rgb10a2(packed),
+                Vector::from_lane_fn(|i| Vector::<4, f32, Unaligned>::unpack_rgb10a2(lanes[i]))
+            );
+        }
+    }
+
+    #[test]
+    fn test_octahedral() {
+        for_types!(|A| {
+            for vector in random_iter::<Vector<3, f32x4, A>>() {
+                let lanes = core::array::from_fn::<_, 4, _>(|i| vector.lane(i));
+                if lanes
+                    .iter()
+                    .any(|lane| !(1e-10..1e30).contains(&lane.abs().element_sum()))
+                {
+                    continue;
+                }
+
+                let encoded = vector.encode_octahedral();
+                for (i, lane) in lanes.into_iter().enumerate() {
+                    assert_eq!(encoded.lane(i), lane.encode_octahedral());
+                }
+
+                let decoded = encoded.decode_octahedral();
+                for i in 0..4 {
+                    assert!(
+                        decoded
+                            .lane(i)
+                            .abs_diff_eq(encoded.lane(i).decode_octahedral(), 1e-6)
+                    );
+                }
+            }
+        });
+    }
+}
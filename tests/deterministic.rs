@@ -0,0 +1,227 @@
+//! With the `deterministic` feature, results must be bit-identical on every
+//! target and for both alignments. The golden values below are the expected
+//! bit patterns, and must never change unless the algorithm of an operation
+//! intentionally changes.
+
+#![cfg(feature = "deterministic")]
+
+use ggmath::{Aligned, Alignment, Matrix, Quaternion, Unaligned, Vector};
+
+macro_rules! golden_test {
+    ($test:ident, $T:ident, $GOLDEN:ident) => {
+        #[test]
+        fn $test() {
+            fn results<A: Alignment>() -> Vec<(&'static str, Vec<$T>)> {
+                let vec3 = Vector::<3, $T, A>::new(0.3, -1.7, 2.9);
+                let vec4 = Vector::<4, $T, A>::new(0.3, -1.7, 2.9, -4.1);
+                let positive = Vector::<4, $T, A>::new(0.3, 1.7, 2.9, 41.5);
+
+                let quat = Quaternion::<$T, A>::from_axis_angle(vec3.normalize(), 0.8);
+                let other_quat = Quaternion::<$T, A>::from_rotation_y(-2.1);
+
+                let mat = Matrix::<4, $T, A>::from_row_array(&[
+                    2.0, 0.5, -1.3, 4.0, //
+                    0.1, 3.2, 0.7, -2.5, //
+                    -0.9, 1.1, 1.6, 0.3, //
+                    0.0, 0.0, 0.0, 1.0, //
+                ]);
+                let other_mat = Matrix::<4, $T, A>::from_axis_angle(vec3.normalize(), 1.3);
+
+                let mat_values = |mat: Matrix<4, $T, A>| {
+                    mat.as_rows()
+                        .iter()
+                        .flat_map(|row| row.to_array())
+                        .collect::<Vec<_>>()
+                };
+
+                vec![
+                    ("sin", vec3.sin().to_array().to_vec()),
+                    ("cos", vec4.cos().to_array().to_vec()),
+                    ("tan", vec4.tan().to_array().to_vec()),
+                    ("atan", vec4.atan().to_array().to_vec()),
+                    ("exp", vec4.exp().to_array().to_vec()),
+                    ("ln", positive.ln().to_array().to_vec()),
+                    ("powf", positive.powf(1.3).to_array().to_vec()),
+                    ("length", vec![vec3.length(), vec4.length()]),
+                    ("normalize", vec3.normalize().to_array().to_vec()),
+                    (
+                        "quat_slerp",
+                        quat.slerp(other_quat, 0.35).to_array().to_vec(),
+                    ),
+                    ("mat4_mul", mat_values(mat * other_mat)),
+                    ("mat4_inverse", mat_values(mat.inverse())),
+                ]
+            }
+
+            for (alignment, results) in [
+                ("aligned", results::<Aligned>()),
+                ("unaligned", results::<Unaligned>()),
+            ] {
+                assert_eq!(results.len(), $GOLDEN.len());
+
+                for ((name, values), (golden_name, golden)) in results.into_iter().zip($GOLDEN) {
+                    assert_eq!(name, *golden_name);
+
+                    let bits = values.iter().map(|x| x.to_bits()).collect::<Vec<_>>();
+                    assert_eq!(
+                        bits,
+                        *golden,
+                        "{alignment} `{}` `{name}` differs from the golden values",
+                        stringify!($T),
+                    );
+                }
+            }
+        }
+    };
+}
+
+golden_test!(test_f32_golden_values, f32, F32_GOLDEN);
+golden_test!(test_f64_golden_values, f64, F64_GOLDEN);
+
+const F32_GOLDEN: &[(&str, &[u32])] = &[
+    ("sin", &[0x3e974e6d, 0xbf7dddbf, 0x3e74fdc0]),
+    ("cos", &[0x3f7490ef, 0xbe03efd3, 0xbf7890b7, 0xbf1327ab]),
+    ("tan", &[0x3e9e6153, 0x40f64a8b, 0xbe7c51ab, 0xbfb6361b]),
+    ("atan", &[0x3e9539d4, 0xbf850052, 0x3f9e8eee, 0xbfaa70b6]),
+    ("exp", &[0x3facc82c, 0x3e3b1163, 0x419164a7, 0x3c87c36c]),
+    ("ln", &[0xbf9a1bc8, 0x3f07d741, 0x3f884871, 0x406e71c3]),
+    ("powf", &[0x3e561226, 0x3fff2619, 0x407f71ea, 0x42fdccec]),
+    ("length", &[0x4057fe7c, 0x40a9ee87]),
+    ("normalize", &[0x3db60ca8, 0xbf00f3a2, 0x3f5bf9f6]),
+    (
+        "quat_slerp",
+        &[0x3cc79379, 0xbef5e90f, 0x3e71278a, 0x3f58345c],
+    ),
+    (
+        "mat4_mul",
+        &[
+            0x3f2ca709, 0x4015cb56, 0xbdabe700, 0x40800000, 0xc041c615, 0x3f9fca81, 0xbdf6cac0,
+            0xc0200000, 0xbff09f3e, 0xbf5c7d5c, 0x3f0d3892, 0x3e99999a, 0x0, 0x0, 0x0, 0x3f800000,
+        ],
+    ),
+    (
+        "mat4_inverse",
+        &[
+            0x3f7c0f4b, 0xbf013782, 0x3f82aa5a, 0xc0b035c4, 0xbe371aff, 0x3eeb4179, 0xbeb14f9c,
+            0x3ffbe2ca, 0x3f2d413c, 0xbf198dba, 0x3fb7f981, 0xc0946938, 0x80000000, 0x0,
+            0x80000000, 0x3f800000,
+        ],
+    ),
+];
+
+const F64_GOLDEN: &[(&str, &[u64])] = &[
+    (
+        "sin",
+        &[0x3fd2e9cd95baba33, 0xbfefbbb7d72f98b6, 0x3fce9fb8d64830e3],
+    ),
+    (
+        "cos",
+        &[
+            0x3fee921dd42f09ba,
+            0xbfc07df9f4a26c86,
+            0xbfef1216dba340c9,
+            0xbfe264f5306a2d6a,
+        ],
+    ),
+    (
+        "tan",
+        &[
+            0x3fd3cc2a44e29998,
+            0x401ec952123d33f2,
+            0xbfcf8a364255739f,
+            0xbff6c6c3b4a4f47e,
+        ],
+    ),
+    (
+        "atan",
+        &[
+            0x3fd2a73a661eaf06,
+            0xbff0a00a3bce369f,
+            0x3ff3d1ddbdad64f0,
+            0xbff54e16d0a3f513,
+        ],
+    ),
+    (
+        "exp",
+        &[
+            0x3ff599058c8c1a96,
+            0x3fc7622c78a98a07,
+            0x40322c94ca7a81fe,
+            0x3f90f86d6b6e600b,
+        ],
+    ),
+    (
+        "ln",
+        &[
+            0xbff34378fcbda721,
+            0x3fe0fae81914a991,
+            0x3ff1090e20315212,
+            0x400dce385b073a88,
+        ],
+    ),
+    (
+        "powf",
+        &[
+            0x3fcac2449f53f2b0,
+            0x3fffe4c32630d4d8,
+            0x400fee3d45564480,
+            0x405fb99ded0fd908,
+        ],
+    ),
+    ("length", &[0x400affcf744319e3, 0x40153dd0e77c6734]),
+    (
+        "normalize",
+        &[0x3fb6c195011eb565, 0xbfe01e7436206b28, 0x3feb7f3eb6afc5da],
+    ),
+    (
+        "quat_slerp",
+        &[
+            0x3f98f26f50c1c658,
+            0xbfdebd220522f5de,
+            0x3fce24f12c3f7a55,
+            0x3feb068b9fef087f,
+        ],
+    ),
+    (
+        "mat4_mul",
+        &[
+            0x3fe594e0fe234951,
+            0x4002b96ac69466b3,
+            0xbfb57ce02c85c980,
+            0x4010000000000000,
+            0xc00838c2a401d684,
+            0x3ff3f94fe752a702,
+            0xbfbed9578025e4c8,
+            0xc004000000000000,
+            0xbffe13e78fe91d0c,
+            0xbfeb8fab96c12a61,
+            0x3fe1a711f18a07a5,
+            0x3fd3333333333333,
+            0x0,
+            0x0,
+            0x0,
+            0x3ff0000000000000,
+        ],
+    ),
+    (
+        "mat4_inverse",
+        &[
+            0x3fef81e984763511,
+            0xbfe026f05adb7ea9,
+            0x3ff0554b71b00942,
+            0xc01606b8ba59b2cf,
+            0xbfc6e360128ad5eb,
+            0x3fdd682f486e53a8,
+            0xbfd629f3b738ab7f,
+            0x3fff7c59777b6c19,
+            0x3fe5a827ddb25a9d,
+            0xbfe331b774016401,
+            0x3ff6ff305370c2ae,
+            0xc0128d27495bab28,
+            0x8000000000000000,
+            0x0,
+            0x8000000000000000,
+            0x3ff0000000000000,
+        ],
+    ),
+];
@@ -0,0 +1,70 @@
+//! Layouts of aligned types must not depend on the `force-fallback` feature.
+
+#![cfg(any(
+    target_feature = "sse2",
+    all(target_arch = "aarch64", target_feature = "neon"),
+    all(target_arch = "wasm32", target_feature = "simd128")
+))]
+
+use std::mem::{align_of, size_of};
+
+use ggmath::{Mat4A, QuatA, Vec2A, Vec3A, Vec4A};
+
+#[test]
+#[cfg(target_feature = "sse2")]
+fn test_x86_layout() {
+    assert_layout::<Vec3A<f32>>(16, 16);
+    assert_layout::<Vec4A<f32>>(16, 16);
+    assert_layout::<QuatA<f32>>(16, 16);
+    assert_layout::<Mat4A<f32>>(64, 16);
+
+    assert_layout::<Vec2A<f64>>(16, 16);
+    assert_layout::<Vec3A<f64>>(32, 32);
+    assert_layout::<Vec4A<f64>>(32, 32);
+    assert_layout::<QuatA<f64>>(32, 32);
+    assert_layout::<Mat4A<f64>>(128, 32);
+
+    assert_layout::<Vec3A<i32>>(16, 16);
+    assert_layout::<Vec4A<i32>>(16, 16);
+    assert_layout::<Vec3A<u32>>(16, 16);
+    assert_layout::<Vec4A<u32>>(16, 16);
+}
+
+#[test]
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+fn test_aarch64_layout() {
+    assert_layout::<Vec3A<f32>>(16, 16);
+    assert_layout::<Vec4A<f32>>(16, 16);
+    assert_layout::<QuatA<f32>>(16, 16);
+    assert_layout::<Mat4A<f32>>(64, 16);
+
+    assert_layout::<Vec2A<f64>>(16, 8);
+    assert_layout::<Vec3A<f64>>(24, 8);
+    assert_layout::<Vec4A<f64>>(32, 8);
+
+    assert_layout::<Vec3A<i32>>(16, 16);
+    assert_layout::<Vec4A<i32>>(16, 16);
+    assert_layout::<Vec3A<u32>>(16, 16);
+    assert_layout::<Vec4A<u32>>(16, 16);
+}
+
+#[test]
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+fn test_wasm32_layout() {
+    assert_layout::<Vec3A<f32>>(16, 16);
+    assert_layout::<Vec4A<f32>>(16, 16);
+    assert_layout::<QuatA<f32>>(16, 16);
+    assert_layout::<Mat4A<f32>>(64, 16);
+
+    assert_layout::<Vec2A<f64>>(16, 8);
+    assert_layout::<Vec4A<f64>>(32, 8);
+
+    assert_layout::<Vec3A<i32>>(12, 4);
+    assert_layout::<Vec4A<i32>>(16, 4);
+}
+
+#[track_caller]
+fn assert_layout<T>(size: usize, align: usize) {
+    assert_eq!(size_of::<T>(), size);
+    assert_eq!(align_of::<T>(), align);
+}